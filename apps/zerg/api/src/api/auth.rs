@@ -1,13 +1,13 @@
 use axum::Router;
 use domain_users::{
-    AccountLinkingService, OAuthStateManager, PostgresOAuthAccountRepository,
-    PostgresUserRepository, UserService,
+    AccountLinkingService, OAuthStateManager, PgUserRepository, PostgresOAuthAccountRepository,
+    UserService,
     auth_handlers::{AuthState, OAuthConfig, auth_router},
 };
 
 pub fn router(state: &crate::state::AppState) -> Router {
     // Use PostgreSQL repository with database connection
-    let user_repository = PostgresUserRepository::new(state.db.clone());
+    let user_repository = PgUserRepository::new(state.db.clone());
     let oauth_repository = PostgresOAuthAccountRepository::new(state.db.clone());
     let service = UserService::new(user_repository.clone());
 
@@ -23,7 +23,10 @@ pub fn routes(state: &crate::state::AppState) -> Router {
     use domain_projects::ApiResource;
 
     let rl = &state.config.rate_limit;
-    let standard = RateLimitTier::new("standard", rl.requests_per_window, rl.window_secs);
+    let mut standard = RateLimitTier::new("standard", rl.requests_per_window, rl.window_secs);
+    if let Some(limit) = rl.authenticated_requests_per_window {
+        standard = standard.with_authenticated_limit(limit);
+    }
     let vector_tier = RateLimitTier::new(
         "vector",
         state.config.rate_limit_vector_requests,
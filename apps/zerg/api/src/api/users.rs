@@ -1,9 +1,9 @@
 use axum::Router;
-use domain_users::{PostgresUserRepository, UserService, handlers};
+use domain_users::{PgUserRepository, UserService, handlers};
 
 pub fn router(state: &crate::state::AppState) -> Router {
     // Use PostgreSQL repository with database connection
-    let repository = PostgresUserRepository::new(state.db.clone());
+    let repository = PgUserRepository::new(state.db.clone());
     let service = UserService::new(repository.clone());
 
     // Return CRUD router (auth is now in separate /auth module)
@@ -8,13 +8,14 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use domain_tasks::{
-    CreateTask, TaskFilter, TaskRepository, TaskService, UpdateTask, conversions as conv,
+    CreateTask, TaskError, TaskFilter, TaskRepository, TaskService, UpdateTask, conversions as conv,
 };
 use grpc_client::ToTonicResult;
 use rpc::tasks::{
-    CreateRequest, CreateResponse, DeleteByIdRequest, DeleteByIdResponse, GetByIdRequest,
-    GetByIdResponse, ListRequest, ListResponse, ListStreamRequest, ListStreamResponse,
-    UpdateByIdRequest, UpdateByIdResponse, tasks_service_server::TasksService,
+    BatchCreateRequest, BatchCreateResponse, CreateRequest, CreateResponse, DeleteByIdRequest,
+    DeleteByIdResponse, GetByIdRequest, GetByIdResponse, ListRequest, ListResponse,
+    ListStreamRequest, ListStreamResponse, UpdateByIdRequest, UpdateByIdResponse,
+    tasks_service_server::TasksService,
 };
 use tokio_stream::Stream;
 use tonic::{Request, Response, Status};
@@ -113,6 +114,8 @@ where
             status: req.status.map(|s| s.try_into()).transpose().to_tonic()?,
             priority: req.priority.map(|p| p.try_into()).transpose().to_tonic()?,
             completed: req.completed,
+            query: None,
+            include_deleted: false,
             limit: req.limit as usize,
             offset: req.offset as usize,
         };
@@ -137,6 +140,8 @@ where
             status: req.status.map(|s| s.try_into()).transpose().to_tonic()?,
             priority: req.priority.map(|p| p.try_into()).transpose().to_tonic()?,
             completed: req.completed,
+            query: None,
+            include_deleted: false,
             limit: req.limit as usize,
             offset: 0,
         };
@@ -148,13 +153,44 @@ where
         let stream = tokio_stream::iter(tasks.into_iter().map(|task| Ok(task.into())));
         Ok(Response::new(Box::pin(stream)))
     }
+
+    async fn batch_create(
+        &self,
+        request: Request<BatchCreateRequest>,
+    ) -> Result<Response<BatchCreateResponse>, Status> {
+        let inputs = request
+            .into_inner()
+            .tasks
+            .into_iter()
+            .enumerate()
+            .map(|(index, req)| {
+                CreateTask::try_from(req).map_err(|e| format!("Batch item {}: {}", index, e))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .to_tonic()?;
+        let tasks = self
+            .service
+            .create_tasks_batch(inputs)
+            .await
+            .map_err(|e| match e {
+                TaskError::BatchItem { ref source, .. }
+                    if matches!(**source, TaskError::Validation(_)) =>
+                {
+                    Status::invalid_argument(e.to_string())
+                }
+                e => Status::internal(e.to_string()),
+            })?;
+        info!("Created batch of {} tasks", tasks.len());
+        let data: Vec<CreateResponse> = tasks.into_iter().map(|task| task.into()).collect();
+        Ok(Response::new(BatchCreateResponse { data }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::Utc;
-    use domain_tasks::{Task, TaskError, TaskPriority, TaskStatus};
+    use domain_tasks::{Task, TaskPriority, TaskStatus};
     use std::collections::HashMap;
     use std::sync::Mutex;
     use uuid::Uuid;
@@ -184,29 +220,54 @@ mod tests {
     #[tonic::async_trait]
     impl TaskRepository for MockTaskRepository {
         async fn create(&self, input: CreateTask) -> Result<Task, TaskError> {
+            self.create_with_id(Uuid::new_v4(), input).await
+        }
+
+        async fn create_with_id(&self, id: Uuid, input: CreateTask) -> Result<Task, TaskError> {
             let task = Task {
-                id: Uuid::new_v4(),
+                id,
                 title: input.title,
                 description: input.description,
                 completed: false,
                 project_id: input.project_id,
+                parent_id: input.parent_id,
                 priority: input.priority,
                 status: input.status,
                 due_date: input.due_date,
+                recurrence: input.recurrence,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
+                deleted_at: None,
             };
             self.tasks.lock().unwrap().insert(task.id, task.clone());
             Ok(task)
         }
 
+        async fn create_batch(&self, inputs: Vec<CreateTask>) -> Result<Vec<Task>, TaskError> {
+            // In-memory creates can't fail, so the batch is trivially atomic
+            let mut tasks = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                tasks.push(self.create(input).await?);
+            }
+            Ok(tasks)
+        }
+
         async fn get_by_id(&self, id: Uuid) -> Result<Option<Task>, TaskError> {
-            Ok(self.tasks.lock().unwrap().get(&id).cloned())
+            Ok(self
+                .tasks
+                .lock()
+                .unwrap()
+                .get(&id)
+                .filter(|task| task.deleted_at.is_none())
+                .cloned())
         }
 
         async fn update(&self, id: Uuid, input: UpdateTask) -> Result<Task, TaskError> {
             let mut tasks = self.tasks.lock().unwrap();
-            let task = tasks.get_mut(&id).ok_or(TaskError::NotFound(id))?;
+            let task = tasks
+                .get_mut(&id)
+                .filter(|task| task.deleted_at.is_none())
+                .ok_or(TaskError::NotFound(id))?;
 
             if let Some(title) = input.title {
                 task.title = title;
@@ -220,6 +281,9 @@ mod tests {
             if let Some(project_id) = input.project_id {
                 task.project_id = project_id;
             }
+            if let Some(parent_id) = input.parent_id {
+                task.parent_id = parent_id;
+            }
             if let Some(priority) = input.priority {
                 task.priority = priority;
             }
@@ -229,13 +293,77 @@ mod tests {
             if let Some(due_date) = input.due_date {
                 task.due_date = due_date;
             }
+            if let Some(recurrence) = input.recurrence {
+                task.recurrence = recurrence;
+            }
             task.updated_at = Utc::now();
 
             Ok(task.clone())
         }
 
         async fn delete(&self, id: Uuid) -> Result<bool, TaskError> {
-            Ok(self.tasks.lock().unwrap().remove(&id).is_some())
+            let mut tasks = self.tasks.lock().unwrap();
+            match tasks.get_mut(&id) {
+                Some(task) if task.deleted_at.is_none() => {
+                    task.deleted_at = Some(Utc::now());
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn delete_subtree(&self, id: Uuid) -> Result<u64, TaskError> {
+            let mut tasks = self.tasks.lock().unwrap();
+            let now = Utc::now();
+            let mut pending = vec![id];
+            let mut deleted = 0;
+            while let Some(id) = pending.pop() {
+                match tasks.get_mut(&id) {
+                    Some(task) if task.deleted_at.is_none() => {
+                        task.deleted_at = Some(now);
+                        deleted += 1;
+                    }
+                    _ => continue,
+                }
+                pending.extend(
+                    tasks
+                        .values()
+                        .filter(|task| task.parent_id == Some(id) && task.deleted_at.is_none())
+                        .map(|task| task.id),
+                );
+            }
+            Ok(deleted)
+        }
+
+        async fn restore(&self, id: Uuid) -> Result<bool, TaskError> {
+            let mut tasks = self.tasks.lock().unwrap();
+            match tasks.get_mut(&id) {
+                Some(task) if task.deleted_at.is_some() => {
+                    task.deleted_at = None;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn list_deleted(&self, filter: TaskFilter) -> Result<Vec<Task>, TaskError> {
+            let mut deleted: Vec<Task> = self
+                .list(TaskFilter {
+                    include_deleted: true,
+                    limit: usize::MAX,
+                    offset: 0,
+                    ..filter.clone()
+                })
+                .await?
+                .into_iter()
+                .filter(|task| task.deleted_at.is_some())
+                .collect();
+            deleted.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+            Ok(deleted
+                .into_iter()
+                .skip(filter.offset)
+                .take(filter.limit)
+                .collect())
         }
 
         async fn list(&self, filter: TaskFilter) -> Result<Vec<Task>, TaskError> {
@@ -243,6 +371,9 @@ mod tests {
             let mut result: Vec<Task> = tasks
                 .values()
                 .filter(|task| {
+                    if !filter.include_deleted && task.deleted_at.is_some() {
+                        return false;
+                    }
                     if let Some(project_id) = filter.project_id
                         && task.project_id != Some(project_id)
                     {
@@ -263,12 +394,21 @@ mod tests {
                     {
                         return false;
                     }
+                    if let Some(ref query) = filter.query
+                        && task.text_match_score(query).is_none()
+                    {
+                        return false;
+                    }
                     true
                 })
                 .cloned()
                 .collect();
 
             result.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+            if let Some(ref query) = filter.query {
+                // Stable sort keeps newest-first within equal scores
+                result.sort_by_key(|task| std::cmp::Reverse(task.text_match_score(query)));
+            }
             Ok(result
                 .into_iter()
                 .skip(filter.offset)
@@ -277,7 +417,26 @@ mod tests {
         }
 
         async fn count(&self) -> Result<usize, TaskError> {
-            Ok(self.tasks.lock().unwrap().len())
+            Ok(self
+                .tasks
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|task| task.deleted_at.is_none())
+                .count())
+        }
+
+        async fn list_children(&self, parent_id: Uuid) -> Result<Vec<Task>, TaskError> {
+            let mut children: Vec<Task> = self
+                .tasks
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|task| task.deleted_at.is_none() && task.parent_id == Some(parent_id))
+                .cloned()
+                .collect();
+            children.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+            Ok(children)
         }
 
         async fn count_by_project(&self, project_id: Uuid) -> Result<usize, TaskError> {
@@ -286,7 +445,7 @@ mod tests {
                 .lock()
                 .unwrap()
                 .values()
-                .filter(|task| task.project_id == Some(project_id))
+                .filter(|task| task.deleted_at.is_none() && task.project_id == Some(project_id))
                 .count())
         }
     }
@@ -304,11 +463,14 @@ mod tests {
             description: "Test Description".to_string(),
             completed: false,
             project_id: None,
+            parent_id: None,
             priority: TaskPriority::Medium,
             status: TaskStatus::Todo,
             due_date: None,
+            recurrence: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
         }
     }
 
@@ -355,6 +517,63 @@ mod tests {
         assert_eq!(response.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
 
+    fn batch_item(title: &str) -> CreateRequest {
+        CreateRequest {
+            title: title.to_string(),
+            description: String::new(),
+            project_id: None,
+            priority: 2,
+            status: 1,
+            due_date: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_success() {
+        let repository = MockTaskRepository::new();
+        let service = TasksServiceImpl::new(TaskService::new(repository.clone()));
+
+        let request = Request::new(BatchCreateRequest {
+            tasks: vec![batch_item("One"), batch_item("Two"), batch_item("Three")],
+        });
+
+        let response = service.batch_create(request).await.unwrap().into_inner();
+        let titles: Vec<_> = response.data.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["One", "Two", "Three"]);
+        assert_eq!(repository.tasks.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_invalid_item_persists_nothing() {
+        let repository = MockTaskRepository::new();
+        let service = TasksServiceImpl::new(TaskService::new(repository.clone()));
+
+        let request = Request::new(BatchCreateRequest {
+            tasks: vec![batch_item("One"), batch_item(""), batch_item("Three")],
+        });
+
+        let status = service.batch_create(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("Batch item 1"));
+        assert!(repository.tasks.lock().unwrap().is_empty());
+
+        // Items that can't even be converted are rejected the same way
+        let request = Request::new(BatchCreateRequest {
+            tasks: vec![
+                batch_item("One"),
+                CreateRequest {
+                    priority: 999,
+                    ..batch_item("Two")
+                },
+            ],
+        });
+
+        let status = service.batch_create(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("Batch item 1"));
+        assert!(repository.tasks.lock().unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_task_success() {
         let task = create_test_task();
@@ -223,6 +223,7 @@ where
                     }
                 }),
                 namespace_filter: filter.namespace_filter,
+                ..Default::default()
             });
         }
 
@@ -458,6 +459,7 @@ where
                     }
                 }),
                 namespace_filter: f.namespace_filter,
+                ..Default::default()
             });
         }
 
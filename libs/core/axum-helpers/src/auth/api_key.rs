@@ -0,0 +1,110 @@
+use redis::{AsyncCommands, RedisResult, aio::ConnectionManager};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Header carrying the API key
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Prefix on issued keys, so they are recognizable in logs and secret scanners
+const API_KEY_PREFIX: &str = "ak_";
+
+/// Identity attached to requests authenticated with an API key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKeyContext {
+    pub key_id: String, // Stable ID (for revocation and audit logs)
+    pub name: String,   // Owning service, e.g. "billing-worker"
+    pub role: String,   // Role granted to the key
+}
+
+/// Redis-backed API key store
+///
+/// Only the SHA-256 of each key is stored; the plaintext is returned once
+/// from [`ApiKeyStore::create_key`] and can't be recovered afterwards.
+#[derive(Clone)]
+pub struct ApiKeyStore {
+    client: ConnectionManager,
+}
+
+impl ApiKeyStore {
+    pub fn new(manager: ConnectionManager) -> Self {
+        tracing::info!("Redis API key store initialized");
+        Self { client: manager }
+    }
+
+    /// Issue a new key, returning the plaintext key and its context
+    pub async fn create_key(
+        &mut self,
+        name: &str,
+        role: &str,
+    ) -> RedisResult<(String, ApiKeyContext)> {
+        let key = format!(
+            "{}{}{}",
+            API_KEY_PREFIX,
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+        let context = ApiKeyContext {
+            key_id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            role: role.to_string(),
+        };
+
+        let hash = hash_key(&key);
+        let payload = serde_json::to_string(&context)
+            .map_err(|e| redis::RedisError::from(std::io::Error::other(e)))?;
+
+        let _: () = redis::pipe()
+            .atomic()
+            .set(format!("apikey:hash:{}", hash), payload)
+            .ignore()
+            .set(format!("apikey:id:{}", context.key_id), &hash)
+            .ignore()
+            .query_async(&mut self.client)
+            .await?;
+
+        tracing::info!(key_id = %context.key_id, name = %context.name, "Created API key");
+        Ok((key, context))
+    }
+
+    /// Look up the context for a plaintext key
+    pub async fn lookup(&mut self, key: &str) -> RedisResult<Option<ApiKeyContext>> {
+        let payload: Option<String> = self
+            .client
+            .get(format!("apikey:hash:{}", hash_key(key)))
+            .await?;
+
+        Ok(payload.and_then(|p| match serde_json::from_str(&p) {
+            Ok(context) => Some(context),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable API key record: {}", e);
+                None
+            }
+        }))
+    }
+
+    /// Revoke a key by ID. Returns whether the key existed.
+    pub async fn revoke_key(&mut self, key_id: &str) -> RedisResult<bool> {
+        let id_key = format!("apikey:id:{}", key_id);
+        let hash: Option<String> = self.client.get(&id_key).await?;
+        let Some(hash) = hash else {
+            return Ok(false);
+        };
+
+        let _: () = redis::pipe()
+            .atomic()
+            .del(format!("apikey:hash:{}", hash))
+            .ignore()
+            .del(&id_key)
+            .ignore()
+            .query_async(&mut self.client)
+            .await?;
+
+        tracing::info!(key_id = %key_id, "Revoked API key");
+        Ok(true)
+    }
+}
+
+fn hash_key(key: &str) -> String {
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
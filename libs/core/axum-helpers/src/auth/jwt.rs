@@ -1,5 +1,6 @@
 use super::config::JwtConfig;
-use super::store::RedisAuthStore;
+use super::store::{RedisAuthStore, RefreshTokenState};
+use crate::errors::{AppError, ErrorCode};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use redis::aio::ConnectionManager;
@@ -20,6 +21,8 @@ pub struct JwtClaims {
     pub exp: i64,           // Expiration time
     pub iat: i64,           // Issued at
     pub jti: String,        // JWT ID (for whitelist/blacklist)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fid: Option<String>, // Refresh-token family ID (refresh tokens only)
 }
 
 /// Hybrid JWT + Redis authentication
@@ -61,10 +64,11 @@ impl JwtRedisAuth {
         name: &str,
         roles: &[String],
     ) -> eyre::Result<String> {
-        self.create_token(user_id, email, name, roles, ACCESS_TOKEN_TTL)
+        self.create_token(user_id, email, name, roles, ACCESS_TOKEN_TTL, None)
+            .map(|(token, _)| token)
     }
 
-    /// Create refresh token (7 days)
+    /// Create refresh token (7 days), starting a new token family
     pub fn create_refresh_token(
         &self,
         user_id: &str,
@@ -72,10 +76,19 @@ impl JwtRedisAuth {
         name: &str,
         roles: &[String],
     ) -> eyre::Result<String> {
-        self.create_token(user_id, email, name, roles, REFRESH_TOKEN_TTL)
+        let family_id = Uuid::new_v4().to_string();
+        self.create_token(
+            user_id,
+            email,
+            name,
+            roles,
+            REFRESH_TOKEN_TTL,
+            Some(family_id),
+        )
+        .map(|(token, _)| token)
     }
 
-    /// Create JWT token with specified TTL
+    /// Create JWT token with specified TTL, returning the token and its `jti`
     fn create_token(
         &self,
         user_id: &str,
@@ -83,7 +96,8 @@ impl JwtRedisAuth {
         name: &str,
         roles: &[String],
         ttl_seconds: i64,
-    ) -> eyre::Result<String> {
+        family_id: Option<String>,
+    ) -> eyre::Result<(String, String)> {
         let now = Utc::now();
         let exp = (now + Duration::seconds(ttl_seconds)).timestamp();
         let iat = now.timestamp();
@@ -96,7 +110,8 @@ impl JwtRedisAuth {
             roles: roles.to_vec(),
             exp,
             iat,
-            jti,
+            jti: jti.clone(),
+            fid: family_id,
         };
 
         let header = Header {
@@ -110,7 +125,7 @@ impl JwtRedisAuth {
             &EncodingKey::from_secret(self.secret.as_bytes()),
         )?;
 
-        Ok(token)
+        Ok((token, jti))
     }
 
     /// Verify JWT token signature and decode claims
@@ -162,6 +177,93 @@ impl JwtRedisAuth {
             .map_err(|e| eyre::eyre!("Failed to check blacklist: {}", e))
     }
 
+    /// Rotate a refresh token, returning a new `(access, refresh)` pair.
+    ///
+    /// The presented token is consumed; the new refresh token stays in the
+    /// same family. Presenting a token that was already rotated means it
+    /// leaked, so the whole family is revoked and `AppError::TokenReuse`
+    /// is returned.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<(String, String), AppError> {
+        let claims = self.verify_token(refresh_token).map_err(|e| {
+            tracing::debug!("Refresh token verification failed: {}", e);
+            AppError::Unauthorized("Invalid or expired refresh token".to_string())
+        })?;
+
+        let Some(family_id) = claims.fid.clone() else {
+            return Err(AppError::Unauthorized("Not a refresh token".to_string()));
+        };
+
+        let remaining = (claims.exp - Utc::now().timestamp()).max(1) as u64;
+        let mut store = self.store.clone();
+
+        let state = store
+            .consume_refresh_token(&claims.jti, remaining)
+            .await
+            .map_err(redis_error)?;
+
+        match state {
+            RefreshTokenState::Rotated => {}
+            RefreshTokenState::Reused => {
+                let revoked = store
+                    .revoke_token_family(&family_id, REFRESH_TOKEN_TTL as u64)
+                    .await
+                    .map_err(redis_error)?;
+                tracing::warn!(
+                    user_id = %claims.sub,
+                    family_id = %family_id,
+                    revoked,
+                    "Refresh token reuse detected, revoked token family"
+                );
+                return Err(AppError::TokenReuse(
+                    ErrorCode::TokenReuse.default_message().to_string(),
+                ));
+            }
+            RefreshTokenState::Unknown => {
+                return Err(AppError::Unauthorized(
+                    "Refresh token has been revoked".to_string(),
+                ));
+            }
+        }
+
+        let (access_token, access_jti) = self
+            .create_token(
+                &claims.sub,
+                &claims.email,
+                &claims.name,
+                &claims.roles,
+                ACCESS_TOKEN_TTL,
+                None,
+            )
+            .map_err(token_error)?;
+        let (new_refresh_token, refresh_jti) = self
+            .create_token(
+                &claims.sub,
+                &claims.email,
+                &claims.name,
+                &claims.roles,
+                REFRESH_TOKEN_TTL,
+                Some(family_id.clone()),
+            )
+            .map_err(token_error)?;
+
+        for (jti, ttl) in [
+            (&access_jti, ACCESS_TOKEN_TTL),
+            (&refresh_jti, REFRESH_TOKEN_TTL),
+        ] {
+            store
+                .store_jwt_whitelist(jti, &claims.sub, ttl as u64)
+                .await
+                .map_err(redis_error)?;
+            store
+                .add_to_token_family(&family_id, jti, REFRESH_TOKEN_TTL as u64)
+                .await
+                .map_err(redis_error)?;
+        }
+
+        tracing::debug!(user_id = %claims.sub, family_id = %family_id, "Rotated refresh token");
+        Ok((access_token, new_refresh_token))
+    }
+
     /// Remove token from whitelist (on logout/refresh)
     pub async fn revoke_token(&self, jti: &str) -> eyre::Result<()> {
         let mut store = self.store.clone();
@@ -172,3 +274,13 @@ impl JwtRedisAuth {
         Ok(())
     }
 }
+
+fn redis_error(e: redis::RedisError) -> AppError {
+    tracing::error!("Redis error during token refresh: {}", e);
+    AppError::InternalServerError("Failed to refresh token".to_string())
+}
+
+fn token_error(e: eyre::Report) -> AppError {
+    tracing::error!("Failed to issue token during refresh: {:?}", e);
+    AppError::InternalServerError("Failed to refresh token".to_string())
+}
@@ -1,4 +1,6 @@
+use super::api_key::{API_KEY_HEADER, ApiKeyStore};
 use super::jwt::JwtRedisAuth;
+use crate::errors::AppError;
 use axum::{
     extract::{Request, State},
     http::{HeaderMap, StatusCode},
@@ -140,3 +142,51 @@ pub async fn optional_jwt_auth_middleware(
 
     next.run(request).await
 }
+
+/// API key authentication middleware
+///
+/// For service-to-service calls. Reads the `X-API-Key` header, looks the key
+/// up in Redis and inserts its `ApiKeyContext` into request extensions.
+/// Missing or unknown keys get a 401.
+///
+/// # Example
+///
+/// ```ignore
+/// use axum_helpers::auth::{ApiKeyStore, api_key_auth_middleware};
+///
+/// let internal_routes = Router::new()
+///     .route("/internal/sync", post(sync_handler))
+///     .layer(axum::middleware::from_fn_with_state(
+///         ApiKeyStore::new(redis_manager),
+///         api_key_auth_middleware,
+///     ));
+/// ```
+pub async fn api_key_auth_middleware(
+    State(mut store): State<ApiKeyStore>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let key = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| AppError::Unauthorized("No API key provided".to_string()))?;
+
+    let context = store
+        .lookup(&key)
+        .await
+        .map_err(|e| {
+            tracing::error!("Redis error checking API key: {}", e);
+            AppError::ServiceUnavailable("Service temporarily unavailable".to_string())
+        })?
+        .ok_or_else(|| {
+            tracing::debug!("Unknown or revoked API key");
+            AppError::Unauthorized("Invalid API key".to_string())
+        })?;
+
+    tracing::debug!(key_id = %context.key_id, role = %context.role, "API key authenticated");
+    request.extensions_mut().insert(context);
+    Ok(next.run(request).await)
+}
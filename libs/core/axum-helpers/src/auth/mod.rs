@@ -2,6 +2,7 @@
 //!
 //! This module provides:
 //! - JWT token creation and verification with Redis-backed whitelist/blacklist
+//! - Hashed, Redis-backed API keys for service-to-service calls
 //! - Session user types for axum-login integration
 //! - Authentication middleware for protected routes
 //!
@@ -21,13 +22,15 @@
 //!     .layer(axum::middleware::from_fn_with_state(auth, jwt_auth_middleware));
 //! ```
 
+pub mod api_key;
 pub mod config;
 pub mod jwt;
 pub mod middleware;
 pub mod store;
 
 // Re-export commonly used types
+pub use api_key::{API_KEY_HEADER, ApiKeyContext, ApiKeyStore};
 pub use config::JwtConfig;
 pub use jwt::{ACCESS_TOKEN_TTL, JwtClaims, JwtRedisAuth, REFRESH_TOKEN_TTL};
-pub use middleware::{jwt_auth_middleware, optional_jwt_auth_middleware};
-pub use store::RedisAuthStore;
+pub use middleware::{api_key_auth_middleware, jwt_auth_middleware, optional_jwt_auth_middleware};
+pub use store::{RedisAuthStore, RefreshTokenState};
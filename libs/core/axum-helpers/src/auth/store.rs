@@ -1,5 +1,16 @@
 use redis::{AsyncCommands, RedisResult, aio::ConnectionManager};
 
+/// Outcome of presenting a refresh token for rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTokenState {
+    /// Token was whitelisted and has now been consumed
+    Rotated,
+    /// Token was already rotated once before
+    Reused,
+    /// Token was never issued, or was revoked
+    Unknown,
+}
+
 /// Redis-backed store for JWT authentication
 /// Handles whitelist/blacklist for tokens and CSRF tokens
 #[derive(Clone)]
@@ -57,6 +68,94 @@ impl RedisAuthStore {
         Ok(())
     }
 
+    /// Consume a whitelisted refresh token, remembering it as used.
+    ///
+    /// Runs as a single script so two concurrent refreshes with the same
+    /// token can't both succeed.
+    pub async fn consume_refresh_token(
+        &mut self,
+        jti: &str,
+        ttl_seconds: u64,
+    ) -> RedisResult<RefreshTokenState> {
+        let whitelist_key = format!("jwt:whitelist:{}", jti);
+        let used_key = format!("jwt:refresh:used:{}", jti);
+
+        let script = redis::Script::new(
+            r"
+            if redis.call('exists', KEYS[1]) == 1 then
+                redis.call('del', KEYS[1])
+                redis.call('set', KEYS[2], '1', 'EX', ARGV[1])
+                return 1
+            elseif redis.call('exists', KEYS[2]) == 1 then
+                return 2
+            else
+                return 0
+            end
+            ",
+        );
+
+        let result: i32 = script
+            .key(&whitelist_key)
+            .key(&used_key)
+            .arg(ttl_seconds)
+            .invoke_async(&mut self.client)
+            .await?;
+
+        Ok(match result {
+            1 => RefreshTokenState::Rotated,
+            2 => RefreshTokenState::Reused,
+            _ => RefreshTokenState::Unknown,
+        })
+    }
+
+    /// Record a token as belonging to a refresh-token family
+    pub async fn add_to_token_family(
+        &mut self,
+        family_id: &str,
+        jti: &str,
+        ttl_seconds: u64,
+    ) -> RedisResult<()> {
+        let key = format!("jwt:family:{}", family_id);
+        let _: () = redis::pipe()
+            .atomic()
+            .sadd(&key, jti)
+            .ignore()
+            .expire(&key, ttl_seconds as i64)
+            .ignore()
+            .query_async(&mut self.client)
+            .await?;
+        Ok(())
+    }
+
+    /// Revoke every token in a family: drop it from the whitelist and
+    /// blacklist it. Returns how many tokens were revoked.
+    pub async fn revoke_token_family(
+        &mut self,
+        family_id: &str,
+        ttl_seconds: u64,
+    ) -> RedisResult<usize> {
+        let key = format!("jwt:family:{}", family_id);
+
+        let script = redis::Script::new(
+            r"
+            local members = redis.call('smembers', KEYS[1])
+            for _, jti in ipairs(members) do
+                redis.call('del', 'jwt:whitelist:' .. jti)
+                redis.call('set', 'jwt:blacklist:' .. jti, '1', 'EX', ARGV[1])
+            end
+            redis.call('del', KEYS[1])
+            return #members
+            ",
+        );
+
+        let revoked: usize = script
+            .key(&key)
+            .arg(ttl_seconds)
+            .invoke_async(&mut self.client)
+            .await?;
+        Ok(revoked)
+    }
+
     /// Store CSRF token with TTL
     pub async fn store_csrf_token(&mut self, token: &str, ttl_seconds: u64) -> RedisResult<()> {
         let key = format!("csrf:{}", token);
@@ -66,6 +66,15 @@ pub enum ErrorCode {
     /// Rate limit exceeded
     RateLimitExceeded,
 
+    /// An already-rotated refresh token was presented again
+    TokenReuse,
+
+    /// A conditional request's precondition (e.g. `If-Match`) did not hold
+    PreconditionFailed,
+
+    /// Request body exceeds the configured size limit
+    PayloadTooLarge,
+
     // Database errors (2000-2999)
     /// Database query returned no results
     DatabaseNotFound,
@@ -159,6 +168,9 @@ impl ErrorCode {
             Self::InternalError => "INTERNAL_ERROR",
             Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
             Self::RateLimitExceeded => "RATE_LIMIT_EXCEEDED",
+            Self::TokenReuse => "TOKEN_REUSE",
+            Self::PreconditionFailed => "PRECONDITION_FAILED",
+            Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
             Self::DatabaseNotFound => "DATABASE_NOT_FOUND",
             Self::DatabaseConfig => "DATABASE_CONFIG",
             Self::DatabaseError => "DATABASE_ERROR",
@@ -215,6 +227,9 @@ impl ErrorCode {
             Self::InvalidJson => 1010,
             Self::ServiceUnavailable => 1011,
             Self::RateLimitExceeded => 1012,
+            Self::TokenReuse => 1013,
+            Self::PreconditionFailed => 1014,
+            Self::PayloadTooLarge => 1015,
 
             // Database errors (2000-2999)
             Self::DatabaseNotFound => 2001,
@@ -275,6 +290,9 @@ impl ErrorCode {
             Self::InternalError => "An internal server error occurred",
             Self::ServiceUnavailable => "Service is temporarily unavailable",
             Self::RateLimitExceeded => "Rate limit exceeded",
+            Self::TokenReuse => "Refresh token reuse detected",
+            Self::PreconditionFailed => "Precondition failed",
+            Self::PayloadTooLarge => "Request body is too large",
             Self::DatabaseNotFound => "Database record not found",
             Self::DatabaseConfig => "Database configuration error",
             Self::DatabaseError => "Database error occurred",
@@ -321,6 +339,7 @@ mod tests {
         assert_eq!(ErrorCode::ValidationError.code(), 1001);
         assert_eq!(ErrorCode::DatabaseError.code(), 2003);
         assert_eq!(ErrorCode::MigrationError.code(), 3001);
+        assert_eq!(ErrorCode::TokenReuse.code(), 1013);
     }
 
     #[test]
@@ -104,6 +104,15 @@ pub enum AppError {
 
     #[error("Too Many Requests: {0}")]
     TooManyRequests(String),
+
+    #[error("Token reuse detected: {0}")]
+    TokenReuse(String),
+
+    #[error("Precondition Failed: {0}")]
+    PreconditionFailed(String),
+
+    #[error("Payload Too Large: {0}")]
+    PayloadTooLarge(String),
 }
 
 impl IntoResponse for AppError {
@@ -288,6 +297,44 @@ impl IntoResponse for AppError {
                     ErrorCode::RateLimitExceeded,
                 )
             }
+            AppError::TokenReuse(msg) => {
+                tracing::warn!(
+                    error_code = ErrorCode::TokenReuse.code(),
+                    "Token reuse detected: {}",
+                    msg
+                );
+                (
+                    StatusCode::UNAUTHORIZED,
+                    "Unauthorized",
+                    msg,
+                    None,
+                    ErrorCode::TokenReuse,
+                )
+            }
+            AppError::PreconditionFailed(msg) => {
+                tracing::info!("Precondition failed: {}", msg);
+                (
+                    StatusCode::PRECONDITION_FAILED,
+                    "PreconditionFailed",
+                    msg,
+                    None,
+                    ErrorCode::PreconditionFailed,
+                )
+            }
+            AppError::PayloadTooLarge(msg) => {
+                tracing::info!(
+                    error_code = ErrorCode::PayloadTooLarge.code(),
+                    "Payload too large: {}",
+                    msg
+                );
+                (
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    "PayloadTooLarge",
+                    msg,
+                    None,
+                    ErrorCode::PayloadTooLarge,
+                )
+            }
         };
 
         let body = Json(ErrorResponse {
@@ -3,8 +3,10 @@
 //! This module provides reusable extractors that reduce boilerplate
 //! and standardize error handling across your API.
 
+pub mod pagination;
 pub mod uuid_path;
 pub mod validated_json;
 
+pub use pagination::{Pagination, PaginationConfig};
 pub use uuid_path::UuidPath;
 pub use validated_json::ValidatedJson;
@@ -0,0 +1,285 @@
+//! Pagination query extractor with bounds checking and `Link` header helpers.
+
+use crate::errors::{ErrorCode, ErrorResponse};
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::{StatusCode, request::Parts},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+/// Page size and cap used by the [`Pagination`] extractor.
+///
+/// Insert as a request extension to override the defaults for a router:
+/// `router.layer(Extension(PaginationConfig { max_per_page: 500, ..Default::default() }))`.
+#[derive(Clone, Copy, Debug)]
+pub struct PaginationConfig {
+    /// Page size when the request doesn't specify one
+    pub default_per_page: u64,
+    /// Larger requested page sizes are clamped to this
+    pub max_per_page: u64,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            default_per_page: 20,
+            max_per_page: 100,
+        }
+    }
+}
+
+/// Raw query parameters; signed so negative values can be reported rather
+/// than surfacing as a generic deserialization failure.
+#[derive(Debug, Default, Deserialize)]
+struct PaginationQuery {
+    page: Option<i64>,
+    per_page: Option<i64>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+/// Extractor for `?page=&per_page=` or `?limit=&offset=` query parameters.
+///
+/// `page` is 1-based and takes precedence over `offset`; `per_page` and
+/// `limit` are synonyms. Page sizes above the configured maximum are
+/// clamped, while zero or negative values are rejected with a 400
+/// `VALIDATION_ERROR` response.
+///
+/// # Example
+/// ```ignore
+/// use axum::Router;
+/// use axum::routing::get;
+/// use axum_helpers::extractors::Pagination;
+///
+/// async fn list_users(pagination: Pagination) -> String {
+///     format!("limit={} offset={}", pagination.limit, pagination.offset)
+/// }
+///
+/// let app = Router::new().route("/users", get(list_users));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pagination {
+    /// Maximum number of items to return
+    pub limit: u64,
+    /// Number of items to skip
+    pub offset: u64,
+}
+
+impl Pagination {
+    /// 1-based page number containing `offset`
+    pub fn page(&self) -> u64 {
+        self.offset / self.limit + 1
+    }
+
+    /// Build an RFC 5988 `Link` header value for a collection of `total` items.
+    ///
+    /// `base` is the collection URL (it may already carry a query string);
+    /// links use `page`/`per_page`. `prev`/`next` are omitted at the ends.
+    pub fn link_header(&self, base: &str, total: u64) -> String {
+        let last = total.div_ceil(self.limit).max(1);
+        let page = self.page();
+        let separator = if base.contains('?') { '&' } else { '?' };
+        let link = |page: u64, rel: &str| {
+            format!(
+                "<{}{}page={}&per_page={}>; rel=\"{}\"",
+                base, separator, page, self.limit, rel
+            )
+        };
+
+        let mut links = vec![link(1, "first")];
+        if page > 1 {
+            links.push(link((page - 1).min(last), "prev"));
+        }
+        if page < last {
+            links.push(link(page + 1, "next"));
+        }
+        links.push(link(last, "last"));
+
+        links.join(", ")
+    }
+
+    /// Resolve the raw query, returning per-field errors for invalid values.
+    fn from_query(
+        query: PaginationQuery,
+        config: PaginationConfig,
+    ) -> Result<Self, serde_json::Map<String, serde_json::Value>> {
+        let mut errors = serde_json::Map::new();
+        let mut positive = |field: &str, value: Option<i64>| match value {
+            Some(v) if v < 1 => {
+                errors.insert(field.to_string(), "must be at least 1".into());
+                None
+            }
+            v => v.map(|v| v as u64),
+        };
+
+        let page = positive("page", query.page);
+        let per_page = positive("per_page", query.per_page);
+        let limit = positive("limit", query.limit);
+        if let Some(offset) = query.offset
+            && offset < 0
+        {
+            errors.insert("offset".to_string(), "must not be negative".into());
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let limit = per_page
+            .or(limit)
+            .unwrap_or(config.default_per_page)
+            .min(config.max_per_page)
+            .max(1);
+        let offset = match page {
+            Some(page) => (page - 1).saturating_mul(limit),
+            None => query.offset.unwrap_or(0) as u64,
+        };
+
+        Ok(Self { limit, offset })
+    }
+}
+
+fn validation_error(details: serde_json::Map<String, serde_json::Value>) -> Response {
+    let error_response = ErrorResponse {
+        code: ErrorCode::ValidationError.code(),
+        error: ErrorCode::ValidationError.as_str().to_string(),
+        message: "Invalid pagination parameters".to_string(),
+        details: Some(serde_json::Value::Object(details)),
+    };
+
+    (StatusCode::BAD_REQUEST, axum::Json(error_response)).into_response()
+}
+
+impl<S> FromRequestParts<S> for Pagination
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(query) = Query::<PaginationQuery>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| {
+                let mut details = serde_json::Map::new();
+                details.insert("query".to_string(), e.body_text().into());
+                validation_error(details)
+            })?;
+
+        let config = parts
+            .extensions
+            .get::<PaginationConfig>()
+            .copied()
+            .unwrap_or_default();
+
+        Self::from_query(query, config).map_err(validation_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+
+    async fn extract(uri: &str, config: Option<PaginationConfig>) -> Result<Pagination, Response> {
+        let mut request = Request::builder().uri(uri).body(()).unwrap();
+        if let Some(config) = config {
+            request.extensions_mut().insert(config);
+        }
+        let (mut parts, _) = request.into_parts();
+        Pagination::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn test_defaults() {
+        let pagination = extract("/items", None).await.unwrap();
+        assert_eq!(
+            pagination,
+            Pagination {
+                limit: 20,
+                offset: 0
+            }
+        );
+        assert_eq!(pagination.page(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_page_and_limit_offset_forms() {
+        let pagination = extract("/items?page=3&per_page=10", None).await.unwrap();
+        assert_eq!(
+            pagination,
+            Pagination {
+                limit: 10,
+                offset: 20
+            }
+        );
+        assert_eq!(pagination.page(), 3);
+
+        let pagination = extract("/items?limit=5&offset=15", None).await.unwrap();
+        assert_eq!(
+            pagination,
+            Pagination {
+                limit: 5,
+                offset: 15
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_over_max_is_clamped() {
+        let pagination = extract("/items?per_page=1000", None).await.unwrap();
+        assert_eq!(pagination.limit, 100);
+
+        let config = PaginationConfig {
+            default_per_page: 10,
+            max_per_page: 50,
+        };
+        let pagination = extract("/items?limit=1000", Some(config)).await.unwrap();
+        assert_eq!(pagination.limit, 50);
+        let pagination = extract("/items", Some(config)).await.unwrap();
+        assert_eq!(pagination.limit, 10);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_values_are_rejected() {
+        let response = extract("/items?offset=-1", None).await.unwrap_err();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"], "VALIDATION_ERROR");
+        assert!(body["details"]["offset"].is_string());
+
+        let response = extract("/items?page=0", None).await.unwrap_err();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = extract("/items?per_page=abc", None).await.unwrap_err();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_link_header() {
+        let pagination = Pagination {
+            limit: 10,
+            offset: 10,
+        };
+        assert_eq!(
+            pagination.link_header("/api/items", 35),
+            "</api/items?page=1&per_page=10>; rel=\"first\", \
+             </api/items?page=1&per_page=10>; rel=\"prev\", \
+             </api/items?page=3&per_page=10>; rel=\"next\", \
+             </api/items?page=4&per_page=10>; rel=\"last\""
+        );
+
+        let first = Pagination {
+            limit: 10,
+            offset: 0,
+        };
+        assert_eq!(
+            first.link_header("/api/items?status=open", 0),
+            "</api/items?status=open&page=1&per_page=10>; rel=\"first\", \
+             </api/items?status=open&page=1&per_page=10>; rel=\"last\""
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! JSON extractor with automatic validation using the validator crate.
 
-use crate::errors::{ErrorCode, ErrorResponse};
+use crate::errors::{AppError, ErrorCode, ErrorResponse};
 use axum::{
     extract::{FromRequest, Json, Request},
     http::StatusCode,
@@ -46,9 +46,14 @@ where
     type Rejection = Response;
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
-        let Json(data) = Json::<T>::from_request(req, state)
-            .await
-            .map_err(|e| e.into_response())?;
+        let Json(data) = Json::<T>::from_request(req, state).await.map_err(|e| {
+            if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                AppError::PayloadTooLarge(ErrorCode::PayloadTooLarge.default_message().to_string())
+                    .into_response()
+            } else {
+                e.into_response()
+            }
+        })?;
 
         data.validate().map_err(|e| {
             // Convert validator errors to structured JSON
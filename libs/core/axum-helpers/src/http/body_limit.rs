@@ -0,0 +1,20 @@
+use axum::extract::DefaultBodyLimit;
+
+/// Cap request bodies at `max_bytes` for the routes this layer wraps.
+///
+/// Uses axum's `DefaultBodyLimit`, so body extractors reject oversized
+/// payloads; [`ValidatedJson`](crate::extractors::ValidatedJson) turns that
+/// into a `413` with the standard `ErrorResponse`. Apply it to a router or a
+/// single route to set different limits per route:
+///
+/// ```ignore
+/// use axum_helpers::http::body_limit_layer;
+///
+/// let app = Router::new()
+///     .route("/uploads", post(upload).layer(body_limit_layer(10 * 1024 * 1024)))
+///     .route("/comments", post(comment))
+///     .layer(body_limit_layer(64 * 1024));
+/// ```
+pub fn body_limit_layer(max_bytes: usize) -> DefaultBodyLimit {
+    DefaultBodyLimit::max(max_bytes)
+}
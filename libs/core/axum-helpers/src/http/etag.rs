@@ -0,0 +1,180 @@
+use crate::errors::AppError;
+use axum::{
+    extract::{FromRequestParts, Request},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+
+/// Compute a strong ETag (`"..."`) from a response body.
+pub fn strong_etag(body: &[u8]) -> String {
+    let digest = Sha256::digest(body);
+    let hex: String = digest[..16].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("\"{}\"", hex)
+}
+
+/// Compute a weak ETag (`W/"..."`) from a response body.
+pub fn weak_etag(body: &[u8]) -> String {
+    format!("W/{}", strong_etag(body))
+}
+
+/// Strong ETag of a value's JSON serialization, matching what [`ETagged`] sends.
+pub fn etag_for<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    Ok(strong_etag(&serde_json::to_vec(value)?))
+}
+
+/// Whether an `If-None-Match` header value matches `etag`.
+///
+/// Handles `*` and comma-separated lists, and uses the weak comparison
+/// (the `W/` prefix is ignored) that RFC 9110 §13.1.2 requires for
+/// `If-None-Match`.
+pub fn etag_matches(header_value: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+
+    header_value
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+/// Whether an `If-Match` header value matches `etag`.
+///
+/// Handles `*` and comma-separated lists, and uses the strong comparison
+/// RFC 9110 §13.1.1 requires for `If-Match`: weak tags never match, on
+/// either side.
+pub fn etag_matches_strong(header_value: &str, etag: &str) -> bool {
+    let etag = etag.trim();
+    let strong = !etag.starts_with("W/");
+
+    header_value.split(',').any(|candidate| {
+        let candidate = candidate.trim();
+        candidate == "*" || (strong && candidate == etag)
+    })
+}
+
+/// JSON response wrapper that sets a strong `ETag` header from the body.
+///
+/// The tag is a hash of the serialized bytes, so it is usable with both
+/// `If-None-Match` and `If-Match`. Pair with [`etag_middleware`] to answer
+/// matching `If-None-Match` requests with `304 Not Modified`.
+///
+/// # Example
+/// ```ignore
+/// use axum_helpers::http::ETagged;
+///
+/// async fn get_project(/* ... */) -> Result<ETagged<Project>, AppError> {
+///     Ok(ETagged(service.get(id).await?))
+/// }
+/// ```
+pub struct ETagged<T>(pub T);
+
+impl<T: Serialize> IntoResponse for ETagged<T> {
+    fn into_response(self) -> Response {
+        let body = match serde_json::to_vec(&self.0) {
+            Ok(body) => body,
+            Err(e) => return AppError::SerdeJson(e).into_response(),
+        };
+
+        let etag = strong_etag(&body);
+        let mut response = (
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            )],
+            body,
+        )
+            .into_response();
+        if let Ok(value) = HeaderValue::from_str(&etag) {
+            response.headers_mut().insert(header::ETAG, value);
+        }
+        response
+    }
+}
+
+/// Middleware that turns successful `GET`/`HEAD` responses into
+/// `304 Not Modified` when their `ETag` matches the request's `If-None-Match`.
+///
+/// Responses without an `ETag` header pass through untouched.
+pub async fn etag_middleware(request: Request, next: Next) -> Response {
+    let if_none_match = if matches!(*request.method(), Method::GET | Method::HEAD) {
+        header_string(request.headers(), header::IF_NONE_MATCH)
+    } else {
+        None
+    };
+
+    let response = next.run(request).await;
+
+    let Some(if_none_match) = if_none_match else {
+        return response;
+    };
+    if !response.status().is_success() {
+        return response;
+    }
+
+    match header_string(response.headers(), header::ETAG) {
+        Some(etag) if etag_matches(&if_none_match, &etag) => {
+            let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+            for name in [header::ETAG, header::CACHE_CONTROL, header::VARY] {
+                if let Some(value) = response.headers().get(&name) {
+                    not_modified.headers_mut().insert(name, value.clone());
+                }
+            }
+            not_modified
+        }
+        _ => response,
+    }
+}
+
+/// Extractor for the `If-Match` header, for optimistic concurrency on writes.
+///
+/// Compares strongly, so the current ETag must be a strong one such as
+/// [`etag_for`] returns.
+///
+/// # Example
+/// ```ignore
+/// async fn update_project(
+///     if_match: IfMatch,
+///     UuidPath(id): UuidPath,
+///     Json(input): Json<UpdateProject>,
+/// ) -> Result<ETagged<Project>, AppError> {
+///     let current = service.get(id).await?;
+///     if_match.check(&etag_for(&current)?)?;
+///     Ok(ETagged(service.update(id, input).await?))
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IfMatch(pub Option<String>);
+
+impl IfMatch {
+    /// `Ok` when no `If-Match` was sent or it matches `current_etag`,
+    /// otherwise `412 Precondition Failed`.
+    pub fn check(&self, current_etag: &str) -> Result<(), AppError> {
+        match &self.0 {
+            Some(expected) if !etag_matches_strong(expected, current_etag) => Err(
+                AppError::PreconditionFailed("Resource has been modified".to_string()),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(IfMatch(header_string(&parts.headers, header::IF_MATCH)))
+    }
+}
+
+fn header_string(headers: &HeaderMap, name: header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
@@ -1,8 +1,10 @@
 //! HTTP middleware module.
 //!
 //! This module provides HTTP-level middleware for:
+//! - Request body size limits
 //! - CORS configuration
 //! - CSRF protection
+//! - ETags and conditional requests
 //! - Security headers
 //!
 //! # Example
@@ -15,11 +17,18 @@
 //!     .layer(create_cors_layer(origin));
 //! ```
 
+pub mod body_limit;
 pub mod cors;
 pub mod csrf;
+pub mod etag;
 pub mod security;
 
 // Re-export commonly used functions
+pub use body_limit::body_limit_layer;
 pub use cors::{create_cors_layer, create_permissive_cors_layer};
 pub use csrf::csrf_validation_middleware;
+pub use etag::{
+    ETagged, IfMatch, etag_for, etag_matches, etag_matches_strong, etag_middleware, strong_etag,
+    weak_etag,
+};
 pub use security::security_headers;
@@ -0,0 +1,195 @@
+use super::{CachedResponse, IdempotencyCache, IdempotencyState};
+use crate::errors::AppError;
+use crate::rate_limit::extract_key;
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+
+/// Request header carrying the client-chosen idempotency key
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Response header set on replayed responses
+pub const IDEMPOTENCY_REPLAYED_HEADER: &str = "idempotency-replayed";
+
+/// Longest accepted idempotency key
+const MAX_KEY_LEN: usize = 255;
+
+/// Response headers never replayed: hop-by-hop headers describe the original
+/// connection, and cookies must not be handed out again
+const UNCACHED_HEADERS: [HeaderName; 10] = [
+    header::CONNECTION,
+    HeaderName::from_static("keep-alive"),
+    header::PROXY_AUTHENTICATE,
+    header::PROXY_AUTHORIZATION,
+    header::TE,
+    header::TRAILER,
+    header::TRANSFER_ENCODING,
+    header::UPGRADE,
+    header::CONTENT_LENGTH,
+    header::SET_COOKIE,
+];
+
+/// Build the cache key from caller, method, path and the client's key.
+///
+/// Scoping by caller and route means two users (or two endpoints) can't
+/// collide on the same key. Callers are identified the same way as by the
+/// rate limiter: JWT subject, else the client IP from trusted proxy headers
+/// or the socket address.
+fn cache_key(request: &Request, idempotency_key: &str) -> String {
+    format!(
+        "{}:{}:{}:{}",
+        extract_key(request),
+        request.method(),
+        request.uri().path(),
+        idempotency_key
+    )
+}
+
+/// Idempotency middleware for Axum.
+///
+/// Only applies to configured methods on requests with an `Idempotency-Key`
+/// header; everything else passes straight through.
+///
+/// Must run after the auth middleware: keys are scoped by the caller's
+/// `JwtClaims`, and without them by client IP only.
+///
+/// Behavior:
+/// - **First request:** Runs the handler; a 2xx response is cached for the TTL
+/// - **Repeat after success:** Replays the cached status, headers and body with `Idempotency-Replayed: true`
+/// - **Repeat with a different body:** Returns 422
+/// - **Repeat while in flight:** Returns 409
+/// - **Non-2xx response:** Not cached, so the client can retry
+/// - **Redis failure:** Fail-open (run the request), log warning
+pub async fn idempotency_middleware(
+    State(cache): State<IdempotencyCache>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !cache.applies_to(request.method()) {
+        return next.run(request).await;
+    }
+
+    let Some(idempotency_key) = request
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+    else {
+        return next.run(request).await;
+    };
+
+    if idempotency_key.is_empty() || idempotency_key.len() > MAX_KEY_LEN {
+        return AppError::BadRequest(format!(
+            "Idempotency-Key must be between 1 and {} characters",
+            MAX_KEY_LEN
+        ))
+        .into_response();
+    }
+
+    let key = cache_key(&request, &idempotency_key);
+
+    // Buffer the body to fingerprint it, then hand it on unchanged
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, cache.max_body_bytes()).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            return AppError::BadRequest(format!("Failed to read request body: {}", err))
+                .into_response();
+        }
+    };
+    let request_hash = format!("{:x}", Sha256::digest(&body));
+    let request = Request::from_parts(parts, Body::from(body));
+
+    match cache.begin(&key).await {
+        Ok(IdempotencyState::Acquired) => {}
+        Ok(IdempotencyState::Completed(cached)) if cached.request_hash != request_hash => {
+            return AppError::UnprocessableEntity(
+                "Idempotency-Key was already used with a different request body".to_string(),
+            )
+            .into_response();
+        }
+        Ok(IdempotencyState::Completed(cached)) => return replay(cached),
+        Ok(IdempotencyState::InFlight) => {
+            return AppError::Conflict(
+                "A request with this idempotency key is already in progress".to_string(),
+            )
+            .into_response();
+        }
+        Err(err) => {
+            tracing::warn!(error = %err, "Idempotency Redis error - failing open");
+            return next.run(request).await;
+        }
+    }
+
+    let response = next.run(request).await;
+
+    if !response.status().is_success() {
+        if let Err(err) = cache.release(&key).await {
+            tracing::warn!(error = %err, "Failed to release idempotency key");
+        }
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            if let Err(release_err) = cache.release(&key).await {
+                tracing::warn!(error = %release_err, "Failed to release idempotency key");
+            }
+            return AppError::InternalServerError(format!("Failed to read response body: {}", err))
+                .into_response();
+        }
+    };
+
+    let cached = CachedResponse {
+        request_hash,
+        status: parts.status.as_u16(),
+        headers: cacheable_headers(&parts.headers),
+        body: body.to_vec(),
+    };
+    if let Err(err) = cache.complete(&key, &cached).await {
+        tracing::warn!(error = %err, "Failed to cache idempotent response");
+    }
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// End-to-end response headers worth replaying, in order
+fn cacheable_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| !UNCACHED_HEADERS.contains(name))
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?;
+            Some((name.as_str().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn replay(cached: CachedResponse) -> Response {
+    let status = StatusCode::from_u16(cached.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, cached.body).into_response();
+
+    let headers = response.headers_mut();
+    headers.remove(header::CONTENT_TYPE);
+    for (name, value) in cached.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            headers.append(name, value);
+        }
+    }
+    headers.insert(
+        IDEMPOTENCY_REPLAYED_HEADER,
+        HeaderValue::from_static("true"),
+    );
+
+    response
+}
@@ -0,0 +1,224 @@
+//! Idempotency keys for safely retrying non-idempotent requests.
+//!
+//! When a request carries an `Idempotency-Key` header, the first successful
+//! response is cached in Redis and replayed for later requests with the same
+//! key, route and caller. A second request arriving while the first is still
+//! running gets `409 Conflict` instead of executing twice, and reusing a key
+//! with a different request body gets `422 Unprocessable Entity`.
+//!
+//! Callers are told apart by their JWT subject, so the middleware must run
+//! after the auth middleware (i.e. be layered inside it). Without claims,
+//! requests are scoped by client IP.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use axum_helpers::idempotency::{IdempotencyCache, IdempotencyConfig, idempotency_middleware};
+//!
+//! let cache = IdempotencyCache::new(redis_conn, IdempotencyConfig::from_env());
+//!
+//! let app = Router::new()
+//!     .route("/api/orders", post(create_order))
+//!     .layer(axum::middleware::from_fn_with_state(
+//!         cache,
+//!         idempotency_middleware,
+//!     ))
+//!     // Added last so it runs first: claims are set before idempotency
+//!     .layer(axum::middleware::from_fn_with_state(auth, jwt_auth_middleware));
+//! ```
+
+mod middleware;
+
+pub use middleware::{IDEMPOTENCY_KEY_HEADER, IDEMPOTENCY_REPLAYED_HEADER, idempotency_middleware};
+
+use axum::http::Method;
+use redis::{AsyncCommands, RedisResult, aio::ConnectionManager};
+use std::collections::HashMap;
+
+/// Configuration for idempotency handling.
+#[derive(Clone, Debug)]
+pub struct IdempotencyConfig {
+    /// How long a successful response is replayed for, in seconds
+    pub ttl_secs: u64,
+    /// How long an in-flight request holds its key, in seconds.
+    /// Bounds how long a crashed request can block retries.
+    pub lock_ttl_secs: u64,
+    /// Methods the middleware applies to; others pass straight through
+    pub methods: Vec<Method>,
+    /// Largest request body buffered for hashing; larger bodies get a 400
+    pub max_body_bytes: usize,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 86400,
+            lock_ttl_secs: 30,
+            methods: vec![Method::POST],
+            max_body_bytes: 2 * 1024 * 1024,
+        }
+    }
+}
+
+impl IdempotencyConfig {
+    /// Load configuration from environment variables with defaults.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let ttl_secs = std::env::var("IDEMPOTENCY_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.ttl_secs);
+
+        let lock_ttl_secs = std::env::var("IDEMPOTENCY_LOCK_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.lock_ttl_secs);
+
+        Self {
+            ttl_secs,
+            lock_ttl_secs,
+            ..defaults
+        }
+    }
+}
+
+/// A response captured for replay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// SHA-256 of the request body that produced this response
+    pub request_hash: String,
+    pub status: u16,
+    /// Response headers to replay, excluding hop-by-hop headers and cookies
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Result of trying to claim an idempotency key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdempotencyState {
+    /// No prior request; the caller now holds the key and must run the request
+    Acquired,
+    /// Another request with this key is still running
+    InFlight,
+    /// A previous request completed; replay its response
+    Completed(CachedResponse),
+}
+
+/// Redis-backed idempotency cache.
+#[derive(Clone)]
+pub struct IdempotencyCache {
+    redis: ConnectionManager,
+    config: IdempotencyConfig,
+}
+
+impl IdempotencyCache {
+    /// Create a new cache with the given Redis connection and config.
+    pub fn new(redis: ConnectionManager, config: IdempotencyConfig) -> Self {
+        Self { redis, config }
+    }
+
+    /// Whether requests with this method are subject to idempotency handling.
+    pub fn applies_to(&self, method: &Method) -> bool {
+        self.config.methods.contains(method)
+    }
+
+    /// Largest request body the middleware buffers.
+    pub fn max_body_bytes(&self) -> usize {
+        self.config.max_body_bytes
+    }
+
+    /// Look up a completed response, otherwise try to take the in-flight lock.
+    pub async fn begin(&self, key: &str) -> RedisResult<IdempotencyState> {
+        if let Some(cached) = self.get(key).await? {
+            return Ok(IdempotencyState::Completed(cached));
+        }
+
+        let mut conn = self.redis.clone();
+        let acquired: bool = redis::cmd("SET")
+            .arg(lock_key(key))
+            .arg("1")
+            .arg("NX")
+            .arg("EX")
+            .arg(self.config.lock_ttl_secs)
+            .query_async::<Option<String>>(&mut conn)
+            .await?
+            .is_some();
+
+        if acquired {
+            return Ok(IdempotencyState::Acquired);
+        }
+
+        // The holder may have finished between our two reads
+        Ok(match self.get(key).await? {
+            Some(cached) => IdempotencyState::Completed(cached),
+            None => IdempotencyState::InFlight,
+        })
+    }
+
+    /// Store a response for replay and release the lock.
+    pub async fn complete(&self, key: &str, response: &CachedResponse) -> RedisResult<()> {
+        let response_key = response_key(key);
+        let status = response.status.to_string();
+        let headers = serde_json::to_vec(&response.headers).unwrap_or_default();
+        let fields: Vec<(&str, &[u8])> = vec![
+            ("request_hash", response.request_hash.as_bytes()),
+            ("status", status.as_bytes()),
+            ("headers", &headers),
+            ("body", &response.body),
+        ];
+
+        let mut conn = self.redis.clone();
+        let _: () = redis::pipe()
+            .atomic()
+            .hset_multiple(&response_key, &fields)
+            .ignore()
+            .expire(&response_key, self.config.ttl_secs as i64)
+            .ignore()
+            .del(lock_key(key))
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Release the lock without caching, so the request can be retried.
+    pub async fn release(&self, key: &str) -> RedisResult<()> {
+        let mut conn = self.redis.clone();
+        conn.del::<_, ()>(lock_key(key)).await
+    }
+
+    async fn get(&self, key: &str) -> RedisResult<Option<CachedResponse>> {
+        let mut conn = self.redis.clone();
+        let mut fields: HashMap<String, Vec<u8>> = conn.hgetall(response_key(key)).await?;
+
+        let Some(status) = fields
+            .get("status")
+            .and_then(|s| std::str::from_utf8(s).ok())
+            .and_then(|s| s.parse().ok())
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(CachedResponse {
+            request_hash: fields
+                .remove("request_hash")
+                .and_then(|v| String::from_utf8(v).ok())
+                .unwrap_or_default(),
+            status,
+            headers: fields
+                .remove("headers")
+                .and_then(|v| serde_json::from_slice(&v).ok())
+                .unwrap_or_default(),
+            body: fields.remove("body").unwrap_or_default(),
+        }))
+    }
+}
+
+fn response_key(key: &str) -> String {
+    format!("idem:{}:response", key)
+}
+
+fn lock_key(key: &str) -> String {
+    format!("idem:{}:lock", key)
+}
@@ -6,9 +6,10 @@
 //!
 //! - **[`auth`]**: JWT authentication with Redis-backed whitelist/blacklist
 //! - **[`server`]**: Server setup, health checks, graceful shutdown
-//! - **[`http`]**: HTTP middleware (CORS, CSRF, security headers)
+//! - **[`http`]**: HTTP middleware (CORS, CSRF, security headers, ETags)
+//! - **[`idempotency`]**: Redis-backed `Idempotency-Key` handling for safe retries
 //! - **[`errors`]**: Structured error responses with error codes
-//! - **[`extractors`]**: Custom extractors (UUID path, validated JSON)
+//! - **[`extractors`]**: Custom extractors (UUID path, validated JSON, pagination)
 //! - **[`audit`]**: Audit logging for security and compliance
 //!
 //! ## Quick Start
@@ -40,12 +41,14 @@ pub mod auth;
 pub mod errors;
 pub mod extractors;
 pub mod http;
+pub mod idempotency;
 pub mod rate_limit;
 pub mod server;
 
 // Re-export auth types
 pub use auth::{
-    ACCESS_TOKEN_TTL, JwtClaims, JwtConfig, JwtRedisAuth, REFRESH_TOKEN_TTL, RedisAuthStore,
+    ACCESS_TOKEN_TTL, ApiKeyContext, ApiKeyStore, JwtClaims, JwtConfig, JwtRedisAuth,
+    REFRESH_TOKEN_TTL, RedisAuthStore, RefreshTokenState, api_key_auth_middleware,
     jwt_auth_middleware, optional_jwt_auth_middleware,
 };
 
@@ -58,17 +61,21 @@ pub use server::{
 
 // Re-export HTTP middleware
 pub use http::{
-    create_cors_layer, create_permissive_cors_layer, csrf_validation_middleware, security_headers,
+    ETagged, IfMatch, body_limit_layer, create_cors_layer, create_permissive_cors_layer,
+    csrf_validation_middleware, etag_middleware, security_headers,
 };
 
 // Re-export error types
 pub use errors::{AppError, ErrorCode, ErrorResponse};
 
+// Re-export idempotency
+pub use idempotency::{IdempotencyCache, IdempotencyConfig, idempotency_middleware};
+
 // Re-export rate limiting
 pub use rate_limit::{RateLimitConfig, RateLimitTier, RateLimiter, rate_limit_middleware};
 
 // Re-export extractors
-pub use extractors::{UuidPath, ValidatedJson};
+pub use extractors::{Pagination, PaginationConfig, UuidPath, ValidatedJson};
 
 // Re-export audit types
 pub use audit::{
@@ -9,7 +9,9 @@ use axum::{
 };
 use std::net::SocketAddr;
 
-/// Extract the rate limit key from the request.
+/// Extract the caller key from the request.
+///
+/// Also used to scope idempotency keys, so both agree on who the caller is.
 ///
 /// Strategy:
 /// 1. Authenticated user ID from `JwtClaims` in extensions -> `user:<id>`
@@ -17,7 +19,7 @@ use std::net::SocketAddr;
 /// 3. `X-Forwarded-For` rightmost IP (last entry = added by our proxy) -> `ip:<ip>`
 /// 4. TCP socket peer address (`ConnectInfo`) -> `ip:<ip>`
 /// 5. Fallback -> `ip:unknown`
-fn extract_key(request: &Request) -> String {
+pub(crate) fn extract_key(request: &Request) -> String {
     // Check for authenticated user (only populated if auth middleware ran first)
     if let Some(claims) = request.extensions().get::<JwtClaims>() {
         return format!("user:{}", claims.sub);
@@ -51,13 +53,14 @@ fn extract_key(request: &Request) -> String {
         return format!("ip:{}", connect_info.0.ip());
     }
 
-    tracing::warn!("Could not determine client IP for rate limiting - using shared 'ip:unknown' key");
+    tracing::warn!("Could not determine client IP - using shared 'ip:unknown' key");
     "ip:unknown".to_string()
 }
 
 /// Rate limiting middleware for Axum.
 ///
-/// Identifies callers by user ID (if auth ran first) or client IP.
+/// Identifies callers by user ID (if auth ran first) or client IP. Authenticated
+/// callers get the tier's authenticated limit when one is set.
 ///
 /// Behavior:
 /// - **Allowed:** Adds `X-RateLimit-Limit`, `X-RateLimit-Remaining`, `X-RateLimit-Reset` headers
@@ -79,10 +82,11 @@ pub async fn rate_limit_middleware(
     };
 
     let key = extract_key(&request);
-    let limit = tier.requests_per_window;
+    let authenticated = request.extensions().get::<JwtClaims>().is_some();
+    let limit = tier.limit_for(authenticated);
 
     match limiter
-        .check_with_config(&key, &tier.name, tier.requests_per_window, tier.window_secs)
+        .check_with_config(&key, &tier.name, limit, tier.window_secs)
         .await
     {
         Ok(result) => {
@@ -163,6 +167,7 @@ mod tests {
             exp: 0,
             iat: 0,
             jti: "jti-1".to_string(),
+            fid: None,
         });
         assert_eq!(extract_key(&req), "user:user-123");
     }
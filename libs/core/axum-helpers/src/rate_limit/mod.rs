@@ -24,6 +24,7 @@
 
 mod middleware;
 
+pub(crate) use middleware::extract_key;
 pub use middleware::rate_limit_middleware;
 
 use redis::aio::ConnectionManager;
@@ -38,6 +39,8 @@ pub struct RateLimitTier {
     pub name: String,
     /// Max requests per window for this tier
     pub requests_per_window: u64,
+    /// Max requests per window for authenticated callers (falls back to `requests_per_window`)
+    pub authenticated_requests_per_window: Option<u64>,
     /// Window duration in seconds
     pub window_secs: u64,
 }
@@ -47,6 +50,8 @@ pub struct RateLimitTier {
 pub struct RateLimitConfig {
     /// Maximum requests allowed per window
     pub requests_per_window: u64,
+    /// Maximum requests per window for authenticated callers, if different
+    pub authenticated_requests_per_window: Option<u64>,
     /// Window duration in seconds
     pub window_secs: u64,
     /// Whether rate limiting is enabled
@@ -61,6 +66,11 @@ impl RateLimitConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(100);
 
+        let authenticated_requests_per_window =
+            std::env::var("RATE_LIMIT_AUTHENTICATED_REQUESTS_PER_WINDOW")
+                .ok()
+                .and_then(|v| v.parse().ok());
+
         let window_secs = std::env::var("RATE_LIMIT_WINDOW_SECS")
             .ok()
             .and_then(|v| v.parse().ok())
@@ -73,6 +83,7 @@ impl RateLimitConfig {
 
         Self {
             requests_per_window,
+            authenticated_requests_per_window,
             window_secs,
             enabled,
         }
@@ -120,9 +131,24 @@ impl RateLimitTier {
         Self {
             name: name.into(),
             requests_per_window,
+            authenticated_requests_per_window: None,
             window_secs,
         }
     }
+
+    /// Allow authenticated callers a different limit than anonymous ones.
+    pub fn with_authenticated_limit(mut self, requests_per_window: u64) -> Self {
+        self.authenticated_requests_per_window = Some(requests_per_window);
+        self
+    }
+
+    /// Effective limit for a caller.
+    pub fn limit_for(&self, authenticated: bool) -> u64 {
+        match self.authenticated_requests_per_window {
+            Some(limit) if authenticated => limit,
+            _ => self.requests_per_window,
+        }
+    }
 }
 
 /// Redis-backed distributed rate limiter.
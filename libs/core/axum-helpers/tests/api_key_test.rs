@@ -0,0 +1,100 @@
+use axum::{
+    Extension, Router, body::Body, extract::Request, http::StatusCode, middleware, routing::get,
+};
+use axum_helpers::auth::{API_KEY_HEADER, ApiKeyContext, ApiKeyStore, api_key_auth_middleware};
+use redis::aio::ConnectionManager;
+use test_utils::TestRedis;
+use tower::ServiceExt; // For oneshot()
+
+async fn setup() -> (TestRedis, ApiKeyStore) {
+    let test_redis = TestRedis::new().await;
+    let client =
+        redis::Client::open(test_redis.connection_string()).expect("Failed to create client");
+    let conn = ConnectionManager::new(client)
+        .await
+        .expect("Failed to create ConnectionManager");
+
+    (test_redis, ApiKeyStore::new(conn))
+}
+
+fn app(store: ApiKeyStore) -> Router {
+    Router::new()
+        .route(
+            "/internal",
+            get(|Extension(context): Extension<ApiKeyContext>| async move {
+                format!("{}:{}", context.name, context.role)
+            }),
+        )
+        .layer(middleware::from_fn_with_state(
+            store,
+            api_key_auth_middleware,
+        ))
+}
+
+fn request(key: Option<&str>) -> Request {
+    let mut builder = Request::builder().uri("/internal");
+    if let Some(key) = key {
+        builder = builder.header(API_KEY_HEADER, key);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_valid_key_attaches_context() {
+    let (_redis, mut store) = setup().await;
+    let (key, context) = store.create_key("billing-worker", "service").await.unwrap();
+
+    assert_eq!(store.lookup(&key).await.unwrap(), Some(context));
+
+    let response = app(store).oneshot(request(Some(&key))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"billing-worker:service");
+}
+
+#[tokio::test]
+async fn test_revoked_key_is_rejected() {
+    let (_redis, mut store) = setup().await;
+    let (key, context) = store.create_key("billing-worker", "service").await.unwrap();
+
+    assert!(store.revoke_key(&context.key_id).await.unwrap());
+    assert!(!store.revoke_key(&context.key_id).await.unwrap());
+    assert!(store.lookup(&key).await.unwrap().is_none());
+
+    let response = app(store).oneshot(request(Some(&key))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(json_body(response).await["error"], "UNAUTHORIZED");
+}
+
+#[tokio::test]
+async fn test_unknown_key_is_rejected() {
+    let (_redis, store) = setup().await;
+
+    let response = app(store)
+        .oneshot(request(Some("ak_not-a-real-key")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_missing_header_is_rejected() {
+    let (_redis, store) = setup().await;
+
+    let response = app(store).oneshot(request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let body = json_body(response).await;
+    assert_eq!(body["error"], "UNAUTHORIZED");
+    assert_eq!(body["message"], "No API key provided");
+}
@@ -0,0 +1,71 @@
+use axum::{
+    Router,
+    body::Body,
+    extract::Request,
+    http::{StatusCode, header},
+    routing::post,
+};
+use axum_helpers::ValidatedJson;
+use axum_helpers::http::body_limit_layer;
+use serde::Deserialize;
+use tower::ServiceExt; // For oneshot()
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+struct Comment {
+    #[validate(length(min = 1))]
+    text: String,
+}
+
+async fn create_comment(ValidatedJson(comment): ValidatedJson<Comment>) -> String {
+    comment.text
+}
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/uploads",
+            post(create_comment).layer(body_limit_layer(4096)),
+        )
+        .route("/comments", post(create_comment))
+        .layer(body_limit_layer(64))
+}
+
+fn json_request(uri: &str, text_len: usize) -> Request {
+    let body = serde_json::json!({ "text": "x".repeat(text_len) }).to_string();
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_over_limit_body_returns_structured_413() {
+    let response = app().oneshot(json_request("/comments", 200)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["code"], 1015);
+    assert_eq!(body["error"], "PAYLOAD_TOO_LARGE");
+    assert_eq!(body["message"], "Request body is too large");
+}
+
+#[tokio::test]
+async fn test_within_limit_body_is_accepted() {
+    let response = app().oneshot(json_request("/comments", 10)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_limit_is_configurable_per_route() {
+    let response = app().oneshot(json_request("/uploads", 200)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app().oneshot(json_request("/uploads", 5000)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
@@ -0,0 +1,140 @@
+use axum::{
+    Router,
+    body::Body,
+    extract::Request,
+    http::{StatusCode, header},
+    middleware,
+    routing::get,
+};
+use axum_helpers::AppError;
+use axum_helpers::http::{
+    ETagged, IfMatch, etag_for, etag_matches, etag_matches_strong, etag_middleware,
+};
+use serde::Serialize;
+use tower::ServiceExt; // For oneshot()
+
+#[derive(Serialize)]
+struct Item {
+    id: u32,
+    name: &'static str,
+}
+
+const ITEM: Item = Item {
+    id: 1,
+    name: "widget",
+};
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/item",
+            get(|| async { ETagged(ITEM) }).put(|if_match: IfMatch| async move {
+                if_match.check(&etag_for(&ITEM)?)?;
+                Ok::<_, AppError>(StatusCode::NO_CONTENT)
+            }),
+        )
+        .layer(middleware::from_fn(etag_middleware))
+}
+
+fn request(method: &str, header: Option<(header::HeaderName, &str)>) -> Request {
+    let mut builder = Request::builder().method(method).uri("/item");
+    if let Some((name, value)) = header {
+        builder = builder.header(name, value);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+async fn body_bytes(response: axum::response::Response) -> Vec<u8> {
+    axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn test_response_carries_strong_etag() {
+    let response = app().oneshot(request("GET", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let etag = response.headers()[header::ETAG]
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert!(etag.starts_with('"'));
+    assert_eq!(etag, etag_for(&ITEM).unwrap());
+    assert_eq!(body_bytes(response).await, br#"{"id":1,"name":"widget"}"#);
+}
+
+#[tokio::test]
+async fn test_matching_if_none_match_returns_304() {
+    let etag = etag_for(&ITEM).unwrap();
+
+    let response = app()
+        .oneshot(request("GET", Some((header::IF_NONE_MATCH, &etag))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag.as_str());
+    assert!(body_bytes(response).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_stale_if_none_match_returns_body() {
+    let response = app()
+        .oneshot(request("GET", Some((header::IF_NONE_MATCH, "W/\"stale\""))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!body_bytes(response).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_if_match_guards_writes() {
+    let etag = etag_for(&ITEM).unwrap();
+
+    let response = app()
+        .oneshot(request("PUT", Some((header::IF_MATCH, &etag))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app()
+        .oneshot(request("PUT", Some((header::IF_MATCH, "W/\"stale\""))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    // A weak tag never satisfies If-Match, even with the same opaque value
+    let response = app()
+        .oneshot(request(
+            "PUT",
+            Some((header::IF_MATCH, &format!("W/{}", etag))),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    // No precondition means no check
+    let response = app().oneshot(request("PUT", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[test]
+fn test_etag_matches() {
+    assert!(etag_matches("W/\"abc\"", "W/\"abc\""));
+    assert!(etag_matches("\"abc\"", "W/\"abc\""));
+    assert!(etag_matches("\"x\", W/\"abc\"", "W/\"abc\""));
+    assert!(etag_matches("*", "W/\"abc\""));
+    assert!(!etag_matches("W/\"abd\"", "W/\"abc\""));
+}
+
+#[test]
+fn test_etag_matches_strong() {
+    assert!(etag_matches_strong("\"abc\"", "\"abc\""));
+    assert!(etag_matches_strong("\"x\", \"abc\"", "\"abc\""));
+    assert!(etag_matches_strong("*", "\"abc\""));
+    assert!(!etag_matches_strong("W/\"abc\"", "\"abc\""));
+    assert!(!etag_matches_strong("\"abc\"", "W/\"abc\""));
+    assert!(etag_matches_strong("*", "W/\"abc\""));
+    assert!(!etag_matches_strong("\"abd\"", "\"abc\""));
+}
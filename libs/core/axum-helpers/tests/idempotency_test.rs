@@ -0,0 +1,366 @@
+use axum::{Router, body::Body, extract::Request, http::StatusCode, middleware, routing::post};
+use axum_helpers::idempotency::{
+    IDEMPOTENCY_KEY_HEADER, IDEMPOTENCY_REPLAYED_HEADER, IdempotencyCache, IdempotencyConfig,
+    idempotency_middleware,
+};
+use redis::aio::ConnectionManager;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
+use test_utils::TestRedis;
+use tower::ServiceExt; // For oneshot()
+
+async fn setup(config: IdempotencyConfig) -> (TestRedis, IdempotencyCache) {
+    let test_redis = TestRedis::new().await;
+    let client =
+        redis::Client::open(test_redis.connection_string()).expect("Failed to create client");
+    let conn = ConnectionManager::new(client)
+        .await
+        .expect("Failed to create ConnectionManager");
+
+    (test_redis, IdempotencyCache::new(conn, config))
+}
+
+/// Router whose handlers count how often they actually run
+fn counting_router(cache: IdempotencyCache, calls: Arc<AtomicUsize>, delay: Duration) -> Router {
+    let create_calls = calls.clone();
+    Router::new()
+        .route(
+            "/orders",
+            post(move || {
+                let calls = create_calls.clone();
+                async move {
+                    tokio::time::sleep(delay).await;
+                    let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                    (StatusCode::CREATED, format!("order-{}", n))
+                }
+            })
+            .get(|| async { "list" }),
+        )
+        .route(
+            "/fail",
+            post(move || {
+                let calls = calls.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            }),
+        )
+        .layer(middleware::from_fn_with_state(
+            cache,
+            idempotency_middleware,
+        ))
+}
+
+fn post_with_key(uri: &str, key: &str) -> Request {
+    post_with_body(uri, key, "")
+}
+
+fn post_with_body(uri: &str, key: &str, body: &'static str) -> Request {
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header(IDEMPOTENCY_KEY_HEADER, key)
+        .body(Body::from(body))
+        .unwrap()
+}
+
+fn post_from_ip(uri: &str, key: &str, ip: &str) -> Request {
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header(IDEMPOTENCY_KEY_HEADER, key)
+        .header("x-real-ip", ip)
+        .body(Body::empty())
+        .unwrap()
+}
+
+async fn body_string(response: axum::response::Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_replays_cached_response() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    let first = app
+        .clone()
+        .oneshot(post_with_key("/orders", "key-1"))
+        .await
+        .unwrap();
+    assert_eq!(first.status(), StatusCode::CREATED);
+    assert!(first.headers().get(IDEMPOTENCY_REPLAYED_HEADER).is_none());
+    assert_eq!(body_string(first).await, "order-1");
+
+    let second = app
+        .clone()
+        .oneshot(post_with_key("/orders", "key-1"))
+        .await
+        .unwrap();
+    assert_eq!(second.status(), StatusCode::CREATED);
+    assert_eq!(second.headers()[IDEMPOTENCY_REPLAYED_HEADER], "true");
+    assert_eq!(
+        second.headers()["content-type"],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(body_string(second).await, "order-1");
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "handler ran only once");
+
+    // A different key runs the handler again
+    let third = app
+        .oneshot(post_with_key("/orders", "key-2"))
+        .await
+        .unwrap();
+    assert_eq!(body_string(third).await, "order-2");
+}
+
+#[tokio::test]
+async fn test_requests_without_key_or_method_pass_through() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    for _ in 0..2 {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/orders")
+            .body(Body::empty())
+            .unwrap();
+        app.clone().oneshot(request).await.unwrap();
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let request = Request::builder()
+        .uri("/orders")
+        .header(IDEMPOTENCY_KEY_HEADER, "key-get")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert!(
+        response
+            .headers()
+            .get(IDEMPOTENCY_REPLAYED_HEADER)
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_concurrent_duplicate_is_rejected() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::from_millis(300));
+
+    let (a, b) = tokio::join!(
+        app.clone()
+            .oneshot(post_with_key("/orders", "key-concurrent")),
+        app.clone()
+            .oneshot(post_with_key("/orders", "key-concurrent")),
+    );
+    let mut statuses = vec![a.unwrap().status(), b.unwrap().status()];
+    statuses.sort();
+
+    assert_eq!(statuses, vec![StatusCode::CREATED, StatusCode::CONFLICT]);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Once the first finishes, the key replays
+    let response = app
+        .oneshot(post_with_key("/orders", "key-concurrent"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.headers()[IDEMPOTENCY_REPLAYED_HEADER], "true");
+}
+
+#[tokio::test]
+async fn test_failed_response_is_not_cached() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    for _ in 0..2 {
+        let response = app
+            .clone()
+            .oneshot(post_with_key("/fail", "key-fail"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 2, "failures can be retried");
+}
+
+#[tokio::test]
+async fn test_cached_response_expires() {
+    let config = IdempotencyConfig {
+        ttl_secs: 1,
+        ..IdempotencyConfig::default()
+    };
+    let (_redis, cache) = setup(config).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    app.clone()
+        .oneshot(post_with_key("/orders", "key-ttl"))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+
+    let response = app
+        .oneshot(post_with_key("/orders", "key-ttl"))
+        .await
+        .unwrap();
+    assert!(
+        response
+            .headers()
+            .get(IDEMPOTENCY_REPLAYED_HEADER)
+            .is_none()
+    );
+    assert_eq!(body_string(response).await, "order-2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_key_reused_with_different_body_is_rejected() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    let first = app
+        .clone()
+        .oneshot(post_with_body("/orders", "key-body", r#"{"qty":1}"#))
+        .await
+        .unwrap();
+    assert_eq!(first.status(), StatusCode::CREATED);
+
+    let mismatch = app
+        .clone()
+        .oneshot(post_with_body("/orders", "key-body", r#"{"qty":2}"#))
+        .await
+        .unwrap();
+    assert_eq!(mismatch.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    // The original body still replays
+    let replay = app
+        .oneshot(post_with_body("/orders", "key-body", r#"{"qty":1}"#))
+        .await
+        .unwrap();
+    assert_eq!(replay.headers()[IDEMPOTENCY_REPLAYED_HEADER], "true");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_anonymous_callers_are_scoped_by_ip() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    let a = app
+        .clone()
+        .oneshot(post_from_ip("/orders", "key-shared", "203.0.113.1"))
+        .await
+        .unwrap();
+    assert_eq!(body_string(a).await, "order-1");
+
+    // Same key from another client is not a replay of the first
+    let b = app
+        .clone()
+        .oneshot(post_from_ip("/orders", "key-shared", "203.0.113.2"))
+        .await
+        .unwrap();
+    assert!(b.headers().get(IDEMPOTENCY_REPLAYED_HEADER).is_none());
+    assert_eq!(body_string(b).await, "order-2");
+
+    let again = app
+        .oneshot(post_from_ip("/orders", "key-shared", "203.0.113.1"))
+        .await
+        .unwrap();
+    assert_eq!(again.headers()[IDEMPOTENCY_REPLAYED_HEADER], "true");
+    assert_eq!(body_string(again).await, "order-1");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_forwarded_for_uses_proxy_added_entry() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let app = counting_router(cache, calls.clone(), Duration::ZERO);
+
+    let forwarded = |chain: &str| {
+        Request::builder()
+            .method("POST")
+            .uri("/orders")
+            .header(IDEMPOTENCY_KEY_HEADER, "key-xff")
+            .header("x-forwarded-for", chain)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let first = app
+        .clone()
+        .oneshot(forwarded("198.51.100.7, 203.0.113.9"))
+        .await
+        .unwrap();
+    assert_eq!(body_string(first).await, "order-1");
+
+    // A spoofed leftmost entry doesn't make the client look like someone else
+    let spoofed = app
+        .oneshot(forwarded("192.0.2.55, 203.0.113.9"))
+        .await
+        .unwrap();
+    assert_eq!(spoofed.headers()[IDEMPOTENCY_REPLAYED_HEADER], "true");
+    assert_eq!(body_string(spoofed).await, "order-1");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_replays_headers_except_cookies() {
+    let (_redis, cache) = setup(IdempotencyConfig::default()).await;
+    let app = Router::new()
+        .route(
+            "/sessions",
+            post(|| async {
+                (
+                    StatusCode::CREATED,
+                    [
+                        ("location", "/sessions/1"),
+                        ("set-cookie", "session=secret"),
+                        ("x-request-id", "req-1"),
+                    ],
+                    "created",
+                )
+            }),
+        )
+        .layer(middleware::from_fn_with_state(
+            cache,
+            idempotency_middleware,
+        ));
+
+    let first = app
+        .clone()
+        .oneshot(post_with_key("/sessions", "key-headers"))
+        .await
+        .unwrap();
+    assert_eq!(first.headers()["set-cookie"], "session=secret");
+
+    let replay = app
+        .oneshot(post_with_key("/sessions", "key-headers"))
+        .await
+        .unwrap();
+    assert_eq!(replay.status(), StatusCode::CREATED);
+    assert_eq!(replay.headers()["location"], "/sessions/1");
+    assert_eq!(replay.headers()["x-request-id"], "req-1");
+    assert_eq!(
+        replay.headers()["content-type"],
+        "text/plain; charset=utf-8"
+    );
+    assert!(replay.headers().get("set-cookie").is_none());
+    assert_eq!(body_string(replay).await, "created");
+}
@@ -0,0 +1,140 @@
+use axum_helpers::{
+    ACCESS_TOKEN_TTL, AppError, JwtClaims, JwtConfig, JwtRedisAuth, REFRESH_TOKEN_TTL,
+};
+use jsonwebtoken::{EncodingKey, Header, encode};
+use redis::aio::ConnectionManager;
+use test_utils::TestRedis;
+
+const SECRET: &str = "test-secret-that-is-at-least-32-characters";
+const USER_ID: &str = "user-123";
+
+async fn setup() -> (TestRedis, JwtRedisAuth) {
+    let test_redis = TestRedis::new().await;
+    let client =
+        redis::Client::open(test_redis.connection_string()).expect("Failed to create client");
+    let conn = ConnectionManager::new(client)
+        .await
+        .expect("Failed to create ConnectionManager");
+
+    let auth = JwtRedisAuth::new(conn, &JwtConfig::new(SECRET)).expect("Failed to create auth");
+    (test_redis, auth)
+}
+
+/// Issue and whitelist a refresh token the way the login handler does
+async fn login(auth: &JwtRedisAuth) -> String {
+    let token = auth
+        .create_refresh_token(USER_ID, "test@example.com", "Test", &[])
+        .unwrap();
+    let claims = auth.verify_token(&token).unwrap();
+    auth.whitelist_token(&claims.jti, USER_ID, REFRESH_TOKEN_TTL as u64)
+        .await
+        .unwrap();
+    token
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_token_pair() {
+    let (_redis, auth) = setup().await;
+    let original = login(&auth).await;
+    let original_claims = auth.verify_token(&original).unwrap();
+
+    let (access, refresh) = auth
+        .refresh(&original)
+        .await
+        .expect("refresh should succeed");
+
+    let access_claims = auth.verify_token(&access).unwrap();
+    let refresh_claims = auth.verify_token(&refresh).unwrap();
+    assert_eq!(access_claims.sub, USER_ID);
+    assert!(access_claims.fid.is_none());
+    assert_eq!(refresh_claims.fid, original_claims.fid);
+    assert_ne!(refresh_claims.jti, original_claims.jti);
+    assert_eq!(
+        access_claims.exp - access_claims.iat,
+        ACCESS_TOKEN_TTL,
+        "access token keeps its short TTL"
+    );
+
+    // The presented token is consumed, the new pair is live
+    assert!(
+        !auth
+            .is_token_whitelisted(&original_claims.jti)
+            .await
+            .unwrap()
+    );
+    assert!(auth.is_token_whitelisted(&access_claims.jti).await.unwrap());
+    assert!(
+        auth.is_token_whitelisted(&refresh_claims.jti)
+            .await
+            .unwrap()
+    );
+
+    // And the new refresh token can itself be rotated
+    auth.refresh(&refresh)
+        .await
+        .expect("second rotation should succeed");
+}
+
+#[tokio::test]
+async fn test_replayed_refresh_token_revokes_family() {
+    let (_redis, auth) = setup().await;
+    let original = login(&auth).await;
+
+    let (access, refresh) = auth.refresh(&original).await.unwrap();
+
+    let result = auth.refresh(&original).await;
+    assert!(
+        matches!(result, Err(AppError::TokenReuse(_))),
+        "replaying a rotated token should be reported as reuse"
+    );
+
+    // Everything issued in the family since is revoked
+    let access_jti = auth.verify_token(&access).unwrap().jti;
+    let refresh_jti = auth.verify_token(&refresh).unwrap().jti;
+    assert!(!auth.is_token_whitelisted(&access_jti).await.unwrap());
+    assert!(auth.is_token_blacklisted(&access_jti).await.unwrap());
+    assert!(!auth.is_token_whitelisted(&refresh_jti).await.unwrap());
+
+    let result = auth.refresh(&refresh).await;
+    assert!(matches!(result, Err(AppError::Unauthorized(_))));
+}
+
+#[tokio::test]
+async fn test_expired_refresh_token_is_rejected() {
+    let (_redis, auth) = setup().await;
+
+    let now = chrono::Utc::now().timestamp();
+    let claims = JwtClaims {
+        sub: USER_ID.to_string(),
+        email: "test@example.com".to_string(),
+        name: "Test".to_string(),
+        roles: vec![],
+        exp: now - 3600,
+        iat: now - 3600 - REFRESH_TOKEN_TTL,
+        jti: uuid::Uuid::new_v4().to_string(),
+        fid: Some(uuid::Uuid::new_v4().to_string()),
+    };
+    let expired = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(SECRET.as_bytes()),
+    )
+    .unwrap();
+    auth.whitelist_token(&claims.jti, USER_ID, 60)
+        .await
+        .unwrap();
+
+    let result = auth.refresh(&expired).await;
+    assert!(matches!(result, Err(AppError::Unauthorized(_))));
+}
+
+#[tokio::test]
+async fn test_access_token_cannot_refresh() {
+    let (_redis, auth) = setup().await;
+    let access = auth
+        .create_access_token(USER_ID, "test@example.com", "Test", &[])
+        .unwrap();
+
+    let result = auth.refresh(&access).await;
+    assert!(matches!(result, Err(AppError::Unauthorized(_))));
+}
@@ -1,6 +1,18 @@
-use axum_helpers::rate_limit::{RateLimitConfig, RateLimiter};
+use axum::{
+    Extension, Router,
+    body::Body,
+    extract::Request,
+    http::StatusCode,
+    middleware::{self, Next},
+    routing::get,
+};
+use axum_helpers::JwtClaims;
+use axum_helpers::rate_limit::{
+    RateLimitConfig, RateLimitTier, RateLimiter, rate_limit_middleware,
+};
 use redis::aio::ConnectionManager;
 use test_utils::TestRedis;
+use tower::ServiceExt; // For oneshot()
 
 async fn setup() -> (TestRedis, RateLimiter) {
     let test_redis = TestRedis::new().await;
@@ -12,6 +24,7 @@ async fn setup() -> (TestRedis, RateLimiter) {
 
     let config = RateLimitConfig {
         requests_per_window: 100,
+        authenticated_requests_per_window: None,
         window_secs: 60,
         enabled: true,
     };
@@ -84,7 +97,10 @@ async fn test_separate_keys_independent() {
         .check_with_config("user:bob", "standard", limit, 60)
         .await
         .unwrap();
-    assert!(result.allowed, "bob should not be affected by alice's limit");
+    assert!(
+        result.allowed,
+        "bob should not be affected by alice's limit"
+    );
 }
 
 #[tokio::test]
@@ -124,6 +140,7 @@ async fn test_disabled_limiter_always_allows() {
 
     let config = RateLimitConfig {
         requests_per_window: 1,
+        authenticated_requests_per_window: None,
         window_secs: 60,
         enabled: false,
     };
@@ -180,3 +197,140 @@ async fn test_window_reset() {
         .unwrap();
     assert!(result.allowed, "should be allowed after window reset");
 }
+
+fn limited_router(limiter: RateLimiter, tier: RateLimitTier, user: Option<&str>) -> Router {
+    let claims = user.map(|sub| JwtClaims {
+        sub: sub.to_string(),
+        email: format!("{}@example.com", sub),
+        name: sub.to_string(),
+        roles: vec![],
+        exp: 0,
+        iat: 0,
+        jti: "jti".to_string(),
+        fid: None,
+    });
+
+    Router::new()
+        .route("/", get(|| async { "ok" }))
+        .layer(middleware::from_fn_with_state(
+            limiter,
+            rate_limit_middleware,
+        ))
+        .layer(Extension(tier))
+        .layer(middleware::from_fn(move |mut req: Request, next: Next| {
+            // Stand-in for the JWT middleware
+            if let Some(claims) = claims.clone() {
+                req.extensions_mut().insert(claims);
+            }
+            next.run(req)
+        }))
+}
+
+fn request_from(ip: &str) -> Request {
+    Request::builder()
+        .uri("/")
+        .header("x-real-ip", ip)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_middleware_rejects_request_over_limit() {
+    let (_redis, limiter) = setup().await;
+    let app = limited_router(limiter, RateLimitTier::new("mw", 2, 60), None);
+
+    for _ in 0..2 {
+        let response = app.clone().oneshot(request_from("10.0.0.1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key("x-ratelimit-remaining"));
+    }
+
+    let response = app.clone().oneshot(request_from("10.0.0.1")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("retry-after"));
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["error"], "RATE_LIMIT_EXCEEDED");
+    assert_eq!(body["code"], 1012);
+
+    // A different client is unaffected
+    let response = app.oneshot(request_from("10.0.0.2")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_middleware_authenticated_limit() {
+    let (_redis, limiter) = setup().await;
+    let tier = RateLimitTier::new("mw-auth", 1, 60).with_authenticated_limit(3);
+
+    let anonymous = limited_router(limiter.clone(), tier.clone(), None);
+    assert_eq!(
+        anonymous
+            .clone()
+            .oneshot(request_from("10.0.0.3"))
+            .await
+            .unwrap()
+            .status(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        anonymous
+            .oneshot(request_from("10.0.0.3"))
+            .await
+            .unwrap()
+            .status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    let authenticated = limited_router(limiter, tier, Some("user-1"));
+    for _ in 0..3 {
+        let response = authenticated
+            .clone()
+            .oneshot(request_from("10.0.0.3"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = authenticated
+        .oneshot(request_from("10.0.0.3"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn test_middleware_window_resets() {
+    let (_redis, limiter) = setup().await;
+    let app = limited_router(limiter, RateLimitTier::new("mw-reset", 1, 1), None);
+
+    assert_eq!(
+        app.clone()
+            .oneshot(request_from("10.0.0.4"))
+            .await
+            .unwrap()
+            .status(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        app.clone()
+            .oneshot(request_from("10.0.0.4"))
+            .await
+            .unwrap()
+            .status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    // Two full windows so the previous window's weight drops to zero
+    tokio::time::sleep(tokio::time::Duration::from_millis(2100)).await;
+
+    assert_eq!(
+        app.oneshot(request_from("10.0.0.4"))
+            .await
+            .unwrap()
+            .status(),
+        StatusCode::OK
+    );
+}
@@ -0,0 +1,259 @@
+//! JWT-backed `AuthContext` extraction.
+//!
+//! With the `jwt` feature, the Axum extractor stops trusting the
+//! `x-user-*` headers and instead verifies `Authorization: Bearer <token>`
+//! against a [`JwtAuthConfig`] stored in request extensions.
+//!
+//! Only the signature, expiry and token type are checked. Revocation is
+//! **not** enforced: unlike `axum_helpers::jwt_auth_middleware`, there is no
+//! Redis whitelist/blacklist lookup, so a logged-out or family-revoked access
+//! token is accepted until it expires. Keep access token lifetimes short, or
+//! put `jwt_auth_middleware` in front of routes that need revocation.
+//!
+//! ```ignore
+//! use axum::{Extension, Router};
+//! use field_selector::JwtAuthConfig;
+//!
+//! let app = Router::new()
+//!     .route("/todos", get(list_todos))
+//!     .layer(Extension(JwtAuthConfig::from_secret(secret.as_bytes())));
+//! ```
+
+use super::*;
+use axum::{
+    http::{StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+};
+use jsonwebtoken::{DecodingKey, Validation, decode};
+
+/// Key and validation rules used to verify bearer tokens
+#[derive(Clone)]
+pub struct JwtAuthConfig {
+    decoding_key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtAuthConfig {
+    /// Verify HS256 tokens signed with a shared secret
+    pub fn from_secret(secret: &[u8]) -> Self {
+        Self::new(DecodingKey::from_secret(secret), Validation::default())
+    }
+
+    /// Verify tokens with a custom key and validation rules
+    pub fn new(decoding_key: DecodingKey, validation: Validation) -> Self {
+        Self {
+            decoding_key,
+            validation,
+        }
+    }
+
+    /// Verify a token and build the auth context from its claims
+    ///
+    /// Refresh tokens (claims with a `fid`) are rejected. Revoked tokens are
+    /// not detected; see the module docs.
+    pub fn verify(&self, token: &str) -> Result<AuthContext, AuthRejection> {
+        let data = decode::<AuthClaims>(token, &self.decoding_key, &self.validation)
+            .map_err(|e| AuthRejection::InvalidToken(e.to_string()))?;
+
+        let claims = data.claims;
+        if claims.fid.is_some() {
+            return Err(AuthRejection::InvalidToken(
+                "refresh tokens are not accepted".to_string(),
+            ));
+        }
+        let user_id = Uuid::parse_str(&claims.sub)
+            .map_err(|_| AuthRejection::InvalidToken("subject is not a UUID".to_string()))?;
+
+        // Any authenticated user is at least a User
+        let role = if claims.roles.iter().any(|r| r.eq_ignore_ascii_case("admin")) {
+            UserRole::Admin
+        } else {
+            UserRole::User
+        };
+
+        Ok(AuthContext {
+            user_id: Some(user_id),
+            role,
+            username: claims.name,
+        })
+    }
+}
+
+/// Claims read from the bearer token
+///
+/// Reads the access tokens issued by `axum_helpers::JwtRedisAuth`; its
+/// refresh tokens are told apart by their family ID (`fid`).
+#[derive(Debug, Deserialize)]
+struct AuthClaims {
+    sub: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(default)]
+    fid: Option<String>,
+}
+
+/// Rejection when the bearer token can't be used
+#[derive(Debug, thiserror::Error)]
+pub enum AuthRejection {
+    #[error("Invalid bearer token: {0}")]
+    InvalidToken(String),
+    #[error("JWT authentication is not configured")]
+    MissingConfig,
+}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            AuthRejection::InvalidToken(_) => StatusCode::UNAUTHORIZED,
+            AuthRejection::MissingConfig => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// Build the auth context from the request's bearer token
+///
+/// No `Authorization` header means an anonymous request.
+pub(crate) fn auth_from_parts(parts: &Parts) -> Result<AuthContext, AuthRejection> {
+    let Some(value) = parts.headers.get(header::AUTHORIZATION) else {
+        return Ok(AuthContext::anonymous());
+    };
+
+    let token = value
+        .to_str()
+        .ok()
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or_else(|| AuthRejection::InvalidToken("expected a Bearer token".to_string()))?;
+
+    let config = parts.extensions.get::<JwtAuthConfig>().ok_or_else(|| {
+        tracing::error!("JwtAuthConfig missing from request extensions");
+        AuthRejection::MissingConfig
+    })?;
+
+    config.verify(token.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::FromRequestParts;
+    use axum::http::Request;
+    use jsonwebtoken::{EncodingKey, Header, encode};
+
+    const SECRET: &[u8] = b"test-secret-that-is-at-least-32-chars";
+
+    fn token(sub: &str, roles: &[&str], exp_offset_secs: i64) -> String {
+        encode_claims(claims(sub, roles, exp_offset_secs))
+    }
+
+    fn claims(sub: &str, roles: &[&str], exp_offset_secs: i64) -> serde_json::Value {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        serde_json::json!({
+            "sub": sub,
+            "name": "alice",
+            "roles": roles,
+            "iat": now,
+            "exp": now + exp_offset_secs,
+        })
+    }
+
+    fn encode_claims(claims: serde_json::Value) -> String {
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap()
+    }
+
+    fn parts(authorization: Option<&str>) -> Parts {
+        let mut builder = Request::builder()
+            .uri("/todos")
+            .header("x-user-role", "admin")
+            .extension(JwtAuthConfig::from_secret(SECRET));
+        if let Some(value) = authorization {
+            builder = builder.header(header::AUTHORIZATION, value);
+        }
+        builder.body(()).unwrap().into_parts().0
+    }
+
+    async fn extract(mut parts: Parts) -> Result<AuthContext, AuthRejection> {
+        AuthContext::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn test_signed_token() {
+        let user_id = Uuid::now_v7();
+        let bearer = format!("Bearer {}", token(&user_id.to_string(), &["admin"], 3600));
+
+        let auth = extract(parts(Some(&bearer))).await.unwrap();
+
+        assert_eq!(auth.user_id, Some(user_id));
+        assert_eq!(auth.role, UserRole::Admin);
+        assert_eq!(auth.username.as_deref(), Some("alice"));
+    }
+
+    #[tokio::test]
+    async fn test_signed_token_without_admin_role() {
+        let bearer = format!(
+            "Bearer {}",
+            token(&Uuid::now_v7().to_string(), &["user"], 3600)
+        );
+
+        let auth = extract(parts(Some(&bearer))).await.unwrap();
+
+        assert_eq!(auth.role, UserRole::User);
+    }
+
+    #[tokio::test]
+    async fn test_expired_token() {
+        let bearer = format!(
+            "Bearer {}",
+            token(&Uuid::now_v7().to_string(), &["admin"], -3600)
+        );
+
+        let err = extract(parts(Some(&bearer))).await.unwrap_err();
+
+        assert!(matches!(err, AuthRejection::InvalidToken(_)));
+        assert_eq!(err.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_missing_token_is_anonymous() {
+        // Role headers are ignored when JWT auth is enabled
+        let auth = extract(parts(None)).await.unwrap();
+
+        assert!(!auth.is_authenticated());
+        assert_eq!(auth.role, UserRole::Anonymous);
+    }
+
+    #[tokio::test]
+    async fn test_tampered_token() {
+        let mut forged = token(&Uuid::now_v7().to_string(), &["admin"], 3600);
+        forged.push('x');
+
+        let err = extract(parts(Some(&format!("Bearer {forged}"))))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthRejection::InvalidToken(_)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_is_rejected() {
+        let mut refresh = claims(&Uuid::now_v7().to_string(), &["admin"], 3600);
+        refresh["fid"] = serde_json::json!(Uuid::now_v7().to_string());
+        let bearer = format!("Bearer {}", encode_claims(refresh));
+
+        let err = extract(parts(Some(&bearer))).await.unwrap_err();
+
+        assert!(matches!(err, AuthRejection::InvalidToken(_)));
+    }
+}
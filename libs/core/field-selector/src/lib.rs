@@ -6,6 +6,9 @@
 //! # Features
 //!
 //! - `axum` - Enables Axum integration with `FromRequestParts` extractor
+//! - `sea-orm` - Enables pushing field selection into SeaORM queries
+//! - `jwt` - Extracts `AuthContext` from a verified bearer token instead of
+//!   trusting `x-user-*` headers (implies `axum`)
 
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -120,16 +123,21 @@ pub trait SelectableFields: Serialize {
             .collect()
     }
 
+    /// Get named groups of fields
+    /// Requesting a group name selects all of its fields
+    fn field_groups() -> HashMap<&'static str, Vec<&'static str>> {
+        HashMap::new()
+    }
+
     /// Validate that requested fields are valid
+    ///
+    /// Dotted paths (`author.name`) are validated by their first segment.
     fn validate_fields(fields: &HashSet<String>) -> Result<(), Vec<String>> {
-        let available: HashSet<String> = Self::available_fields()
-            .into_iter()
-            .map(String::from)
-            .collect();
+        let available: HashSet<&str> = Self::available_fields().into_iter().collect();
 
         let invalid: Vec<String> = fields
             .iter()
-            .filter(|f| !available.contains(*f))
+            .filter(|f| f.split('.').any(str::is_empty) || !available.contains(root_field(f)))
             .cloned()
             .collect();
 
@@ -155,8 +163,11 @@ pub trait SelectableFields: Serialize {
         fields
             .iter()
             .filter(|field| {
+                // Nested paths inherit the access rules of their top-level field
+                let root = root_field(field);
+
                 // Filter out restricted fields
-                if restricted.contains(*field) {
+                if restricted.contains(root) {
                     tracing::warn!(
                         field = field.as_str(),
                         "Attempted access to restricted field"
@@ -165,7 +176,7 @@ pub trait SelectableFields: Serialize {
                 }
 
                 // Check role-based access
-                if let Some(required_role) = access_map.get(*field)
+                if let Some(required_role) = access_map.get(root)
                     && !auth.has_role(required_role)
                 {
                     tracing::warn!(
@@ -189,6 +200,8 @@ pub trait SelectableFields: Serialize {
 pub enum FieldSelectionError {
     #[error("Invalid fields requested: {0:?}")]
     InvalidFields(Vec<String>),
+    #[error("Insufficient permissions for fields: {0:?}")]
+    ForbiddenFields(Vec<String>),
     #[error("Serialization error: {0}")]
     SerializationError(String),
 }
@@ -199,10 +212,23 @@ pub enum FieldSelectionError {
 pub struct FieldSelector {
     #[serde(default)]
     pub fields: Option<String>,
+    /// Reject explicitly requested fields the user can't access with
+    /// `ForbiddenFields` instead of silently dropping them.
+    /// Set by the server, never read from the query string.
+    #[serde(skip)]
+    pub strict_roles: bool,
 }
 
 impl FieldSelector {
+    /// Enable or disable strict role checking
+    pub fn with_strict_roles(mut self, strict: bool) -> Self {
+        self.strict_roles = strict;
+        self
+    }
+
     /// Get the set of requested fields
+    ///
+    /// Entries are returned as written, including any leading `-`.
     pub fn get_fields(&self) -> Option<HashSet<String>> {
         self.fields.as_ref().map(|f| {
             f.split(',')
@@ -212,9 +238,16 @@ impl FieldSelector {
         })
     }
 
+    /// Check whether this is an exclusion request (`fields=-email,-internal_id`)
+    pub fn is_exclusion(&self) -> bool {
+        self.get_fields()
+            .is_some_and(|fields| fields.iter().any(|f| f.starts_with('-')))
+    }
+
     /// Check if a specific field is requested
     pub fn includes(&self, field: &str) -> bool {
         match self.get_fields() {
+            Some(fields) if self.is_exclusion() => !fields.contains(&format!("-{field}")),
             Some(fields) => fields.contains(field),
             None => true, // If no fields specified, include all
         }
@@ -225,12 +258,58 @@ impl FieldSelector {
     where
         T: SelectableFields,
     {
-        match self.get_fields() {
+        match self.get_fields().map(|fields| expand_groups::<T>(&fields)) {
+            Some(ref fields) if self.is_exclusion() => {
+                let (excluded, included): (HashSet<String>, HashSet<String>) =
+                    fields.iter().cloned().partition(|f| f.starts_with('-'));
+
+                if !included.is_empty() {
+                    let mut included: Vec<String> = included.into_iter().collect();
+                    included.sort();
+                    return Err(FieldSelectionError::InvalidFields(vec![format!(
+                        "cannot mix included and excluded fields (included: {})",
+                        included.join(", ")
+                    )]));
+                }
+
+                let excluded: HashSet<String> = excluded
+                    .iter()
+                    .map(|f| f.trim_start_matches('-').to_string())
+                    .collect();
+
+                // Only whole top-level fields can be excluded
+                let available: HashSet<&str> = T::available_fields().into_iter().collect();
+                let mut invalid: Vec<String> = excluded
+                    .iter()
+                    .filter(|f| !available.contains(f.as_str()))
+                    .map(|f| format!("-{f}"))
+                    .collect();
+                if !invalid.is_empty() {
+                    invalid.sort();
+                    return Err(FieldSelectionError::InvalidFields(invalid));
+                }
+
+                // Everything else, still subject to role checks
+                let remaining: HashSet<String> = available
+                    .into_iter()
+                    .filter(|f| !excluded.contains(*f))
+                    .map(String::from)
+                    .collect();
+                Ok(T::filter_by_role(&remaining, auth))
+            }
             Some(ref fields) => {
                 // Validate that requested fields exist
                 T::validate_fields(fields).map_err(FieldSelectionError::InvalidFields)?;
                 // Filter by role and restrictions
-                Ok(T::filter_by_role(fields, auth))
+                let allowed = T::filter_by_role(fields, auth);
+
+                if self.strict_roles && allowed.len() < fields.len() {
+                    let mut forbidden: Vec<String> = fields.difference(&allowed).cloned().collect();
+                    forbidden.sort();
+                    return Err(FieldSelectionError::ForbiddenFields(forbidden));
+                }
+
+                Ok(allowed)
             }
             None => {
                 // Return all fields the user has access to
@@ -314,20 +393,132 @@ impl FieldSelector {
     }
 }
 
+/// Replace requested group names with the fields in each group
+///
+/// Real field names take precedence over a group of the same name. A leading
+/// `-` is kept on the expanded names so groups can be excluded too.
+fn expand_groups<T: SelectableFields>(fields: &HashSet<String>) -> HashSet<String> {
+    let groups = T::field_groups();
+    if groups.is_empty() {
+        return fields.clone();
+    }
+
+    let available: HashSet<&str> = T::available_fields().into_iter().collect();
+
+    fields
+        .iter()
+        .flat_map(|field| {
+            let (prefix, name) = match field.strip_prefix('-') {
+                Some(name) => ("-", name),
+                None => ("", field.as_str()),
+            };
+
+            match groups.get(name) {
+                Some(members) if !available.contains(name) => members
+                    .iter()
+                    .map(|member| format!("{prefix}{member}"))
+                    .collect(),
+                _ => vec![field.clone()],
+            }
+        })
+        .collect()
+}
+
+/// Top-level field of a possibly dotted path (`author.name` -> `author`)
+fn root_field(path: &str) -> &str {
+    path.split('.').next().unwrap_or(path)
+}
+
+/// Requested field paths grouped by their first segment
+///
+/// A `None` child means the whole value is selected; `Some` narrows it to
+/// the nested paths.
+#[derive(Debug, Default)]
+struct FieldTree {
+    children: HashMap<String, Option<FieldTree>>,
+}
+
+impl FieldTree {
+    fn from_paths<'a>(paths: impl IntoIterator<Item = &'a String>) -> Self {
+        let mut tree = Self::default();
+        for path in paths {
+            tree.insert(path);
+        }
+        tree
+    }
+
+    fn insert(&mut self, path: &str) {
+        match path.split_once('.') {
+            None => {
+                // Selecting the whole field overrides any nested selection
+                self.children.insert(path.to_string(), None);
+            }
+            Some((head, rest)) => {
+                if let Some(subtree) = self
+                    .children
+                    .entry(head.to_string())
+                    .or_insert_with(|| Some(FieldTree::default()))
+                {
+                    subtree.insert(rest);
+                }
+            }
+        }
+    }
+}
+
 /// Helper function to filter JSON object by field names
+///
+/// Dotted paths recurse into nested objects and into each element of arrays.
 fn filter_object(obj: Map<String, Value>, fields: &HashSet<String>) -> Map<String, Value> {
+    filter_map(obj, &FieldTree::from_paths(fields))
+}
+
+fn filter_map(obj: Map<String, Value>, tree: &FieldTree) -> Map<String, Value> {
     obj.into_iter()
-        .filter(|(k, _)| fields.contains(k))
+        .filter_map(|(k, v)| match tree.children.get(&k)? {
+            None => Some((k, v)),
+            Some(subtree) => Some((k, filter_nested(v, subtree))),
+        })
         .collect()
 }
 
+fn filter_nested(value: Value, tree: &FieldTree) -> Value {
+    match value {
+        Value::Object(obj) => Value::Object(filter_map(obj, tree)),
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| filter_nested(item, tree))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
 // Axum integration - only available with the "axum" feature
 #[cfg(feature = "axum")]
 mod axum_integration {
     use super::*;
     use axum::{extract::FromRequestParts, http::request::Parts};
 
+    /// Extractor for AuthContext from a verified bearer token
+    #[cfg(feature = "jwt")]
+    impl<S> FromRequestParts<S> for AuthContext
+    where
+        S: Send + Sync,
+    {
+        type Rejection = crate::jwt::AuthRejection;
+
+        async fn from_request_parts(
+            parts: &mut Parts,
+            _state: &S,
+        ) -> Result<Self, Self::Rejection> {
+            crate::jwt::auth_from_parts(parts)
+        }
+    }
+
     /// Extractor for AuthContext from request
+    #[cfg(not(feature = "jwt"))]
     impl<S> FromRequestParts<S> for AuthContext
     where
         S: Send + Sync,
@@ -372,6 +563,20 @@ mod axum_integration {
     }
 }
 
+// JWT authentication - only available with the "jwt" feature
+#[cfg(feature = "jwt")]
+mod jwt;
+
+#[cfg(feature = "jwt")]
+pub use jwt::{AuthRejection, JwtAuthConfig};
+
+// SeaORM integration - only available with the "sea-orm" feature
+#[cfg(feature = "sea-orm")]
+mod sea_orm_integration;
+
+#[cfg(feature = "sea-orm")]
+pub use sea_orm_integration::{EntityFields, Projection};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -399,6 +604,7 @@ mod tests {
 
         let selector = FieldSelector {
             fields: Some("id,name".to_string()),
+            ..Default::default()
         };
 
         let auth = AuthContext::anonymous();
@@ -411,6 +617,351 @@ mod tests {
         assert!(!obj.contains_key("email"));
     }
 
+    #[derive(Serialize)]
+    struct Author {
+        name: String,
+        email: String,
+    }
+
+    #[derive(Serialize)]
+    struct Comment {
+        author: Author,
+        body: String,
+    }
+
+    #[derive(Serialize)]
+    struct PostDto {
+        id: i32,
+        title: String,
+        author: Author,
+        comments: Vec<Comment>,
+    }
+
+    impl SelectableFields for PostDto {
+        fn available_fields() -> Vec<&'static str> {
+            vec!["id", "title", "author", "comments"]
+        }
+    }
+
+    fn post() -> PostDto {
+        let author = |name: &str| Author {
+            name: name.to_string(),
+            email: format!("{name}@example.com"),
+        };
+
+        PostDto {
+            id: 1,
+            title: "Hello".to_string(),
+            author: author("alice"),
+            comments: vec![
+                Comment {
+                    author: author("bob"),
+                    body: "First".to_string(),
+                },
+                Comment {
+                    author: author("carol"),
+                    body: "Second".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_nested_field_selection() {
+        let selector = FieldSelector {
+            fields: Some("author.name,id".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&post(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(
+            filtered,
+            serde_json::json!({
+                "id": 1,
+                "author": { "name": "alice" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_field_selection_in_arrays() {
+        let selector = FieldSelector {
+            fields: Some("comments.author.name,comments.body".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&post(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(
+            filtered,
+            serde_json::json!({
+                "comments": [
+                    { "author": { "name": "bob" }, "body": "First" },
+                    { "author": { "name": "carol" }, "body": "Second" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_whole_field_overrides_nested_path() {
+        let selector = FieldSelector {
+            fields: Some("author.name,author".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&post(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(
+            filtered,
+            serde_json::json!({
+                "author": { "name": "alice", "email": "alice@example.com" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_field_validation() {
+        let valid: HashSet<String> = ["author.name", "comments.body"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert!(PostDto::validate_fields(&valid).is_ok());
+
+        let invalid: HashSet<String> = ["editor.name", "author."]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut errors = PostDto::validate_fields(&invalid).unwrap_err();
+        errors.sort();
+        assert_eq!(errors, vec!["author.", "editor.name"]);
+    }
+
+    fn test_dto() -> TestDto {
+        TestDto {
+            id: 1,
+            name: "test".to_string(),
+            email: "test@example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_field_exclusion() {
+        let selector = FieldSelector {
+            fields: Some("-email".to_string()),
+            ..Default::default()
+        };
+        assert!(selector.is_exclusion());
+        assert!(selector.includes("name"));
+        assert!(!selector.includes("email"));
+
+        let filtered = selector
+            .filter_secure(&test_dto(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(filtered, serde_json::json!({ "id": 1, "name": "test" }));
+    }
+
+    #[test]
+    fn test_field_exclusion_of_everything() {
+        let selector = FieldSelector {
+            fields: Some("-id,-name,-email".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&test_dto(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(filtered, serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_field_exclusion_rejects_mixed_mode() {
+        let selector = FieldSelector {
+            fields: Some("id,-email".to_string()),
+            ..Default::default()
+        };
+
+        let err = selector
+            .filter_secure(&test_dto(), &AuthContext::anonymous())
+            .unwrap_err();
+
+        match err {
+            FieldSelectionError::InvalidFields(messages) => {
+                assert_eq!(messages.len(), 1);
+                assert!(messages[0].contains("cannot mix"));
+                assert!(messages[0].contains("id"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_field_exclusion_rejects_unknown_fields() {
+        let selector = FieldSelector {
+            fields: Some("-email,-nope".to_string()),
+            ..Default::default()
+        };
+
+        let err = selector
+            .filter_secure(&test_dto(), &AuthContext::anonymous())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            FieldSelectionError::InvalidFields(fields) if fields == vec!["-nope".to_string()]
+        ));
+    }
+
+    #[derive(Serialize)]
+    struct ProfileDto {
+        id: i32,
+        name: String,
+        email: String,
+        summary: String,
+    }
+
+    impl SelectableFields for ProfileDto {
+        fn available_fields() -> Vec<&'static str> {
+            vec!["id", "name", "email", "summary"]
+        }
+
+        fn field_access() -> Vec<FieldAccess> {
+            vec![FieldAccess {
+                field: "email",
+                required_role: UserRole::User,
+            }]
+        }
+
+        fn field_groups() -> HashMap<&'static str, Vec<&'static str>> {
+            HashMap::from([
+                ("basic", vec!["id", "name"]),
+                ("contact", vec!["name", "email"]),
+                ("summary", vec!["id"]),
+            ])
+        }
+    }
+
+    fn profile() -> ProfileDto {
+        ProfileDto {
+            id: 1,
+            name: "test".to_string(),
+            email: "test@example.com".to_string(),
+            summary: "A profile".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_field_group_expansion() {
+        let selector = FieldSelector {
+            fields: Some("basic".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&profile(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(filtered, serde_json::json!({ "id": 1, "name": "test" }));
+    }
+
+    #[test]
+    fn test_field_group_respects_roles() {
+        let selector = FieldSelector {
+            fields: Some("contact".to_string()),
+            ..Default::default()
+        };
+
+        let anonymous = selector
+            .filter_secure(&profile(), &AuthContext::anonymous())
+            .unwrap();
+        assert_eq!(anonymous, serde_json::json!({ "name": "test" }));
+
+        let user = AuthContext::user(Uuid::now_v7(), "testuser".to_string());
+        let filtered = selector.filter_secure(&profile(), &user).unwrap();
+        assert_eq!(
+            filtered,
+            serde_json::json!({ "name": "test", "email": "test@example.com" })
+        );
+    }
+
+    #[test]
+    fn test_field_name_wins_over_group() {
+        let selector = FieldSelector {
+            fields: Some("summary".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&profile(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(filtered, serde_json::json!({ "summary": "A profile" }));
+    }
+
+    #[test]
+    fn test_forbidden_field_lenient_by_default() {
+        let selector = FieldSelector {
+            fields: Some("id,email".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = selector
+            .filter_secure(&profile(), &AuthContext::anonymous())
+            .unwrap();
+
+        assert_eq!(filtered, serde_json::json!({ "id": 1 }));
+    }
+
+    #[test]
+    fn test_forbidden_field_strict_roles() {
+        let selector = FieldSelector {
+            fields: Some("id,email".to_string()),
+            ..Default::default()
+        }
+        .with_strict_roles(true);
+
+        let err = selector
+            .filter_secure(&profile(), &AuthContext::anonymous())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            FieldSelectionError::ForbiddenFields(fields) if fields == vec!["email".to_string()]
+        ));
+
+        // Unknown fields are still reported as invalid, not forbidden
+        let selector = FieldSelector {
+            fields: Some("id,nope".to_string()),
+            ..Default::default()
+        }
+        .with_strict_roles(true);
+        let err = selector
+            .filter_secure(&profile(), &AuthContext::anonymous())
+            .unwrap_err();
+        assert!(matches!(err, FieldSelectionError::InvalidFields(_)));
+
+        // Permitted users get the field as usual
+        let user = AuthContext::user(Uuid::now_v7(), "testuser".to_string());
+        let selector = FieldSelector {
+            fields: Some("id,email".to_string()),
+            ..Default::default()
+        }
+        .with_strict_roles(true);
+        let filtered = selector.filter_secure(&profile(), &user).unwrap();
+        assert_eq!(
+            filtered,
+            serde_json::json!({ "id": 1, "email": "test@example.com" })
+        );
+    }
+
     #[test]
     fn test_user_role_permissions() {
         assert!(UserRole::Admin.has_permission(&UserRole::Admin));
@@ -0,0 +1,217 @@
+//! SeaORM integration: push field selection down into the SQL projection.
+//!
+//! Instead of fetching full rows and filtering the serialized JSON, the
+//! resolved field set is translated into `select_only().columns([...])`.
+//! Results should be read with `into_json()` (the full `Model` can't be
+//! built from a partial row), then passed through the usual post-filter.
+
+use super::*;
+use sea_orm::{EntityTrait, QuerySelect, Select};
+use std::str::FromStr;
+
+/// Maps a [`SelectableFields`] type onto the SeaORM entity that backs it.
+pub trait EntityFields: SelectableFields {
+    /// Entity the DTO is read from
+    type Entity: EntityTrait;
+
+    /// Column backing a JSON field name, or `None` if the field isn't a
+    /// plain column (e.g. computed or renamed in serialization).
+    ///
+    /// By default fields map to the column of the same name.
+    fn column_for(field: &str) -> Option<<Self::Entity as EntityTrait>::Column> {
+        <<Self::Entity as EntityTrait>::Column as FromStr>::from_str(field).ok()
+    }
+}
+
+/// Columns to fetch for a field selection.
+#[derive(Debug)]
+pub enum Projection<E: EntityTrait> {
+    /// Fetch only these columns
+    Columns(Vec<E::Column>),
+    /// Fetch the full row and rely on the post-filter
+    All,
+}
+
+impl<E: EntityTrait> Projection<E> {
+    /// Apply the projection to a query
+    pub fn apply(self, select: Select<E>) -> Select<E> {
+        match self {
+            Projection::Columns(columns) => select.select_only().columns(columns),
+            Projection::All => select,
+        }
+    }
+}
+
+impl FieldSelector {
+    /// Resolve the SQL projection for the requested and allowed fields
+    ///
+    /// Falls back to [`Projection::All`] when any allowed field has no column
+    /// mapping, or when no fields remain after role filtering.
+    pub fn projection<T>(
+        &self,
+        auth: &AuthContext,
+    ) -> Result<Projection<T::Entity>, FieldSelectionError>
+    where
+        T: EntityFields,
+    {
+        // Nested paths need their whole top-level column
+        let fields: HashSet<String> = self
+            .resolve_fields::<T>(auth)?
+            .iter()
+            .map(|f| root_field(f).to_string())
+            .collect();
+
+        // Keep declaration order so the generated SQL is stable
+        let mut columns = Vec::with_capacity(fields.len());
+        for field in T::available_fields() {
+            if !fields.contains(field) {
+                continue;
+            }
+            match T::column_for(field) {
+                Some(column) => columns.push(column),
+                None => {
+                    tracing::debug!(field, "No column mapping, selecting full row");
+                    return Ok(Projection::All);
+                }
+            }
+        }
+
+        if columns.is_empty() {
+            return Ok(Projection::All);
+        }
+
+        Ok(Projection::Columns(columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    mod item {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "items")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+            pub description: String,
+            pub secret: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    // Only the field metadata is used, instances are never built
+    #[allow(dead_code)]
+    #[derive(Serialize)]
+    struct ItemDto {
+        id: i32,
+        name: String,
+        description: String,
+        secret: String,
+    }
+
+    impl SelectableFields for ItemDto {
+        fn available_fields() -> Vec<&'static str> {
+            vec!["id", "name", "description", "secret"]
+        }
+
+        fn field_access() -> Vec<FieldAccess> {
+            vec![FieldAccess {
+                field: "secret",
+                required_role: UserRole::Admin,
+            }]
+        }
+    }
+
+    impl EntityFields for ItemDto {
+        type Entity = item::Entity;
+    }
+
+    /// DTO exposing a field that isn't a column
+    #[allow(dead_code)]
+    #[derive(Serialize)]
+    struct ItemSummaryDto {
+        id: i32,
+        label: String,
+    }
+
+    impl SelectableFields for ItemSummaryDto {
+        fn available_fields() -> Vec<&'static str> {
+            vec!["id", "label"]
+        }
+    }
+
+    impl EntityFields for ItemSummaryDto {
+        type Entity = item::Entity;
+    }
+
+    fn sql<T: EntityFields>(selector: &FieldSelector, auth: &AuthContext) -> String {
+        selector
+            .projection::<T>(auth)
+            .unwrap()
+            .apply(T::Entity::find())
+            .build(DbBackend::Postgres)
+            .to_string()
+    }
+
+    #[test]
+    fn test_projection_selects_requested_columns() {
+        let selector = FieldSelector {
+            fields: Some("name,id".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sql::<ItemDto>(&selector, &AuthContext::anonymous()),
+            r#"SELECT "items"."id", "items"."name" FROM "items""#
+        );
+    }
+
+    #[test]
+    fn test_projection_excludes_forbidden_columns() {
+        let selector = FieldSelector::default();
+
+        assert_eq!(
+            sql::<ItemDto>(&selector, &AuthContext::anonymous()),
+            r#"SELECT "items"."id", "items"."name", "items"."description" FROM "items""#
+        );
+
+        let admin = AuthContext::admin(Uuid::now_v7(), "admin".to_string());
+        assert_eq!(
+            sql::<ItemDto>(&selector, &admin),
+            r#"SELECT "items"."id", "items"."name", "items"."description", "items"."secret" FROM "items""#
+        );
+    }
+
+    #[test]
+    fn test_projection_falls_back_without_mapping() {
+        let selector = FieldSelector {
+            fields: Some("id,label".to_string()),
+            ..Default::default()
+        };
+
+        let projection = selector
+            .projection::<ItemSummaryDto>(&AuthContext::anonymous())
+            .unwrap();
+        assert!(matches!(projection, Projection::All));
+    }
+
+    #[test]
+    fn test_projection_rejects_invalid_fields() {
+        let selector = FieldSelector {
+            fields: Some("id,nope".to_string()),
+            ..Default::default()
+        };
+
+        let result = selector.projection::<ItemDto>(&AuthContext::anonymous());
+        assert!(matches!(result, Err(FieldSelectionError::InvalidFields(_))));
+    }
+}
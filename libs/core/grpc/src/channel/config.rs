@@ -1,5 +1,13 @@
+use std::collections::HashMap;
 use std::time::Duration;
 use tonic::transport::Endpoint;
+#[cfg(feature = "tls")]
+use tonic::transport::{Certificate, ClientTlsConfig, Identity};
+
+#[cfg(feature = "tls")]
+use crate::error::GrpcError;
+use crate::error::GrpcResult;
+use crate::interceptors::DeadlineInterceptor;
 
 /// Configuration for gRPC channel creation
 ///
@@ -16,6 +24,8 @@ pub struct ChannelConfig {
     // Connection settings
     pub connect_timeout: Duration,
     pub timeout: Duration,
+    /// Per-method request timeouts keyed by full path (`/package.Service/Method`)
+    pub method_timeouts: HashMap<String, Duration>,
 
     // Window sizes (HTTP/2 flow control)
     pub initial_connection_window_size: Option<u32>,
@@ -28,6 +38,24 @@ pub struct ChannelConfig {
 
     // Concurrency
     pub max_concurrent_streams: Option<u32>,
+
+    /// TLS settings; `https://` URIs without one use the system roots
+    #[cfg(feature = "tls")]
+    pub tls: Option<ClientTlsConfig>,
+
+    /// Server name for TLS verification; enables TLS when set
+    #[cfg(feature = "tls")]
+    pub tls_domain: Option<String>,
+}
+
+/// TLS settings an endpoint ends up with
+#[cfg(feature = "tls")]
+pub(crate) struct ResolvedTls {
+    pub config: ClientTlsConfig,
+    /// No TLS settings were given, so the server is verified against the
+    /// system roots
+    pub system_roots: bool,
+    pub domain: Option<String>,
 }
 
 impl Default for ChannelConfig {
@@ -42,12 +70,17 @@ impl Default for ChannelConfig {
             keep_alive_while_idle: true,
             connect_timeout: Duration::from_secs(5),
             timeout: Duration::from_secs(30),
+            method_timeouts: HashMap::new(),
             initial_connection_window_size: Some(1024 * 1024), // 1MB
             initial_stream_window_size: Some(1024 * 1024),     // 1MB
             http2_adaptive_window: true,
             tcp_nodelay: true,
             tcp_keepalive: Some(Duration::from_secs(30)),
             max_concurrent_streams: None, // Use tonic's default
+            #[cfg(feature = "tls")]
+            tls: None,
+            #[cfg(feature = "tls")]
+            tls_domain: None,
         }
     }
 }
@@ -82,6 +115,34 @@ impl ChannelConfig {
         self
     }
 
+    /// Override the request timeout for a single method
+    ///
+    /// `path` is the full gRPC method path. Takes effect through
+    /// [`deadline_interceptor`](Self::deadline_interceptor); methods without
+    /// an override use the request timeout.
+    ///
+    /// The request timeout still caps every call on the channel, so an
+    /// override can only tighten it. Give methods that need longer a separate
+    /// channel with a larger request timeout.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = ChannelConfig::new()
+    ///     .with_request_timeout(Duration::from_secs(10))
+    ///     .with_method_timeout("/tasks.TasksService/GetById", Duration::from_secs(2));
+    /// ```
+    pub fn with_method_timeout(mut self, path: impl Into<String>, timeout: Duration) -> Self {
+        self.method_timeouts.insert(path.into(), timeout);
+        self
+    }
+
+    /// Build an interceptor applying the per-method timeouts
+    ///
+    /// Attach it to clients created on a channel built from this config.
+    pub fn deadline_interceptor(&self) -> DeadlineInterceptor {
+        DeadlineInterceptor::with_methods(self.timeout, self.method_timeouts.clone())
+    }
+
     /// Set the maximum number of concurrent streams per HTTP/2 connection
     ///
     /// # Example
@@ -130,12 +191,93 @@ impl ChannelConfig {
         self
     }
 
+    /// Use TLS with the given settings
+    ///
+    /// Requires the `tls` feature.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use tonic::transport::{Certificate, ClientTlsConfig};
+    ///
+    /// let config = ChannelConfig::new().with_tls(
+    ///     ClientTlsConfig::new().ca_certificate(Certificate::from_pem(ca_pem)),
+    /// );
+    /// ```
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, tls: ClientTlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Use mutual TLS from PEM-encoded CA, client certificate and key
+    ///
+    /// Requires the `tls` feature. Invalid PEM is reported when the channel
+    /// is created.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = ChannelConfig::new().with_tls_from_pem(
+    ///     std::fs::read("ca.pem")?,
+    ///     std::fs::read("client.pem")?,
+    ///     std::fs::read("client.key")?,
+    /// );
+    /// ```
+    #[cfg(feature = "tls")]
+    pub fn with_tls_from_pem(
+        self,
+        ca: impl AsRef<[u8]>,
+        cert: impl AsRef<[u8]>,
+        key: impl AsRef<[u8]>,
+    ) -> Self {
+        self.with_tls(
+            ClientTlsConfig::new()
+                .ca_certificate(Certificate::from_pem(ca))
+                .identity(Identity::from_pem(cert, key)),
+        )
+    }
+
+    /// Override the server name used for TLS verification
+    ///
+    /// Useful when connecting by IP or through a proxy. Requires the `tls`
+    /// feature; enables TLS with system roots if not configured yet.
+    #[cfg(feature = "tls")]
+    pub fn with_tls_domain(mut self, domain: impl Into<String>) -> Self {
+        self.tls_domain = Some(domain.into());
+        self
+    }
+
+    /// TLS settings for an endpoint, or `None` for plaintext h2c
+    ///
+    /// TLS is used when configured, when a domain is set, or for `https://`
+    /// URIs; the latter two fall back to the system roots.
+    #[cfg(feature = "tls")]
+    pub(crate) fn resolve_tls(&self, https: bool) -> Option<ResolvedTls> {
+        let (config, system_roots) = match &self.tls {
+            Some(tls) => (tls.clone(), false),
+            None if https || self.tls_domain.is_some() => {
+                (ClientTlsConfig::new().with_native_roots(), true)
+            }
+            None => return None,
+        };
+
+        let config = match &self.tls_domain {
+            Some(domain) => config.domain_name(domain.clone()),
+            None => config,
+        };
+
+        Some(ResolvedTls {
+            config,
+            system_roots,
+            domain: self.tls_domain.clone(),
+        })
+    }
+
     /// Apply this configuration to a tonic Endpoint
     ///
     /// This is the core function that transforms our configuration into
     /// tonic's Endpoint API calls. It mirrors the exact configuration from
     /// the original grpc_pool.rs implementation.
-    pub(crate) fn apply_to_endpoint(self, mut endpoint: Endpoint) -> Endpoint {
+    pub(crate) fn apply_to_endpoint(self, mut endpoint: Endpoint) -> GrpcResult<Endpoint> {
         // HTTP/2 keep-alive
         if let Some(interval) = self.http2_keep_alive_interval {
             endpoint = endpoint.http2_keep_alive_interval(interval);
@@ -172,7 +314,21 @@ impl ChannelConfig {
             // Would need tonic upgrade or different approach
         }
 
-        endpoint
+        // TLS (plaintext h2c unless configured or the URI is https)
+        #[cfg(feature = "tls")]
+        if let Some(tls) = self.resolve_tls(endpoint.uri().scheme_str() == Some("https")) {
+            tracing::debug!(
+                target: "grpc_client",
+                system_roots = tls.system_roots,
+                domain = ?tls.domain,
+                "Using TLS"
+            );
+            endpoint = endpoint
+                .tls_config(tls.config)
+                .map_err(|e| GrpcError::InvalidConfig(format!("TLS: {e}")))?;
+        }
+
+        Ok(endpoint)
     }
 }
 
@@ -206,9 +362,96 @@ mod tests {
         assert_eq!(config.initial_stream_window_size, Some(2 * 1024 * 1024));
     }
 
+    #[test]
+    fn test_method_timeouts() {
+        let config = ChannelConfig::new()
+            .with_request_timeout(Duration::from_secs(5))
+            .with_method_timeout("/tasks.TasksService/ListStream", Duration::from_secs(300))
+            .with_method_timeout("/tasks.TasksService/GetById", Duration::from_secs(1));
+
+        // A slow override does not raise the channel-wide timeout
+        assert_eq!(config.timeout, Duration::from_secs(5));
+
+        let deadline = config.deadline_interceptor();
+        assert_eq!(
+            deadline.timeout_for("/tasks.TasksService/ListStream"),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            deadline.timeout_for("/tasks.TasksService/GetById"),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            deadline.timeout_for("/tasks.TasksService/Create"),
+            Duration::from_secs(5)
+        );
+    }
+
     #[test]
     fn test_disable_keep_alive() {
         let config = ChannelConfig::new().without_keep_alive();
         assert_eq!(config.http2_keep_alive_interval, None);
     }
+
+    #[test]
+    fn test_plaintext_endpoint() {
+        let endpoint = Endpoint::from_static("http://[::1]:50051");
+        let endpoint = ChannelConfig::new().apply_to_endpoint(endpoint).unwrap();
+        assert_eq!(endpoint.uri().scheme_str(), Some("http"));
+    }
+
+    #[cfg(feature = "tls")]
+    mod tls {
+        use super::*;
+
+        const CA: &[u8] = include_bytes!("../../tests/fixtures/tls/ca.pem");
+        const CLIENT_CERT: &[u8] = include_bytes!("../../tests/fixtures/tls/client.pem");
+        const CLIENT_KEY: &[u8] = include_bytes!("../../tests/fixtures/tls/client.key");
+
+        #[test]
+        fn test_tls_from_pem_builds_endpoint() {
+            let config = ChannelConfig::new()
+                .with_tls_from_pem(CA, CLIENT_CERT, CLIENT_KEY)
+                .with_tls_domain("tasks.internal");
+
+            let tls = config.resolve_tls(false).unwrap();
+            assert!(!tls.system_roots);
+            assert_eq!(tls.domain.as_deref(), Some("tasks.internal"));
+
+            let endpoint = Endpoint::from_static("https://127.0.0.1:50051");
+            assert!(config.apply_to_endpoint(endpoint).is_ok());
+        }
+
+        #[test]
+        fn test_tls_domain_without_tls_uses_system_roots() {
+            let config = ChannelConfig::new().with_tls_domain("tasks.internal");
+
+            let tls = config.resolve_tls(false).unwrap();
+            assert!(tls.system_roots);
+            assert_eq!(tls.domain.as_deref(), Some("tasks.internal"));
+
+            let endpoint = Endpoint::from_static("https://127.0.0.1:50051");
+            assert!(config.apply_to_endpoint(endpoint).is_ok());
+        }
+
+        #[test]
+        fn test_https_defaults_to_system_roots() {
+            let config = ChannelConfig::new();
+            assert!(config.resolve_tls(true).unwrap().system_roots);
+            assert!(config.resolve_tls(false).is_none());
+
+            let endpoint = Endpoint::from_static("https://tasks.internal:50051");
+            assert!(ChannelConfig::new().apply_to_endpoint(endpoint).is_ok());
+        }
+
+        #[test]
+        fn test_invalid_pem_is_rejected() {
+            let config = ChannelConfig::new().with_tls_from_pem(CA, b"not a cert", b"not a key");
+            let endpoint = Endpoint::from_static("https://127.0.0.1:50051");
+            assert!(matches!(
+                config.apply_to_endpoint(endpoint),
+                Err(GrpcError::InvalidConfig(_))
+            ));
+        }
+    }
 }
@@ -60,7 +60,7 @@ pub async fn create_channel_with_config(
         GrpcError::InvalidUri(e)
     })?;
 
-    let endpoint = config.apply_to_endpoint(endpoint);
+    let endpoint = config.apply_to_endpoint(endpoint)?;
 
     tracing::debug!(
         target: "grpc_client",
@@ -79,6 +79,57 @@ pub async fn create_channel_with_config(
     })
 }
 
+/// Creates a gRPC channel load balanced across several endpoints
+///
+/// Every endpoint gets the same HTTP/2 and TCP tuning as
+/// [`create_channel_with_config`]. Requests go to ready endpoints only, so a
+/// backend that is down or failing is skipped while the others take the load.
+///
+/// Balancing uses tonic's built-in strategy (power of two random choices
+/// by pending requests). Connections are established lazily, so this only
+/// fails on an invalid URI or an empty endpoint list.
+///
+/// The returned [`Channel`] is used like any other: interceptors and
+/// compression are configured on the client wrapping it.
+///
+/// ## Example
+/// ```ignore
+/// use grpc_client::{create_balanced_channel, ChannelConfig};
+/// use rpc::tasks::tasks_service_client::TasksServiceClient;
+///
+/// let channel = create_balanced_channel(
+///     &["http://tasks-0:50051", "http://tasks-1:50051"],
+///     ChannelConfig::default(),
+/// )?;
+/// let client = TasksServiceClient::new(channel);
+/// ```
+pub fn create_balanced_channel(endpoints: &[&str], config: ChannelConfig) -> GrpcResult<Channel> {
+    if endpoints.is_empty() {
+        return Err(GrpcError::InvalidConfig(
+            "at least one endpoint is required".to_string(),
+        ));
+    }
+
+    let endpoints = endpoints
+        .iter()
+        .map(|addr| {
+            let endpoint = Endpoint::from_shared(addr.to_string()).map_err(|e| {
+                tracing::error!(target: "grpc_client", addr = %addr, error = ?e, "Invalid URI");
+                GrpcError::InvalidUri(e)
+            })?;
+            config.clone().apply_to_endpoint(endpoint)
+        })
+        .collect::<GrpcResult<Vec<_>>>()?;
+
+    tracing::debug!(
+        target: "grpc_client",
+        endpoints = endpoints.len(),
+        "Creating balanced gRPC channel"
+    );
+
+    Ok(Channel::balance_list(endpoints.into_iter()))
+}
+
 /// Creates a channel with retry logic
 ///
 /// This function will retry connection establishment with exponential backoff
@@ -135,6 +186,23 @@ mod tests {
         assert!(matches!(result.unwrap_err(), GrpcError::InvalidUri(_)));
     }
 
+    #[test]
+    fn test_balanced_channel_requires_endpoints() {
+        let result = create_balanced_channel(&[], ChannelConfig::default());
+        assert!(matches!(result.unwrap_err(), GrpcError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn test_balanced_channel_invalid_uri() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let _guard = runtime.enter();
+        let result = create_balanced_channel(
+            &["http://[::1]:50051", "not a valid uri"],
+            ChannelConfig::default(),
+        );
+        assert!(matches!(result.unwrap_err(), GrpcError::InvalidUri(_)));
+    }
+
     #[test]
     fn test_connection_failed() {
         let runtime = tokio::runtime::Runtime::new().unwrap();
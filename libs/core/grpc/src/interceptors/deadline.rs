@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tonic::{GrpcMethod, Request, Status};
+
+use super::propagation::request_timeout;
+
+/// Interceptor that sets a per-method deadline (`grpc-timeout`)
+///
+/// Looks up the called method's full path (`/package.Service/Method`) in a
+/// timeout map and falls back to a default for unlisted methods. A
+/// `grpc-timeout` already on the request is kept when it is shorter, so the
+/// call gets the tighter of the two.
+///
+/// Usually built from a [`ChannelConfig`](crate::ChannelConfig). The channel's
+/// request timeout still caps every call, so overrides can only shorten it;
+/// methods that need longer belong on a separate channel.
+///
+/// # Example
+/// ```ignore
+/// use grpc_client::{ChannelConfig, create_channel_with_config};
+/// use rpc::tasks::tasks_service_client::TasksServiceClient;
+/// use std::time::Duration;
+///
+/// let config = ChannelConfig::new()
+///     .with_request_timeout(Duration::from_secs(30))
+///     .with_method_timeout("/tasks.TasksService/GetById", Duration::from_secs(2));
+/// let deadline = config.deadline_interceptor();
+///
+/// let channel = create_channel_with_config("http://[::1]:50051", config).await?;
+/// let client = TasksServiceClient::with_interceptor(channel, deadline);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeadlineInterceptor {
+    default: Duration,
+    methods: Arc<HashMap<String, Duration>>,
+}
+
+impl DeadlineInterceptor {
+    /// Create an interceptor applying `default` to every method
+    pub fn new(default: Duration) -> Self {
+        Self {
+            default,
+            methods: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// Create an interceptor with per-method overrides
+    pub fn with_methods(default: Duration, methods: HashMap<String, Duration>) -> Self {
+        Self {
+            default,
+            methods: Arc::new(methods),
+        }
+    }
+
+    /// Timeout for a method path, falling back to the default
+    pub fn timeout_for(&self, path: &str) -> Duration {
+        self.methods.get(path).copied().unwrap_or(self.default)
+    }
+}
+
+impl tonic::service::Interceptor for DeadlineInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        // Generated clients attach the method being called as an extension
+        let timeout = match request.extensions().get::<GrpcMethod<'static>>() {
+            Some(method) => self.timeout_for(&format!("/{}/{}", method.service(), method.method())),
+            None => self.default,
+        };
+
+        if request_timeout(&request).is_some_and(|existing| existing <= timeout) {
+            return Ok(request);
+        }
+
+        request.set_timeout(timeout);
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::service::Interceptor;
+
+    fn interceptor() -> DeadlineInterceptor {
+        DeadlineInterceptor::with_methods(
+            Duration::from_secs(5),
+            HashMap::from([
+                (
+                    "/tasks.TasksService/ListStream".to_string(),
+                    Duration::from_secs(300),
+                ),
+                (
+                    "/tasks.TasksService/GetById".to_string(),
+                    Duration::from_millis(500),
+                ),
+            ]),
+        )
+    }
+
+    fn request_for(service: &'static str, method: &'static str) -> Request<()> {
+        let mut request = Request::new(());
+        request
+            .extensions_mut()
+            .insert(GrpcMethod::new(service, method));
+        request
+    }
+
+    #[test]
+    fn test_timeout_for_configured_methods() {
+        let deadline = interceptor();
+        assert_eq!(
+            deadline.timeout_for("/tasks.TasksService/ListStream"),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            deadline.timeout_for("/tasks.TasksService/GetById"),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn test_timeout_falls_back_to_default() {
+        let deadline = interceptor();
+        assert_eq!(
+            deadline.timeout_for("/tasks.TasksService/Create"),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn test_sets_timeout_from_method_extension() {
+        let mut fast = interceptor();
+        let fast_req = fast
+            .call(request_for("tasks.TasksService", "GetById"))
+            .unwrap();
+
+        let mut slow = interceptor();
+        let slow_req = slow
+            .call(request_for("tasks.TasksService", "ListStream"))
+            .unwrap();
+
+        let mut expected_fast = Request::new(());
+        expected_fast.set_timeout(Duration::from_millis(500));
+        let mut expected_slow = Request::new(());
+        expected_slow.set_timeout(Duration::from_secs(300));
+
+        assert_eq!(
+            fast_req.metadata().get("grpc-timeout"),
+            expected_fast.metadata().get("grpc-timeout")
+        );
+        assert_eq!(
+            slow_req.metadata().get("grpc-timeout"),
+            expected_slow.metadata().get("grpc-timeout")
+        );
+    }
+
+    #[test]
+    fn test_uses_default_without_method_extension() {
+        let mut deadline = interceptor();
+        let req = deadline.call(Request::new(())).unwrap();
+
+        let mut expected = Request::new(());
+        expected.set_timeout(Duration::from_secs(5));
+        assert_eq!(
+            req.metadata().get("grpc-timeout"),
+            expected.metadata().get("grpc-timeout")
+        );
+    }
+
+    #[test]
+    fn test_shortens_longer_explicit_timeout() {
+        let mut deadline = interceptor();
+        let mut request = request_for("tasks.TasksService", "GetById");
+        request.set_timeout(Duration::from_secs(10));
+
+        let req = deadline.call(request).unwrap();
+
+        let mut expected = Request::new(());
+        expected.set_timeout(Duration::from_millis(500));
+        assert_eq!(
+            req.metadata().get("grpc-timeout"),
+            expected.metadata().get("grpc-timeout")
+        );
+    }
+
+    #[test]
+    fn test_keeps_explicit_timeout() {
+        let mut deadline = interceptor();
+        let mut request = request_for("tasks.TasksService", "ListStream");
+        request.set_timeout(Duration::from_secs(1));
+
+        let req = deadline.call(request).unwrap();
+
+        let mut expected = Request::new(());
+        expected.set_timeout(Duration::from_secs(1));
+        assert_eq!(
+            req.metadata().get("grpc-timeout"),
+            expected.metadata().get("grpc-timeout")
+        );
+    }
+}
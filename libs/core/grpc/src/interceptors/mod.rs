@@ -3,10 +3,17 @@ pub use tonic::service::Interceptor;
 
 pub mod auth;
 pub mod compose;
+pub mod deadline;
 pub mod metrics;
+pub mod propagation;
 pub mod tracing;
 
 pub use auth::AuthInterceptor;
 pub use compose::{ComposedInterceptor, compose_interceptors};
+pub use deadline::DeadlineInterceptor;
 pub use metrics::MetricsInterceptor;
+pub use propagation::{
+    DeadlinePropagationInterceptor, current_deadline, format_grpc_timeout, parse_grpc_timeout,
+    scope_deadline,
+};
 pub use tracing::TracingInterceptor;
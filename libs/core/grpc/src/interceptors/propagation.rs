@@ -0,0 +1,330 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tonic::metadata::MetadataValue;
+use tonic::{Request, Status};
+
+tokio::task_local! {
+    static CURRENT_DEADLINE: Instant;
+}
+
+/// Run `future` with a deadline that outgoing calls propagate
+///
+/// Any [`DeadlinePropagationInterceptor`] invoked inside the future sends the
+/// remaining budget as `grpc-timeout`. Nested scopes keep the tighter deadline.
+///
+/// # Example
+/// ```ignore
+/// use grpc_client::interceptors::{scope_deadline, DeadlinePropagationInterceptor};
+/// use std::time::{Duration, Instant};
+///
+/// let mut client = TasksServiceClient::with_interceptor(channel, DeadlinePropagationInterceptor::new());
+///
+/// // Downstream sees the time left out of the caller's 2s budget
+/// scope_deadline(Instant::now() + Duration::from_secs(2), async {
+///     client.get_by_id(request).await
+/// })
+/// .await?;
+/// ```
+pub async fn scope_deadline<F: Future>(deadline: Instant, future: F) -> F::Output {
+    let deadline = current_deadline().map_or(deadline, |current| current.min(deadline));
+    CURRENT_DEADLINE.scope(deadline, future).await
+}
+
+/// Deadline set by the enclosing [`scope_deadline`], if any
+pub fn current_deadline() -> Option<Instant> {
+    CURRENT_DEADLINE.try_with(|deadline| *deadline).ok()
+}
+
+/// Format a timeout as a `grpc-timeout` header value
+///
+/// Uses milliseconds (`100m`) where possible, falling back to a unit that
+/// keeps the value within the 8 digits allowed by the gRPC spec. Values are
+/// truncated, never rounded up.
+pub fn format_grpc_timeout(timeout: Duration) -> String {
+    const MAX_VALUE: u128 = 99_999_999;
+
+    if timeout < Duration::from_millis(1) {
+        return match timeout.as_micros() {
+            0 => format!("{}n", timeout.as_nanos()),
+            micros => format!("{}u", micros),
+        };
+    }
+
+    let millis = timeout.as_millis();
+    if millis <= MAX_VALUE {
+        return format!("{}m", millis);
+    }
+
+    let secs = u128::from(timeout.as_secs());
+    if secs <= MAX_VALUE {
+        return format!("{}S", secs);
+    }
+    if secs / 60 <= MAX_VALUE {
+        return format!("{}M", secs / 60);
+    }
+    format!("{}H", (secs / 3600).min(MAX_VALUE))
+}
+
+/// Parse a `grpc-timeout` header value such as `100m` or `5S`
+///
+/// Returns `None` for values that don't follow the spec: 1-8 digits
+/// followed by one of the units `H`, `M`, `S`, `m`, `u` or `n`.
+pub fn parse_grpc_timeout(value: &str) -> Option<Duration> {
+    let (digits, unit) = value.split_at(value.len().checked_sub(1)?);
+    if digits.is_empty() || digits.len() > 8 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let amount: u64 = digits.parse().ok()?;
+
+    match unit {
+        "H" => Some(Duration::from_secs(amount * 3600)),
+        "M" => Some(Duration::from_secs(amount * 60)),
+        "S" => Some(Duration::from_secs(amount)),
+        "m" => Some(Duration::from_millis(amount)),
+        "u" => Some(Duration::from_micros(amount)),
+        "n" => Some(Duration::from_nanos(amount)),
+        _ => None,
+    }
+}
+
+/// Timeout already set on a request, if its `grpc-timeout` header is valid
+pub(crate) fn request_timeout<T>(request: &Request<T>) -> Option<Duration> {
+    request
+        .metadata()
+        .get("grpc-timeout")
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_grpc_timeout)
+}
+
+/// Interceptor that propagates the caller's remaining deadline
+///
+/// Writes a `grpc-timeout` header so downstream services learn how much of
+/// the budget is left. The budget is the tightest of:
+/// - an explicit deadline ([`with_deadline`](Self::with_deadline))
+/// - an explicit timeout ([`with_timeout`](Self::with_timeout))
+/// - the deadline of the enclosing [`scope_deadline`]
+///
+/// Without any of these it is a no-op. A `grpc-timeout` already on the
+/// request is only replaced when the remaining budget is shorter, so it
+/// composes with a [`DeadlineInterceptor`](super::DeadlineInterceptor) in
+/// either order. A deadline that has already passed fails the call with
+/// `DEADLINE_EXCEEDED` without sending it.
+///
+/// # Example
+/// ```ignore
+/// use grpc_client::interceptors::{
+///     compose_interceptors, AuthInterceptor, DeadlinePropagationInterceptor,
+/// };
+///
+/// let interceptor = compose_interceptors(
+///     DeadlinePropagationInterceptor::new(),
+///     AuthInterceptor::bearer("token"),
+/// );
+/// let client = TasksServiceClient::with_interceptor(channel, interceptor);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DeadlinePropagationInterceptor {
+    deadline: Option<Instant>,
+    timeout: Option<Duration>,
+}
+
+impl DeadlinePropagationInterceptor {
+    /// Create an interceptor propagating the scoped deadline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Propagate an explicit deadline
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Propagate a fixed timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Remaining budget, or `None` when no deadline is set
+    fn remaining(&self) -> Option<Duration> {
+        let now = Instant::now();
+        [self.deadline, current_deadline()]
+            .into_iter()
+            .flatten()
+            .map(|deadline| deadline.saturating_duration_since(now))
+            .chain(self.timeout)
+            .min()
+    }
+}
+
+impl tonic::service::Interceptor for DeadlinePropagationInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(remaining) = self.remaining() else {
+            return Ok(request);
+        };
+
+        if remaining.is_zero() {
+            return Err(Status::deadline_exceeded(
+                "Deadline exceeded before request was sent",
+            ));
+        }
+
+        if request_timeout(&request).is_some_and(|existing| existing <= remaining) {
+            return Ok(request);
+        }
+
+        let value = MetadataValue::try_from(format_grpc_timeout(remaining))
+            .map_err(|_| Status::internal("Failed to create grpc-timeout header"))?;
+        request.metadata_mut().insert("grpc-timeout", value);
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::service::Interceptor;
+
+    fn timeout_header(request: &Request<()>) -> Option<&str> {
+        request
+            .metadata()
+            .get("grpc-timeout")
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[test]
+    fn test_format_grpc_timeout() {
+        assert_eq!(format_grpc_timeout(Duration::from_millis(100)), "100m");
+        assert_eq!(format_grpc_timeout(Duration::from_secs(30)), "30000m");
+        assert_eq!(format_grpc_timeout(Duration::from_micros(250)), "250u");
+        assert_eq!(format_grpc_timeout(Duration::from_nanos(500)), "500n");
+        assert_eq!(format_grpc_timeout(Duration::from_secs(200_000)), "200000S");
+    }
+
+    #[test]
+    fn test_explicit_timeout_sets_header() {
+        let mut interceptor =
+            DeadlinePropagationInterceptor::new().with_timeout(Duration::from_millis(100));
+        let request = interceptor.call(Request::new(())).unwrap();
+        assert_eq!(timeout_header(&request), Some("100m"));
+    }
+
+    #[test]
+    fn test_explicit_deadline_sets_remaining_budget() {
+        let mut interceptor = DeadlinePropagationInterceptor::new()
+            .with_deadline(Instant::now() + Duration::from_secs(1));
+        let request = interceptor.call(Request::new(())).unwrap();
+
+        let header = timeout_header(&request).unwrap();
+        let millis: u64 = header.strip_suffix('m').unwrap().parse().unwrap();
+        assert!(millis > 900 && millis <= 1000, "unexpected budget {header}");
+    }
+
+    #[test]
+    fn test_no_deadline_is_noop() {
+        let mut interceptor = DeadlinePropagationInterceptor::new();
+        let request = interceptor.call(Request::new(())).unwrap();
+        assert_eq!(timeout_header(&request), None);
+    }
+
+    #[test]
+    fn test_expired_deadline_fails() {
+        let mut interceptor = DeadlinePropagationInterceptor::new().with_deadline(Instant::now());
+        let status = interceptor.call(Request::new(())).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+    }
+
+    #[test]
+    fn test_parse_grpc_timeout() {
+        assert_eq!(parse_grpc_timeout("100m"), Some(Duration::from_millis(100)));
+        assert_eq!(parse_grpc_timeout("5S"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_grpc_timeout("2M"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_grpc_timeout("1H"), Some(Duration::from_secs(3600)));
+        assert_eq!(parse_grpc_timeout("250u"), Some(Duration::from_micros(250)));
+        assert_eq!(parse_grpc_timeout("500n"), Some(Duration::from_nanos(500)));
+        assert_eq!(parse_grpc_timeout(""), None);
+        assert_eq!(parse_grpc_timeout("m"), None);
+        assert_eq!(parse_grpc_timeout("10"), None);
+        assert_eq!(parse_grpc_timeout("-1S"), None);
+        assert_eq!(parse_grpc_timeout("123456789m"), None);
+    }
+
+    #[test]
+    fn test_shortens_longer_existing_header() {
+        let mut interceptor =
+            DeadlinePropagationInterceptor::new().with_timeout(Duration::from_millis(100));
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("grpc-timeout", "5S".parse().unwrap());
+
+        let request = interceptor.call(request).unwrap();
+        assert_eq!(timeout_header(&request), Some("100m"));
+    }
+
+    #[test]
+    fn test_keeps_shorter_existing_header() {
+        let mut interceptor =
+            DeadlinePropagationInterceptor::new().with_timeout(Duration::from_secs(5));
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("grpc-timeout", "100m".parse().unwrap());
+
+        let request = interceptor.call(request).unwrap();
+        assert_eq!(timeout_header(&request), Some("100m"));
+    }
+
+    #[test]
+    fn test_expired_deadline_fails_despite_existing_header() {
+        let mut interceptor = DeadlinePropagationInterceptor::new().with_deadline(Instant::now());
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("grpc-timeout", "5S".parse().unwrap());
+
+        let status = interceptor.call(request).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_scoped_deadline_is_propagated() {
+        let deadline = Instant::now() + Duration::from_millis(500);
+        let request = scope_deadline(deadline, async {
+            DeadlinePropagationInterceptor::new()
+                .call(Request::new(()))
+                .unwrap()
+        })
+        .await;
+
+        let header = timeout_header(&request).unwrap();
+        let millis: u64 = header.strip_suffix('m').unwrap().parse().unwrap();
+        assert!(millis > 400 && millis <= 500, "unexpected budget {header}");
+    }
+
+    #[tokio::test]
+    async fn test_nested_scope_keeps_tighter_deadline() {
+        let outer = Instant::now() + Duration::from_millis(200);
+        let inner = outer + Duration::from_secs(10);
+
+        let effective =
+            scope_deadline(outer, scope_deadline(inner, async { current_deadline() })).await;
+        assert_eq!(effective, Some(outer));
+    }
+
+    #[test]
+    fn test_composes_with_other_interceptors() {
+        use crate::interceptors::{AuthInterceptor, TracingInterceptor, compose_interceptors};
+
+        let mut composed = compose_interceptors(
+            DeadlinePropagationInterceptor::new().with_timeout(Duration::from_millis(100)),
+            compose_interceptors(AuthInterceptor::bearer("token"), TracingInterceptor::new()),
+        );
+        let request = composed.call(Request::new(())).unwrap();
+
+        assert_eq!(timeout_header(&request), Some("100m"));
+        assert!(request.metadata().get("authorization").is_some());
+        assert!(request.metadata().get("x-request-id").is_some());
+    }
+}
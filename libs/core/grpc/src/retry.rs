@@ -1,7 +1,52 @@
 use std::future::Future;
 use std::time::Duration;
+use tonic::Code;
 use tracing::{debug, warn};
 
+use crate::error::GrpcError;
+
+/// Status codes retried by default: the call was rejected before any work
+const DEFAULT_RETRY_CODES: [Code; 3] = [Code::Unavailable, Code::ResourceExhausted, Code::Aborted];
+
+/// Errors that may carry a gRPC status code
+///
+/// The retry loop uses the code to decide whether another attempt is safe.
+/// Errors without a code (`None`) are retried only when
+/// [`RetryConfig::retry_uncoded`] is set.
+pub trait GrpcCode {
+    /// The gRPC status code of this error, if any
+    fn grpc_code(&self) -> Option<Code>;
+}
+
+impl GrpcCode for tonic::Status {
+    fn grpc_code(&self) -> Option<Code> {
+        Some(self.code())
+    }
+}
+
+impl GrpcCode for GrpcError {
+    fn grpc_code(&self) -> Option<Code> {
+        match self {
+            GrpcError::InvalidUri(_) | GrpcError::InvalidConfig(_) => Some(Code::InvalidArgument),
+            GrpcError::ConnectionFailed(_)
+            | GrpcError::ConnectionTimeout(_)
+            | GrpcError::MaxRetriesExceeded(_) => Some(Code::Unavailable),
+        }
+    }
+}
+
+impl GrpcCode for String {
+    fn grpc_code(&self) -> Option<Code> {
+        None
+    }
+}
+
+impl GrpcCode for &str {
+    fn grpc_code(&self) -> Option<Code> {
+        None
+    }
+}
+
 /// Retry configuration for gRPC channel connections
 ///
 /// Mirrors the retry configuration from the database library for consistency.
@@ -21,6 +66,12 @@ pub struct RetryConfig {
 
     /// Whether to add jitter to prevent thundering herd
     pub use_jitter: bool,
+
+    /// Status codes that may be retried; any other code fails immediately
+    pub retry_codes: Vec<Code>,
+
+    /// Whether errors without a status code may be retried
+    pub retry_uncoded: bool,
 }
 
 impl RetryConfig {
@@ -32,10 +83,54 @@ impl RetryConfig {
     /// - max_delay_ms: 5000
     /// - backoff_multiplier: 2.0
     /// - use_jitter: true
+    /// - retry_codes: Unavailable, ResourceExhausted, Aborted
+    /// - retry_uncoded: true
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Preset for idempotent calls (reads, upserts)
+    ///
+    /// Also retries `DeadlineExceeded`, where the server may have done the
+    /// work but repeating it is harmless.
+    pub fn idempotent() -> Self {
+        Self::default().retry_on([
+            Code::Unavailable,
+            Code::ResourceExhausted,
+            Code::Aborted,
+            Code::DeadlineExceeded,
+        ])
+    }
+
+    /// Preset for non-idempotent calls (creates, payments)
+    ///
+    /// Only retries `Unavailable`, which signals the request was not processed.
+    /// Errors without a status code give no such guarantee and fail immediately.
+    pub fn non_idempotent() -> Self {
+        let mut config = Self::default().retry_on([Code::Unavailable]);
+        config.retry_uncoded = false;
+        config
+    }
+
+    /// Set the status codes that may be retried
+    ///
+    /// # Example
+    /// ```ignore
+    /// let config = RetryConfig::new().retry_on([Code::Unavailable, Code::Internal]);
+    /// ```
+    pub fn retry_on(mut self, codes: impl IntoIterator<Item = Code>) -> Self {
+        self.retry_codes = codes.into_iter().collect();
+        self
+    }
+
+    /// Whether an error may be retried under this configuration
+    pub fn is_retryable<E: GrpcCode>(&self, error: &E) -> bool {
+        match error.grpc_code() {
+            Some(code) => self.retry_codes.contains(&code),
+            None => self.retry_uncoded,
+        }
+    }
+
     /// Create a retry config with custom max retries
     ///
     /// # Example
@@ -89,6 +184,8 @@ impl Default for RetryConfig {
             max_delay_ms: 5000,
             backoff_multiplier: 2.0,
             use_jitter: true,
+            retry_codes: DEFAULT_RETRY_CODES.to_vec(),
+            retry_uncoded: true,
         }
     }
 }
@@ -99,6 +196,10 @@ impl Default for RetryConfig {
 /// with exponentially increasing delays between attempts. Useful for
 /// establishing connections to services that may not be immediately available.
 ///
+/// Errors with a status code outside `config.retry_codes` are returned
+/// immediately, so non-idempotent calls are not repeated after the server
+/// may have acted on them.
+///
 /// # Arguments
 /// * `operation` - The async operation to retry
 /// * `config` - Retry configuration
@@ -118,7 +219,7 @@ pub async fn retry_with_backoff<F, Fut, T, E>(mut operation: F, config: RetryCon
 where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T, E>>,
-    E: std::fmt::Display,
+    E: std::fmt::Display + GrpcCode,
 {
     let mut attempt = 0;
     let mut delay = config.initial_delay_ms;
@@ -136,6 +237,16 @@ where
                 return Ok(result);
             }
             Err(e) => {
+                if !config.is_retryable(&e) {
+                    debug!(
+                        target: "grpc_client",
+                        code = ?e.grpc_code(),
+                        error = %e,
+                        "gRPC operation failed with non-retryable status"
+                    );
+                    return Err(e);
+                }
+
                 attempt += 1;
 
                 if attempt > config.max_retries {
@@ -205,7 +316,7 @@ pub async fn retry<F, Fut, T, E>(operation: F) -> Result<T, E>
 where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T, E>>,
-    E: std::fmt::Display,
+    E: std::fmt::Display + GrpcCode,
 {
     retry_with_backoff(operation, RetryConfig::default()).await
 }
@@ -304,6 +415,97 @@ mod tests {
         assert!(!config.use_jitter);
     }
 
+    #[tokio::test]
+    async fn test_retry_stops_on_non_retryable_code() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+
+        let config = RetryConfig::new()
+            .with_max_retries(3)
+            .with_initial_delay(10)
+            .without_jitter();
+
+        let result = retry_with_backoff(
+            || {
+                let counter = counter_clone.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Err::<(), _>(tonic::Status::not_found("task not found"))
+                }
+            },
+            config,
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err().code(), Code::NotFound);
+        assert_eq!(counter.load(Ordering::SeqCst), 1); // No retries
+    }
+
+    #[tokio::test]
+    async fn test_retry_retries_unavailable_up_to_max() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+
+        let config = RetryConfig::new()
+            .with_max_retries(3)
+            .with_initial_delay(10)
+            .without_jitter();
+
+        let result = retry_with_backoff(
+            || {
+                let counter = counter_clone.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Err::<(), _>(tonic::Status::unavailable("backend down"))
+                }
+            },
+            config,
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(counter.load(Ordering::SeqCst), 4); // 1 initial + 3 retries
+    }
+
+    #[test]
+    fn test_retry_code_presets() {
+        let default = RetryConfig::new();
+        assert!(default.is_retryable(&tonic::Status::unavailable("")));
+        assert!(default.is_retryable(&tonic::Status::resource_exhausted("")));
+        assert!(default.is_retryable(&tonic::Status::aborted("")));
+        assert!(!default.is_retryable(&tonic::Status::not_found("")));
+        assert!(!default.is_retryable(&tonic::Status::deadline_exceeded("")));
+
+        let idempotent = RetryConfig::idempotent();
+        assert!(idempotent.is_retryable(&tonic::Status::deadline_exceeded("")));
+
+        let non_idempotent = RetryConfig::non_idempotent();
+        assert!(non_idempotent.is_retryable(&tonic::Status::unavailable("")));
+        assert!(!non_idempotent.is_retryable(&tonic::Status::aborted("")));
+
+        let custom = RetryConfig::new().retry_on([Code::Internal]);
+        assert!(custom.is_retryable(&tonic::Status::internal("")));
+        assert!(!custom.is_retryable(&tonic::Status::unavailable("")));
+    }
+
+    #[test]
+    fn test_errors_without_code() {
+        let config = RetryConfig::new();
+        assert!(config.is_retryable(&"plain error"));
+        assert!(config.is_retryable(&"plain error".to_string()));
+
+        let non_idempotent = RetryConfig::non_idempotent();
+        assert!(!non_idempotent.is_retryable(&"plain error"));
+        assert!(!non_idempotent.is_retryable(&"plain error".to_string()));
+    }
+
+    #[test]
+    fn test_grpc_error_codes() {
+        let config = RetryConfig::new();
+        assert!(config.is_retryable(&GrpcError::ConnectionTimeout(Duration::from_secs(1))));
+        assert!(!config.is_retryable(&GrpcError::InvalidConfig("bad".to_string())));
+    }
+
     #[test]
     fn test_apply_jitter() {
         let delay = 1000;
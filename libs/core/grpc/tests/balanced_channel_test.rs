@@ -0,0 +1,66 @@
+//! Integration tests for load-balanced channels
+//!
+//! Runs a real tonic health server on localhost next to an endpoint with
+//! nothing listening, and checks requests still succeed.
+
+#![cfg(feature = "server")]
+
+use grpc_client::{ChannelConfig, create_balanced_channel};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use tonic_health::pb::HealthCheckRequest;
+use tonic_health::pb::health_client::HealthClient;
+
+/// Start a health server on a random port and return its address
+async fn start_health_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (_reporter, health_service) = tonic_health::server::health_reporter();
+
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(health_service)
+            .serve_with_incoming(TcpIncoming::from(listener))
+            .await
+            .unwrap();
+    });
+
+    format!("http://{}", addr)
+}
+
+/// Address of a port that refuses connections
+async fn unused_addr() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_balanced_channel_skips_down_endpoint() {
+    let live = start_health_server().await;
+    let down = unused_addr().await;
+
+    let config = ChannelConfig::new().with_connect_timeout(Duration::from_millis(500));
+    let channel = create_balanced_channel(&[down.as_str(), live.as_str()], config).unwrap();
+    let mut client = HealthClient::new(channel);
+
+    for _ in 0..10 {
+        let response = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.check(HealthCheckRequest {
+                service: String::new(),
+            }),
+        )
+        .await
+        .expect("request should not hang on the down endpoint")
+        .expect("request should be served by the live endpoint");
+
+        assert_eq!(
+            response.into_inner().status,
+            tonic_health::pb::health_check_response::ServingStatus::Serving as i32
+        );
+    }
+}
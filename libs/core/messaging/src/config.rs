@@ -1,5 +1,6 @@
 //! Configuration types for message queues.
 
+use crate::error::{ErrorCategory, ProcessingError};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -53,6 +54,10 @@ pub struct QueueConfig {
 
     /// Rate limit (requests per second)
     pub rate_limit_rps: f64,
+
+    /// Route jobs by `Job::priority()` and consume higher priorities first
+    #[serde(default)]
+    pub priority_enabled: bool,
 }
 
 // Custom serde for Duration as milliseconds
@@ -113,6 +118,7 @@ impl Default for QueueConfig {
             enable_circuit_breaker: true,
             enable_rate_limiter: false,
             rate_limit_rps: 100.0,
+            priority_enabled: false,
         }
     }
 }
@@ -198,6 +204,12 @@ impl QueueConfig {
         self.retry_policy = policy;
         self
     }
+
+    /// Enable priority-ordered delivery.
+    pub fn with_priority(mut self, enabled: bool) -> Self {
+        self.priority_enabled = enabled;
+        self
+    }
 }
 
 /// Queue definition trait (for type-safe constants).
@@ -235,8 +247,16 @@ pub struct RetryPolicy {
     /// Maximum retries for rate limited errors
     pub max_rate_limit_retries: u32,
 
-    /// Backoff strategy
+    /// Backoff strategy for transient errors
     pub backoff: BackoffStrategy,
+
+    /// Backoff strategy for rate limited errors
+    #[serde(default = "default_rate_limit_backoff")]
+    pub rate_limit_backoff: BackoffStrategy,
+}
+
+fn default_rate_limit_backoff() -> BackoffStrategy {
+    BackoffStrategy::Fixed { delay_ms: 60_000 }
 }
 
 impl Default for RetryPolicy {
@@ -248,6 +268,76 @@ impl Default for RetryPolicy {
                 base_ms: 1000,
                 max_ms: 30000,
             },
+            rate_limit_backoff: default_rate_limit_backoff(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Check the policy for values that would make retries degenerate.
+    ///
+    /// Rejects zero retry limits and invalid backoff strategies (see
+    /// [`BackoffStrategy::validate`]).
+    pub fn validate(&self) -> Result<(), ProcessingError> {
+        if self.max_transient_retries == 0 {
+            return Err(ProcessingError::Config(
+                "max_transient_retries must be greater than 0".to_string(),
+            ));
+        }
+        if self.max_rate_limit_retries == 0 {
+            return Err(ProcessingError::Config(
+                "max_rate_limit_retries must be greater than 0".to_string(),
+            ));
+        }
+
+        self.backoff
+            .validate()
+            .map_err(|e| ProcessingError::Config(format!("backoff: {}", e)))?;
+        self.rate_limit_backoff
+            .validate()
+            .map_err(|e| ProcessingError::Config(format!("rate_limit_backoff: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Maximum retries for an error category (always 0 for permanent errors).
+    pub fn max_retries(&self, category: ErrorCategory) -> u32 {
+        match category {
+            ErrorCategory::Transient => self.max_transient_retries,
+            ErrorCategory::RateLimited => self.max_rate_limit_retries,
+            ErrorCategory::Permanent => 0,
+        }
+    }
+
+    /// Delay before redelivering a job that failed with `category`.
+    ///
+    /// Returns `None` when the job should not be redelivered: permanent
+    /// errors, or retries exhausted for the category.
+    pub fn delay(&self, category: ErrorCategory, retry_count: u32) -> Option<Duration> {
+        if retry_count >= self.max_retries(category) {
+            return None;
+        }
+
+        match category {
+            ErrorCategory::Transient => Some(self.backoff.delay(retry_count)),
+            ErrorCategory::RateLimited => Some(self.rate_limit_backoff.delay(retry_count)),
+            ErrorCategory::Permanent => None,
+        }
+    }
+
+    /// Delay before redelivering a job that failed with `error`.
+    ///
+    /// Like [`delay`](Self::delay), but a rate limited error's retry-after
+    /// hint takes precedence over the configured backoff.
+    pub fn redelivery_delay(&self, error: &ProcessingError, retry_count: u32) -> Option<Duration> {
+        let delay = self.delay(error.category(), retry_count)?;
+
+        match error {
+            ProcessingError::RateLimited {
+                retry_after_ms: Some(ms),
+                ..
+            } => Some(Duration::from_millis(*ms)),
+            _ => Some(delay),
         }
     }
 }
@@ -267,6 +357,32 @@ pub enum BackoffStrategy {
 }
 
 impl BackoffStrategy {
+    /// Check that the strategy produces a usable, non-zero delay.
+    ///
+    /// Rejects zero delays and a `max_ms` below `base_ms`.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            BackoffStrategy::Fixed { delay_ms } => {
+                if *delay_ms == 0 {
+                    return Err("delay_ms must be greater than 0".to_string());
+                }
+            }
+            BackoffStrategy::Exponential { base_ms, max_ms }
+            | BackoffStrategy::Linear { base_ms, max_ms } => {
+                if *base_ms == 0 {
+                    return Err("base_ms must be greater than 0".to_string());
+                }
+                if max_ms < base_ms {
+                    return Err(format!(
+                        "max_ms ({}) must not be less than base_ms ({})",
+                        max_ms, base_ms
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Calculate the delay for a given retry count.
     pub fn delay(&self, retry_count: u32) -> Duration {
         match self {
@@ -331,6 +447,183 @@ mod tests {
         assert_eq!(backoff.delay(20), Duration::from_secs(60)); // Capped
     }
 
+    #[test]
+    fn test_retry_policy_delay_per_category() {
+        let policy = RetryPolicy::default();
+
+        // Transient: exponential 1s, 2s, 4s, then exhausted
+        assert_eq!(
+            policy.delay(ErrorCategory::Transient, 0),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(ErrorCategory::Transient, 1),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.delay(ErrorCategory::Transient, 2),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(policy.delay(ErrorCategory::Transient, 3), None);
+
+        // RateLimited: fixed 60s for every attempt, then exhausted
+        assert_eq!(
+            policy.delay(ErrorCategory::RateLimited, 0),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            policy.delay(ErrorCategory::RateLimited, 4),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(policy.delay(ErrorCategory::RateLimited, 5), None);
+
+        // Permanent: never redelivered
+        assert_eq!(policy.delay(ErrorCategory::Permanent, 0), None);
+    }
+
+    #[test]
+    fn test_retry_policy_custom_backoff() {
+        let policy = RetryPolicy {
+            max_transient_retries: 10,
+            max_rate_limit_retries: 2,
+            backoff: BackoffStrategy::Exponential {
+                base_ms: 500,
+                max_ms: 4000,
+            },
+            rate_limit_backoff: BackoffStrategy::Fixed { delay_ms: 90_000 },
+        };
+
+        assert_eq!(
+            policy.delay(ErrorCategory::Transient, 2),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.delay(ErrorCategory::Transient, 6),
+            Some(Duration::from_secs(4))
+        ); // Capped
+        assert_eq!(
+            policy.delay(ErrorCategory::RateLimited, 1),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(policy.delay(ErrorCategory::RateLimited, 2), None);
+    }
+
+    #[test]
+    fn test_retry_policy_redelivery_delay() {
+        let policy = RetryPolicy::default();
+
+        let transient = ProcessingError::transient("timeout");
+        assert_eq!(
+            policy.redelivery_delay(&transient, 1),
+            Some(Duration::from_secs(2))
+        );
+
+        let rate_limited = ProcessingError::rate_limited("slow down");
+        assert_eq!(
+            policy.redelivery_delay(&rate_limited, 0),
+            Some(Duration::from_secs(60))
+        );
+
+        // Retry-after hint wins while retries remain
+        let hinted = ProcessingError::rate_limited_with_retry("slow down", 15_000);
+        assert_eq!(
+            policy.redelivery_delay(&hinted, 0),
+            Some(Duration::from_secs(15))
+        );
+        assert_eq!(policy.redelivery_delay(&hinted, 5), None);
+
+        let permanent = ProcessingError::permanent("invalid");
+        assert_eq!(policy.redelivery_delay(&permanent, 0), None);
+    }
+
+    #[test]
+    fn test_retry_policy_validate_baseline() {
+        assert!(RetryPolicy::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_retry_policy_validate_rejects_invalid() {
+        let invalid = [
+            RetryPolicy {
+                max_transient_retries: 0,
+                ..Default::default()
+            },
+            RetryPolicy {
+                max_rate_limit_retries: 0,
+                ..Default::default()
+            },
+            RetryPolicy {
+                backoff: BackoffStrategy::Fixed { delay_ms: 0 },
+                ..Default::default()
+            },
+            RetryPolicy {
+                backoff: BackoffStrategy::Exponential {
+                    base_ms: 0,
+                    max_ms: 30_000,
+                },
+                ..Default::default()
+            },
+            RetryPolicy {
+                backoff: BackoffStrategy::Exponential {
+                    base_ms: 5000,
+                    max_ms: 1000,
+                },
+                ..Default::default()
+            },
+            RetryPolicy {
+                backoff: BackoffStrategy::Linear {
+                    base_ms: 0,
+                    max_ms: 1000,
+                },
+                ..Default::default()
+            },
+            RetryPolicy {
+                backoff: BackoffStrategy::Linear {
+                    base_ms: 2000,
+                    max_ms: 1000,
+                },
+                ..Default::default()
+            },
+            RetryPolicy {
+                rate_limit_backoff: BackoffStrategy::Fixed { delay_ms: 0 },
+                ..Default::default()
+            },
+        ];
+
+        for policy in invalid {
+            let result = policy.validate();
+            assert!(
+                matches!(result, Err(ProcessingError::Config(_))),
+                "expected {:?} to be rejected",
+                policy
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_deserializes_without_rate_limit_backoff() {
+        let json = r#"{
+            "max_transient_retries": 3,
+            "max_rate_limit_retries": 5,
+            "backoff": { "type": "fixed", "delay_ms": 1000 }
+        }"#;
+        let policy: RetryPolicy = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            policy.delay(ErrorCategory::RateLimited, 0),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn test_config_priority_flag() {
+        assert!(!QueueConfig::default().priority_enabled);
+        assert!(
+            QueueConfig::new("jobs")
+                .with_priority(true)
+                .priority_enabled
+        );
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = QueueConfig::default();
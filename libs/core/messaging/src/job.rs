@@ -111,6 +111,24 @@ pub enum JobPriority {
 }
 
 impl JobPriority {
+    /// All priorities, most important first.
+    pub const DESCENDING: [JobPriority; 4] = [
+        JobPriority::Critical,
+        JobPriority::High,
+        JobPriority::Normal,
+        JobPriority::Low,
+    ];
+
+    /// Lowercase name, used e.g. as a subject token by backends.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobPriority::Low => "low",
+            JobPriority::Normal => "normal",
+            JobPriority::High => "high",
+            JobPriority::Critical => "critical",
+        }
+    }
+
     /// Get the numeric priority value (higher = more important).
     pub fn value(&self) -> u8 {
         match self {
@@ -179,6 +197,18 @@ mod tests {
         assert!(JobPriority::High < JobPriority::Critical);
     }
 
+    #[test]
+    fn test_job_priority_default_and_descending() {
+        let job = TestJob {
+            id: "job-1".to_string(),
+            retry_count: 0,
+        };
+        assert_eq!(job.priority(), JobPriority::Normal);
+
+        let values: Vec<u8> = JobPriority::DESCENDING.iter().map(|p| p.value()).collect();
+        assert_eq!(values, vec![3, 2, 1, 0]);
+    }
+
     #[test]
     fn test_job_priority_serialization() {
         let priority = JobPriority::High;
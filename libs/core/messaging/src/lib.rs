@@ -8,6 +8,7 @@
 //! # Features
 //!
 //! - `nats` - Enable NATS JetStream backend support
+//! - `outbox` - Enable the transactional outbox (Postgres via SeaORM)
 //!
 //! # Architecture
 //!
@@ -76,6 +77,10 @@ pub use event::{JobEvent, ProcessResult};
 pub use job::{Job, JobPriority};
 pub use processor::{FailingProcessor, NoOpProcessor, Processor};
 
+// Outbox module (feature-gated)
+#[cfg(feature = "outbox")]
+pub mod outbox;
+
 // NATS module (feature-gated)
 #[cfg(feature = "nats")]
 pub mod nats;
@@ -84,5 +89,5 @@ pub mod nats;
 #[cfg(feature = "nats")]
 pub use nats::{
     DlqManager, HealthServer, NatsConsumer, NatsError, NatsMetrics, NatsProducer, NatsWorker,
-    StreamConfig, WorkerConfig,
+    StreamConfig, WorkerConfig, WorkerStats,
 };
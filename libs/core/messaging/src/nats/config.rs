@@ -1,5 +1,7 @@
 //! Configuration for NATS JetStream workers.
 
+use crate::nats::error::NatsError;
+use crate::{JobPriority, QueueConfig, RetryPolicy};
 use std::time::Duration;
 
 /// Stream configuration trait (type-safe constants).
@@ -31,8 +33,12 @@ pub trait StreamConfig {
     /// Subject pattern (e.g., "emails.>")
     const SUBJECT: &'static str = ">";
 
-    /// Maximum deliveries before moving to DLQ (default: 3)
-    const MAX_DELIVER: i64 = 3;
+    /// Maximum deliveries before JetStream gives up on a message (default: 6)
+    ///
+    /// Must exceed the retry policy's largest retry limit so the final
+    /// attempt can still move the job to the DLQ; the default allows for
+    /// [`RetryPolicy::default`]'s 5 rate-limited retries.
+    const MAX_DELIVER: i64 = 6;
 
     /// Ack wait timeout in seconds (default: 30)
     const ACK_WAIT_SECS: u64 = 30;
@@ -68,6 +74,13 @@ pub struct WorkerConfig {
     /// Maximum deliveries before DLQ
     pub max_deliver: i64,
 
+    /// Retry limits and nak backoff per error category
+    ///
+    /// `max_deliver` must exceed the largest retry limit, otherwise JetStream
+    /// stops redelivering before the job reaches the DLQ; `validate` rejects
+    /// such configs.
+    pub retry_policy: RetryPolicy,
+
     /// Ack wait timeout
     pub ack_wait: Duration,
 
@@ -82,10 +95,20 @@ pub struct WorkerConfig {
 
     /// Health server port
     pub health_port: u16,
+
+    /// Publish/consume on per-priority subjects, higher priorities first
+    pub priority_enabled: bool,
+
+    /// JetStream KV bucket used to skip already-processed jobs (disabled if `None`)
+    pub idempotency_bucket: Option<String>,
+
+    /// How long processed job ids are remembered in the idempotency bucket
+    pub idempotency_ttl: Duration,
 }
 
 impl Default for WorkerConfig {
     fn default() -> Self {
+        let retry_policy = RetryPolicy::default();
         Self {
             stream_name: "JOBS".to_string(),
             consumer_name: "worker".to_string(),
@@ -94,12 +117,16 @@ impl Default for WorkerConfig {
             dlq_stream: "JOBS_DLQ".to_string(),
             batch_size: 10,
             fetch_timeout: Duration::from_secs(5),
-            max_deliver: 3,
+            max_deliver: required_max_deliver(&retry_policy),
+            retry_policy,
             ack_wait: Duration::from_secs(30),
             max_concurrent_jobs: 4,
             enable_rate_limiter: false,
             rate_limit_rps: 100.0,
             health_port: 8081,
+            priority_enabled: false,
+            idempotency_bucket: None,
+            idempotency_ttl: Duration::from_secs(24 * 60 * 60),
         }
     }
 }
@@ -130,6 +157,24 @@ impl WorkerConfig {
         }
     }
 
+    /// Create from a backend-agnostic [`QueueConfig`].
+    pub fn from_queue_config(queue: &QueueConfig) -> Self {
+        Self {
+            stream_name: queue.queue_name.clone(),
+            consumer_name: queue.consumer_group.clone(),
+            durable_name: queue.consumer_id.clone(),
+            dlq_stream: queue.dlq_name.clone(),
+            batch_size: queue.batch_size,
+            max_deliver: required_max_deliver(&queue.retry_policy),
+            max_concurrent_jobs: queue.max_concurrent_jobs,
+            retry_policy: queue.retry_policy.clone(),
+            enable_rate_limiter: queue.enable_rate_limiter,
+            rate_limit_rps: queue.rate_limit_rps,
+            priority_enabled: queue.priority_enabled,
+            ..Default::default()
+        }
+    }
+
     /// Set the consumer name.
     pub fn with_consumer_name(mut self, name: impl Into<String>) -> Self {
         self.consumer_name = name.into();
@@ -154,6 +199,16 @@ impl WorkerConfig {
         self
     }
 
+    /// Set the retry policy used to compute nak delays.
+    ///
+    /// `max_deliver` is raised if needed so the policy's last retry can
+    /// still reach the DLQ.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.max_deliver = self.max_deliver.max(required_max_deliver(&policy));
+        self.retry_policy = policy;
+        self
+    }
+
     /// Set the maximum concurrent jobs.
     pub fn with_max_concurrent_jobs(mut self, max: usize) -> Self {
         self.max_concurrent_jobs = max;
@@ -167,11 +222,101 @@ impl WorkerConfig {
         self
     }
 
+    /// Enable priority-ordered delivery.
+    pub fn with_priority(mut self, enabled: bool) -> Self {
+        self.priority_enabled = enabled;
+        self
+    }
+
+    /// Subjects to consume, paired with the durable consumer for each.
+    ///
+    /// Without priority this is the configured subject alone. With priority,
+    /// one `<prefix>.<priority>` subject per level, most important first.
+    pub fn consumer_subjects(&self) -> Vec<(String, String)> {
+        if !self.priority_enabled {
+            return vec![(self.durable_name.clone(), self.subject.clone())];
+        }
+
+        JobPriority::DESCENDING
+            .iter()
+            .map(|priority| {
+                (
+                    format!("{}-{}", self.durable_name, priority.as_str()),
+                    priority_subject(&self.subject, *priority),
+                )
+            })
+            .collect()
+    }
+
+    /// Check the configuration for values that would fail at runtime.
+    ///
+    /// Called by `NatsWorker` on startup.
+    pub fn validate(&self) -> Result<(), NatsError> {
+        if self.batch_size == 0 {
+            return Err(NatsError::Config(
+                "batch_size must be greater than 0".to_string(),
+            ));
+        }
+        if self.max_concurrent_jobs == 0 {
+            return Err(NatsError::Config(
+                "max_concurrent_jobs must be greater than 0".to_string(),
+            ));
+        }
+
+        self.retry_policy
+            .validate()
+            .map_err(|e| NatsError::Config(format!("{}: {}", self.stream_name, e)))?;
+
+        let required = required_max_deliver(&self.retry_policy);
+        if self.max_deliver < required {
+            return Err(NatsError::Config(format!(
+                "{}: max_deliver ({}) must exceed the largest retry limit ({}), \
+                 otherwise exhausted jobs never reach the DLQ",
+                self.stream_name,
+                self.max_deliver,
+                required - 1
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Set the health server port.
     pub fn with_health_port(mut self, port: u16) -> Self {
         self.health_port = port;
         self
     }
+
+    /// Enable idempotency tracking in the given KV bucket.
+    ///
+    /// Processed job ids are kept for `ttl`; redeliveries within that window
+    /// are acked without invoking the processor.
+    pub fn with_idempotency(mut self, bucket: impl Into<String>, ttl: Duration) -> Self {
+        self.idempotency_bucket = Some(bucket.into());
+        self.idempotency_ttl = ttl;
+        self
+    }
+}
+
+/// Smallest `max_deliver` that lets every retry of `policy` happen and still
+/// leaves a delivery to move the job to the DLQ.
+pub(crate) fn required_max_deliver(policy: &RetryPolicy) -> i64 {
+    i64::from(
+        policy
+            .max_transient_retries
+            .max(policy.max_rate_limit_retries),
+    ) + 1
+}
+
+/// Subject carrying jobs of `priority` for a stream subject.
+///
+/// Trailing wildcards are dropped, so `emails.>` maps `High` to `emails.high`.
+pub(crate) fn priority_subject(subject: &str, priority: JobPriority) -> String {
+    let prefix = subject
+        .strip_suffix(".>")
+        .or_else(|| subject.strip_suffix(".*"))
+        .unwrap_or(subject);
+    format!("{}.{}", prefix, priority.as_str())
 }
 
 #[cfg(test)]
@@ -185,7 +330,15 @@ mod tests {
         const CONSUMER_NAME: &'static str = "test-worker";
         const DLQ_STREAM: &'static str = "TEST_JOBS_DLQ";
         const SUBJECT: &'static str = "test.>";
-        const MAX_DELIVER: i64 = 5;
+        const MAX_DELIVER: i64 = 10;
+    }
+
+    struct TestStreamDefaults;
+
+    impl StreamConfig for TestStreamDefaults {
+        const STREAM_NAME: &'static str = "DEFAULT_JOBS";
+        const CONSUMER_NAME: &'static str = "default-worker";
+        const DLQ_STREAM: &'static str = "DEFAULT_JOBS_DLQ";
     }
 
     #[test]
@@ -195,7 +348,27 @@ mod tests {
         assert_eq!(config.consumer_name, "test-worker");
         assert_eq!(config.dlq_stream, "TEST_JOBS_DLQ");
         assert_eq!(config.subject, "test.>");
-        assert_eq!(config.max_deliver, 5);
+        assert_eq!(config.max_deliver, 10);
+    }
+
+    #[test]
+    fn test_default_max_deliver_exceeds_retry_limits() {
+        let policy = RetryPolicy::default();
+        let largest = i64::from(
+            policy
+                .max_transient_retries
+                .max(policy.max_rate_limit_retries),
+        );
+
+        assert!(WorkerConfig::default().max_deliver > largest);
+        assert!(TestStreamDefaults::MAX_DELIVER > largest);
+        assert!(WorkerConfig::from_queue_config(&QueueConfig::new("ORDERS")).max_deliver > largest);
+
+        let config = WorkerConfig::new("MY_STREAM").with_retry_policy(RetryPolicy {
+            max_transient_retries: 10,
+            ..Default::default()
+        });
+        assert_eq!(config.max_deliver, 11);
     }
 
     #[test]
@@ -210,5 +383,107 @@ mod tests {
         assert_eq!(config.batch_size, 20);
         assert_eq!(config.max_concurrent_jobs, 8);
         assert_eq!(config.health_port, 9090);
+        assert_eq!(config.idempotency_bucket, None);
+    }
+
+    #[test]
+    fn test_priority_subject() {
+        assert_eq!(
+            priority_subject("emails.>", JobPriority::High),
+            "emails.high"
+        );
+        assert_eq!(priority_subject("emails.*", JobPriority::Low), "emails.low");
+        assert_eq!(
+            priority_subject("emails.send", JobPriority::Normal),
+            "emails.send.normal"
+        );
+    }
+
+    #[test]
+    fn test_consumer_subjects_without_priority() {
+        let config = WorkerConfig::from_stream::<TestStream>().with_durable_name("w1");
+        assert_eq!(
+            config.consumer_subjects(),
+            vec![("w1".to_string(), "test.>".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_consumer_subjects_with_priority() {
+        let config = WorkerConfig::from_stream::<TestStream>()
+            .with_durable_name("w1")
+            .with_priority(true);
+        let expected: Vec<(String, String)> = [
+            ("w1-critical", "test.critical"),
+            ("w1-high", "test.high"),
+            ("w1-normal", "test.normal"),
+            ("w1-low", "test.low"),
+        ]
+        .iter()
+        .map(|(d, s)| (d.to_string(), s.to_string()))
+        .collect();
+        assert_eq!(config.consumer_subjects(), expected);
+    }
+
+    #[test]
+    fn test_config_from_queue_config() {
+        let queue = QueueConfig::new("ORDERS")
+            .with_consumer_group("order-worker")
+            .with_consumer_id("order-worker-1")
+            .with_batch_size(25)
+            .with_priority(true);
+        let config = WorkerConfig::from_queue_config(&queue);
+
+        assert_eq!(config.stream_name, "ORDERS");
+        assert_eq!(config.consumer_name, "order-worker");
+        assert_eq!(config.durable_name, "order-worker-1");
+        assert_eq!(config.dlq_stream, "ORDERS:dlq");
+        assert_eq!(config.batch_size, 25);
+        assert!(config.priority_enabled);
+    }
+
+    #[test]
+    fn test_config_validate() {
+        assert!(WorkerConfig::from_stream::<TestStream>().validate().is_ok());
+
+        let zero_batch = WorkerConfig::new("MY_STREAM").with_batch_size(0);
+        assert!(matches!(zero_batch.validate(), Err(NatsError::Config(_))));
+
+        let bad_policy = WorkerConfig::new("MY_STREAM").with_retry_policy(RetryPolicy {
+            max_transient_retries: 0,
+            ..Default::default()
+        });
+        assert!(matches!(bad_policy.validate(), Err(NatsError::Config(_))));
+
+        // Deliveries must outlast every retry, for both error categories
+        let mut too_few_deliveries = WorkerConfig::new("MY_STREAM");
+        too_few_deliveries.max_deliver = 5;
+        assert!(matches!(
+            too_few_deliveries.validate(),
+            Err(NatsError::Config(_))
+        ));
+
+        let mut transient_heavy = WorkerConfig::new("MY_STREAM");
+        transient_heavy.retry_policy.max_transient_retries = 8;
+        transient_heavy.max_deliver = 8;
+        assert!(matches!(
+            transient_heavy.validate(),
+            Err(NatsError::Config(_))
+        ));
+
+        transient_heavy.max_deliver = 9;
+        assert!(transient_heavy.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_with_idempotency() {
+        let config = WorkerConfig::new("MY_STREAM")
+            .with_idempotency("my_stream_processed", Duration::from_secs(3600));
+
+        assert_eq!(
+            config.idempotency_bucket.as_deref(),
+            Some("my_stream_processed")
+        );
+        assert_eq!(config.idempotency_ttl, Duration::from_secs(3600));
     }
 }
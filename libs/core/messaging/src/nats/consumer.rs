@@ -89,8 +89,26 @@ impl NatsConsumer {
     }
 
     /// Ensure the consumer exists, creating it if necessary.
+    ///
+    /// With priority enabled this is the consumer for the most important level.
     pub async fn ensure_consumer(
         &self,
+    ) -> Result<async_nats::jetstream::consumer::Consumer<ConsumerConfig>, NatsError> {
+        let (durable_name, filter_subject) = self
+            .config
+            .consumer_subjects()
+            .into_iter()
+            .next()
+            .expect("at least one consumer subject");
+        self.ensure_consumer_for(&durable_name, &filter_subject)
+            .await
+    }
+
+    /// Ensure a durable consumer on `filter_subject` exists.
+    async fn ensure_consumer_for(
+        &self,
+        durable_name: &str,
+        filter_subject: &str,
     ) -> Result<async_nats::jetstream::consumer::Consumer<ConsumerConfig>, NatsError> {
         let stream = self
             .jetstream
@@ -99,13 +117,10 @@ impl NatsConsumer {
             .map_err(NatsError::from_jetstream_error)?;
 
         // Try to get existing consumer
-        match stream
-            .get_consumer::<ConsumerConfig>(&self.config.durable_name)
-            .await
-        {
+        match stream.get_consumer::<ConsumerConfig>(durable_name).await {
             Ok(consumer) => {
                 debug!(
-                    consumer = %self.config.durable_name,
+                    consumer = %durable_name,
                     "Consumer already exists"
                 );
                 Ok(consumer)
@@ -113,26 +128,27 @@ impl NatsConsumer {
             Err(_) => {
                 // Create the consumer
                 info!(
-                    consumer = %self.config.durable_name,
+                    consumer = %durable_name,
                     stream = %self.config.stream_name,
+                    subject = %filter_subject,
                     "Creating consumer"
                 );
 
                 let consumer = stream
                     .create_consumer(ConsumerConfig {
-                        durable_name: Some(self.config.durable_name.clone()),
-                        name: Some(self.config.durable_name.clone()),
+                        durable_name: Some(durable_name.to_string()),
+                        name: Some(durable_name.to_string()),
                         ack_policy: AckPolicy::Explicit,
                         ack_wait: self.config.ack_wait,
                         max_deliver: self.config.max_deliver,
-                        filter_subject: self.config.subject.clone(),
+                        filter_subject: filter_subject.to_string(),
                         ..Default::default()
                     })
                     .await
                     .map_err(NatsError::from_jetstream_error)?;
 
                 info!(
-                    consumer = %self.config.durable_name,
+                    consumer = %durable_name,
                     "Consumer created"
                 );
 
@@ -141,17 +157,45 @@ impl NatsConsumer {
         }
     }
 
-    /// Initialize stream and consumer.
+    /// Initialize stream and consumer(s).
     pub async fn init(&self) -> Result<(), NatsError> {
         self.ensure_stream().await?;
-        self.ensure_consumer().await?;
+        for (durable_name, filter_subject) in self.config.consumer_subjects() {
+            self.ensure_consumer_for(&durable_name, &filter_subject)
+                .await?;
+        }
         Ok(())
     }
 
     /// Fetch a batch of messages.
+    ///
+    /// With priority enabled, levels are drained most important first: lower
+    /// priorities only fill what is left of the batch.
     pub async fn fetch<J: Job>(&self, batch_size: usize) -> Result<Vec<NatsMessage<J>>, NatsError> {
-        let consumer = self.ensure_consumer().await?;
+        let mut result = Vec::new();
+
+        for (durable_name, filter_subject) in self.config.consumer_subjects() {
+            let remaining = batch_size.saturating_sub(result.len());
+            if remaining == 0 {
+                break;
+            }
 
+            let consumer = self
+                .ensure_consumer_for(&durable_name, &filter_subject)
+                .await?;
+            self.fetch_from(&consumer, remaining, &mut result).await?;
+        }
+
+        Ok(result)
+    }
+
+    /// Fetch up to `batch_size` messages from one consumer into `result`.
+    async fn fetch_from<J: Job>(
+        &self,
+        consumer: &async_nats::jetstream::consumer::Consumer<ConsumerConfig>,
+        batch_size: usize,
+        result: &mut Vec<NatsMessage<J>>,
+    ) -> Result<(), NatsError> {
         let mut messages = consumer
             .fetch()
             .max_messages(batch_size)
@@ -160,8 +204,6 @@ impl NatsConsumer {
             .await
             .map_err(NatsError::from_jetstream_error)?;
 
-        let mut result = Vec::new();
-
         while let Some(msg) = messages.next().await {
             match msg {
                 Ok(message) => {
@@ -200,7 +242,7 @@ impl NatsConsumer {
             }
         }
 
-        Ok(result)
+        Ok(())
     }
 
     /// Get stream info.
@@ -1,5 +1,6 @@
 //! Health endpoints for K8s probes.
 
+use crate::nats::stats::WorkerStats;
 use axum::{
     extract::State,
     http::StatusCode,
@@ -8,6 +9,7 @@ use axum::{
     Router,
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::info;
@@ -44,12 +46,25 @@ impl HealthStatus {
 #[derive(Clone)]
 pub struct HealthState {
     inner: Arc<RwLock<HealthStateInner>>,
+    stats: WorkerStats,
 }
 
 struct HealthStateInner {
     stream_connected: bool,
     processor_healthy: bool,
     last_error: Option<String>,
+    /// Last fetch error per consumed stream, `None` while connected
+    streams: BTreeMap<String, Option<String>>,
+}
+
+impl HealthStateInner {
+    fn streams_connected(&self) -> bool {
+        self.stream_connected && self.streams.values().all(Option::is_none)
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.streams_connected() && self.processor_healthy
+    }
 }
 
 impl HealthState {
@@ -60,16 +75,32 @@ impl HealthState {
                 stream_connected: true,
                 processor_healthy: true,
                 last_error: None,
+                streams: BTreeMap::new(),
             })),
+            stats: WorkerStats::new(),
         }
     }
 
+    /// Job counters, shared with the worker this state is attached to.
+    pub fn stats(&self) -> WorkerStats {
+        self.stats.clone()
+    }
+
     /// Mark stream as connected.
     pub async fn set_stream_connected(&self, connected: bool) {
         let mut inner = self.inner.write().await;
         inner.stream_connected = connected;
     }
 
+    /// Record the outcome of the last fetch on one stream.
+    ///
+    /// `None` marks the stream connected. A multi-stream worker is only ready
+    /// while every stream it reported is connected.
+    pub async fn set_stream_status(&self, stream: &str, error: Option<String>) {
+        let mut inner = self.inner.write().await;
+        inner.streams.insert(stream.to_string(), error);
+    }
+
     /// Mark processor as healthy.
     pub async fn set_processor_healthy(&self, healthy: bool) {
         let mut inner = self.inner.write().await;
@@ -95,18 +126,20 @@ impl HealthState {
     /// Check if healthy (for readiness).
     pub async fn is_healthy(&self) -> bool {
         let inner = self.inner.read().await;
-        inner.stream_connected && inner.processor_healthy
+        inner.is_healthy()
     }
 
     /// Get status.
     pub async fn status(&self) -> HealthStatus {
         let inner = self.inner.read().await;
-        if inner.stream_connected && inner.processor_healthy {
+        if inner.is_healthy() {
             HealthStatus::healthy()
         } else {
-            let reason = inner
-                .last_error
-                .clone()
+            let stream_error = inner.streams.iter().find_map(|(stream, error)| {
+                error.as_ref().map(|error| format!("{}: {}", stream, error))
+            });
+            let reason = stream_error
+                .or_else(|| inner.last_error.clone())
                 .unwrap_or_else(|| "unknown".to_string());
             HealthStatus::unhealthy(&reason)
         }
@@ -157,6 +190,7 @@ impl HealthServer {
             .route("/healthz", get(health_handler))
             .route("/ready", get(ready_handler))
             .route("/readyz", get(ready_handler))
+            .route("/stats", get(stats_handler))
             .with_state(state);
 
         if let Some(handle) = metrics_handle {
@@ -207,3 +241,34 @@ async fn ready_handler(State(state): State<HealthState>) -> impl IntoResponse {
         (StatusCode::SERVICE_UNAVAILABLE, Json(state.status().await))
     }
 }
+
+/// Cumulative job counters.
+async fn stats_handler(State(state): State<HealthState>) -> impl IntoResponse {
+    Json(state.stats().snapshot())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_ready_only_when_every_stream_connected() {
+        let state = HealthState::new();
+        state.set_stream_status("orders", None).await;
+        state
+            .set_stream_status("emails", Some("fetch timed out".to_string()))
+            .await;
+        assert!(!state.is_healthy().await);
+        assert!(state.is_alive().await);
+
+        // A later success on another stream doesn't mask the failing one
+        state.set_stream_status("orders", None).await;
+        assert!(!state.is_healthy().await);
+        let status = state.status().await;
+        assert!(!status.stream_connected);
+        assert_eq!(status.status, "unhealthy: emails: fetch timed out");
+
+        state.set_stream_status("emails", None).await;
+        assert!(state.is_healthy().await);
+    }
+}
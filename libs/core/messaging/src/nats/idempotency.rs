@@ -0,0 +1,199 @@
+//! Idempotent job processing backed by a JetStream KV bucket.
+//!
+//! JetStream delivers at least once, so a job can reach the processor twice
+//! (e.g. the worker crashed after processing but before acking). When enabled,
+//! the worker records each successfully processed `job_id()` in a KV bucket and
+//! acks redeliveries of recorded jobs without processing them again.
+//!
+//! The id is written *after* the processor succeeds, so a crash before the
+//! write leads to reprocessing rather than a lost job. A crash between the
+//! write and the ack is covered by the redelivery being skipped.
+
+use crate::nats::error::NatsError;
+use async_nats::jetstream::kv::{Config as KvConfig, CreateErrorKind, Store};
+use async_nats::jetstream::Context;
+use async_trait::async_trait;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Storage for processed job ids.
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// Whether a job id has already been recorded.
+    async fn contains(&self, job_id: &str) -> Result<bool, NatsError>;
+
+    /// Record a job id, returning `false` if it was already present.
+    async fn insert(&self, job_id: &str) -> Result<bool, NatsError>;
+}
+
+/// [`IdempotencyStore`] backed by a JetStream KV bucket.
+///
+/// Entries expire after the bucket's `max_age`, which is set from
+/// [`WorkerConfig::idempotency_ttl`](crate::nats::WorkerConfig::idempotency_ttl).
+/// Job ids must be valid KV keys (UUIDs are).
+pub struct KvIdempotencyStore {
+    store: Store,
+}
+
+impl KvIdempotencyStore {
+    /// Open the bucket, creating it with the given TTL if it does not exist.
+    pub async fn new(jetstream: &Context, bucket: &str, ttl: Duration) -> Result<Self, NatsError> {
+        let store = match jetstream.get_key_value(bucket).await {
+            Ok(store) => {
+                debug!(bucket = %bucket, "Idempotency bucket already exists");
+                store
+            }
+            Err(_) => {
+                info!(bucket = %bucket, ttl_secs = ttl.as_secs(), "Creating idempotency bucket");
+                jetstream
+                    .create_key_value(KvConfig {
+                        bucket: bucket.to_string(),
+                        history: 1,
+                        max_age: ttl,
+                        ..Default::default()
+                    })
+                    .await
+                    .map_err(NatsError::from_jetstream_error)?
+            }
+        };
+
+        Ok(Self { store })
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for KvIdempotencyStore {
+    async fn contains(&self, job_id: &str) -> Result<bool, NatsError> {
+        let entry = self
+            .store
+            .get(job_id)
+            .await
+            .map_err(NatsError::from_jetstream_error)?;
+        Ok(entry.is_some())
+    }
+
+    async fn insert(&self, job_id: &str) -> Result<bool, NatsError> {
+        match self.store.create(job_id, Vec::new().into()).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == CreateErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(NatsError::from_jetstream_error(e)),
+        }
+    }
+}
+
+/// Whether a delivered job should be handed to the processor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Decision {
+    /// Not seen before (or the store is unavailable) - process it.
+    Process,
+    /// Already processed - ack and skip.
+    Skip,
+}
+
+/// Decide whether to process a job.
+///
+/// Store errors fail open: processing twice is preferable to never.
+pub(crate) async fn check(store: &dyn IdempotencyStore, job_id: &str) -> Decision {
+    match store.contains(job_id).await {
+        Ok(true) => Decision::Skip,
+        Ok(false) => Decision::Process,
+        Err(e) => {
+            warn!(job_id = %job_id, error = %e, "Idempotency check failed, processing anyway");
+            Decision::Process
+        }
+    }
+}
+
+/// Record a successfully processed job.
+///
+/// Failures are logged and otherwise ignored; the job has already been
+/// processed and must still be acked.
+pub(crate) async fn record(store: &dyn IdempotencyStore, job_id: &str) {
+    match store.insert(job_id).await {
+        Ok(true) => {}
+        Ok(false) => debug!(job_id = %job_id, "Job id already recorded"),
+        Err(e) => warn!(job_id = %job_id, error = %e, "Failed to record processed job"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+
+    /// In-memory stand-in for a KV bucket
+    #[derive(Default)]
+    struct MockKv {
+        keys: Mutex<HashSet<String>>,
+        unavailable: AtomicBool,
+    }
+
+    impl MockKv {
+        fn check_available(&self) -> Result<(), NatsError> {
+            if self.unavailable.load(Ordering::SeqCst) {
+                return Err(NatsError::JetStream("kv unavailable".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl IdempotencyStore for MockKv {
+        async fn contains(&self, job_id: &str) -> Result<bool, NatsError> {
+            self.check_available()?;
+            Ok(self.keys.lock().unwrap().contains(job_id))
+        }
+
+        async fn insert(&self, job_id: &str) -> Result<bool, NatsError> {
+            self.check_available()?;
+            Ok(self.keys.lock().unwrap().insert(job_id.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_job_is_processed() {
+        let kv = MockKv::default();
+        assert_eq!(check(&kv, "job-1").await, Decision::Process);
+    }
+
+    #[tokio::test]
+    async fn test_recorded_job_is_skipped() {
+        let kv = MockKv::default();
+        record(&kv, "job-1").await;
+
+        assert_eq!(check(&kv, "job-1").await, Decision::Skip);
+        assert_eq!(check(&kv, "job-2").await, Decision::Process);
+    }
+
+    #[tokio::test]
+    async fn test_unrecorded_job_is_reprocessed() {
+        // Processor ran but the worker crashed before recording: redelivery
+        // must not be dropped.
+        let kv = MockKv::default();
+        assert_eq!(check(&kv, "job-1").await, Decision::Process);
+        assert_eq!(check(&kv, "job-1").await, Decision::Process);
+    }
+
+    #[tokio::test]
+    async fn test_record_is_idempotent() {
+        let kv = MockKv::default();
+        record(&kv, "job-1").await;
+        record(&kv, "job-1").await;
+
+        assert!(!kv.insert("job-1").await.unwrap());
+        assert_eq!(check(&kv, "job-1").await, Decision::Skip);
+    }
+
+    #[tokio::test]
+    async fn test_store_errors_fail_open() {
+        let kv = MockKv::default();
+        record(&kv, "job-1").await;
+        kv.unavailable.store(true, Ordering::SeqCst);
+
+        assert_eq!(check(&kv, "job-1").await, Decision::Process);
+        // Recording while unavailable does not panic
+        record(&kv, "job-2").await;
+    }
+}
@@ -77,6 +77,16 @@ impl NatsMetrics {
         .increment(1);
     }
 
+    /// Record a duplicate job skipped by the idempotency check.
+    pub fn job_duplicate_skipped(&self) {
+        counter!(
+            "nats_worker_jobs_duplicate_skipped_total",
+            "stream" => self.stream_name.clone(),
+            "processor" => self.processor_name.clone()
+        )
+        .increment(1);
+    }
+
     /// Update stream depth gauge.
     pub fn stream_depth(&self, depth: u64) {
         gauge!(
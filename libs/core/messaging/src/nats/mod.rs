@@ -25,8 +25,11 @@
 //! - **Dead Letter Queue**: Failed messages moved to DLQ after max retries
 //! - **Health Endpoints**: K8s-ready liveness/readiness probes
 //! - **Prometheus Metrics**: Jobs processed, failed, latency histograms
+//! - **Live Stats**: Cumulative job counters in-process and at `/stats`
 //! - **Graceful Shutdown**: Drain in-flight messages before exit
 //! - **Concurrent Processing**: Process multiple messages in parallel (configurable)
+//! - **Idempotency**: Optional JetStream KV dedup of redelivered jobs
+//! - **Multiple Streams**: One worker can consume several streams via `NatsWorker::multi`
 //!
 //! # Example
 //!
@@ -58,8 +61,10 @@ mod consumer;
 mod dlq;
 mod error;
 mod health;
+mod idempotency;
 pub mod metrics;
 mod producer;
+mod stats;
 mod worker;
 
 pub use config::{StreamConfig, WorkerConfig};
@@ -67,6 +72,8 @@ pub use consumer::{NatsConsumer, NatsMessage, StreamInfo};
 pub use dlq::{DlqEntry, DlqManager, DlqStats};
 pub use error::NatsError;
 pub use health::{HealthServer, HealthState, HealthStatus};
+pub use idempotency::{IdempotencyStore, KvIdempotencyStore};
 pub use metrics::{init_metrics, NatsMetrics};
 pub use producer::NatsProducer;
+pub use stats::{WorkerStats, WorkerStatsSnapshot};
 pub use worker::NatsWorker;
@@ -1,6 +1,6 @@
 //! NATS JetStream producer for publishing jobs.
 
-use crate::nats::config::StreamConfig;
+use crate::nats::config::{priority_subject, StreamConfig};
 use crate::nats::error::NatsError;
 use crate::Job;
 use async_nats::jetstream::Context;
@@ -13,6 +13,7 @@ pub struct NatsProducer {
     jetstream: Arc<Context>,
     stream_name: String,
     subject: String,
+    priority_enabled: bool,
 }
 
 impl NatsProducer {
@@ -26,6 +27,7 @@ impl NatsProducer {
             jetstream: Arc::new(jetstream),
             stream_name: stream_name.into(),
             subject: subject.into(),
+            priority_enabled: false,
         }
     }
 
@@ -35,6 +37,7 @@ impl NatsProducer {
             jetstream: Arc::new(jetstream),
             stream_name: S::STREAM_NAME.to_string(),
             subject: S::SUBJECT.to_string(),
+            priority_enabled: false,
         }
     }
 
@@ -48,9 +51,19 @@ impl NatsProducer {
             jetstream,
             stream_name: stream_name.into(),
             subject: subject.into(),
+            priority_enabled: false,
         }
     }
 
+    /// Publish to per-priority subjects (`<prefix>.<priority>`).
+    ///
+    /// Must match `WorkerConfig::priority_enabled` on the consuming side, and
+    /// the subject should be the stream's subject (e.g. `emails.>`).
+    pub fn with_priority(mut self, enabled: bool) -> Self {
+        self.priority_enabled = enabled;
+        self
+    }
+
     /// Get the stream name.
     pub fn stream_name(&self) -> &str {
         &self.stream_name
@@ -61,15 +74,25 @@ impl NatsProducer {
         &self.subject
     }
 
+    /// Subject a job is published to.
+    pub fn subject_for<J: Job>(&self, job: &J) -> String {
+        if self.priority_enabled {
+            priority_subject(&self.subject, job.priority())
+        } else {
+            self.subject.clone()
+        }
+    }
+
     /// Publish a job to the stream.
     ///
     /// Returns the sequence number of the published message.
     pub async fn send<J: Job>(&self, job: &J) -> Result<u64, NatsError> {
         let job_json = serde_json::to_vec(job)?;
+        let subject = self.subject_for(job);
 
         let ack = self
             .jetstream
-            .publish(self.subject.clone(), job_json.into())
+            .publish(subject.clone(), job_json.into())
             .await
             .map_err(|e| NatsError::publish_error(e.to_string()))?
             .await
@@ -77,7 +100,7 @@ impl NatsProducer {
 
         debug!(
             stream = %self.stream_name,
-            subject = %self.subject,
+            subject = %subject,
             sequence = ack.sequence,
             job_id = %job.job_id(),
             "Published job"
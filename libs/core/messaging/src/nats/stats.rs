@@ -0,0 +1,169 @@
+//! Cumulative job counters for the worker.
+//!
+//! Unlike the Prometheus metrics, these are plain in-process totals that can
+//! be read directly (e.g. by a custom autoscaler) or via the `/stats` endpoint.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Live job counters since the worker started.
+///
+/// Cheap to clone; all clones share the same counters.
+#[derive(Clone)]
+pub struct WorkerStats {
+    inner: Arc<WorkerStatsInner>,
+}
+
+struct WorkerStatsInner {
+    started_at: DateTime<Utc>,
+    received: AtomicU64,
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+    retried: AtomicU64,
+    dead_lettered: AtomicU64,
+    duplicates_skipped: AtomicU64,
+}
+
+/// Point-in-time copy of [`WorkerStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct WorkerStatsSnapshot {
+    pub started_at: DateTime<Utc>,
+    /// Messages fetched from the stream.
+    pub received: u64,
+    /// Jobs whose processing finished, successfully or not.
+    pub processed: u64,
+    pub succeeded: u64,
+    pub failed: u64,
+    /// Failed jobs scheduled for redelivery.
+    pub retried: u64,
+    /// Failed jobs moved to the DLQ.
+    pub dead_lettered: u64,
+    /// Already-processed jobs acked without running the processor.
+    pub duplicates_skipped: u64,
+}
+
+impl WorkerStats {
+    /// Create zeroed counters.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(WorkerStatsInner {
+                started_at: Utc::now(),
+                received: AtomicU64::new(0),
+                succeeded: AtomicU64::new(0),
+                failed: AtomicU64::new(0),
+                retried: AtomicU64::new(0),
+                dead_lettered: AtomicU64::new(0),
+                duplicates_skipped: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Record a message received.
+    pub fn job_received(&self) {
+        self.inner.received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a job processed successfully.
+    pub fn job_succeeded(&self) {
+        self.inner.succeeded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a job failed.
+    pub fn job_failed(&self) {
+        self.inner.failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a job scheduled for retry.
+    pub fn job_retried(&self) {
+        self.inner.retried.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a job moved to the DLQ.
+    pub fn job_dead_lettered(&self) {
+        self.inner.dead_lettered.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a duplicate job skipped by the idempotency check.
+    pub fn job_duplicate_skipped(&self) {
+        self.inner
+            .duplicates_skipped
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Read the current totals.
+    pub fn snapshot(&self) -> WorkerStatsSnapshot {
+        let succeeded = self.inner.succeeded.load(Ordering::Relaxed);
+        let failed = self.inner.failed.load(Ordering::Relaxed);
+
+        WorkerStatsSnapshot {
+            started_at: self.inner.started_at,
+            received: self.inner.received.load(Ordering::Relaxed),
+            processed: succeeded + failed,
+            succeeded,
+            failed,
+            retried: self.inner.retried.load(Ordering::Relaxed),
+            dead_lettered: self.inner.dead_lettered.load(Ordering::Relaxed),
+            duplicates_skipped: self.inner.duplicates_skipped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for WorkerStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_start_at_zero() {
+        let snapshot = WorkerStats::new().snapshot();
+        assert_eq!(snapshot.received, 0);
+        assert_eq!(snapshot.processed, 0);
+        assert_eq!(snapshot.dead_lettered, 0);
+        assert_eq!(snapshot.duplicates_skipped, 0);
+    }
+
+    #[test]
+    fn test_counters_advance() {
+        let stats = WorkerStats::new();
+
+        for _ in 0..4 {
+            stats.job_received();
+        }
+        stats.job_succeeded();
+        stats.job_succeeded();
+        stats.job_failed();
+        stats.job_retried();
+        stats.job_duplicate_skipped();
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.received, 4);
+        assert_eq!(snapshot.processed, 3);
+        assert_eq!(snapshot.succeeded, 2);
+        assert_eq!(snapshot.failed, 1);
+        assert_eq!(snapshot.retried, 1);
+        assert_eq!(snapshot.dead_lettered, 0);
+        assert_eq!(snapshot.duplicates_skipped, 1);
+    }
+
+    #[test]
+    fn test_clones_share_counters() {
+        let stats = WorkerStats::new();
+        let clone = stats.clone();
+
+        clone.job_received();
+        clone.job_failed();
+        clone.job_dead_lettered();
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.received, 1);
+        assert_eq!(snapshot.failed, 1);
+        assert_eq!(snapshot.dead_lettered, 1);
+    }
+}
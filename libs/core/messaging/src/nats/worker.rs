@@ -1,28 +1,42 @@
 //! NATS JetStream worker for processing jobs.
 //!
 //! IMPROVEMENT: Now processes messages concurrently using a semaphore
-//! to respect max_concurrent_jobs configuration.
+//! to respect max_concurrent_jobs configuration. A single worker can consume
+//! several streams, each polled by its own pull consumer.
 
 use crate::nats::config::WorkerConfig;
 use crate::nats::consumer::{NatsConsumer, NatsMessage, StreamInfo};
 use crate::nats::dlq::DlqManager;
 use crate::nats::error::NatsError;
 use crate::nats::health::HealthState;
+use crate::nats::idempotency::{self, Decision, IdempotencyStore, KvIdempotencyStore};
 use crate::nats::metrics::NatsMetrics;
-use crate::{ErrorCategory, Job, ProcessingError, Processor};
+use crate::nats::stats::WorkerStats;
+use crate::{ErrorCategory, Job, ProcessingError, Processor, RetryPolicy};
 use async_nats::jetstream::Context;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{watch, Semaphore};
 use tracing::{debug, error, info, warn};
 
-/// NATS JetStream worker for processing jobs.
-pub struct NatsWorker<J: Job, P: Processor<J>> {
+/// A single consumed stream with its own consumer, DLQ and metrics.
+struct StreamHandle {
     consumer: NatsConsumer,
     dlq: Arc<DlqManager>,
-    processor: Arc<P>,
-    config: WorkerConfig,
     metrics: Arc<NatsMetrics>,
+    idempotency: Option<Arc<dyn IdempotencyStore>>,
+    config: WorkerConfig,
+}
+
+/// NATS JetStream worker for processing jobs.
+///
+/// A worker consumes one or more streams (see [`NatsWorker::multi`]) and fans
+/// every message into the same [`Processor`]. Ack/nak, DLQ routing and
+/// metrics are kept per stream.
+pub struct NatsWorker<J: Job, P: Processor<J>> {
+    streams: Vec<StreamHandle>,
+    processor: Arc<P>,
+    stats: WorkerStats,
     health_state: Option<HealthState>,
     _marker: std::marker::PhantomData<J>,
 }
@@ -34,25 +48,79 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
         processor: P,
         config: WorkerConfig,
     ) -> Result<Self, NatsError> {
+        Self::multi(jetstream, processor, vec![config]).await
+    }
+
+    /// Create a worker consuming several streams with a shared processor.
+    ///
+    /// A pull consumer is created per config. Each stream keeps its own DLQ,
+    /// batch size and concurrency limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let worker = NatsWorker::multi(
+    ///     jetstream,
+    ///     processor,
+    ///     vec![
+    ///         WorkerConfig::from_stream::<EmailStream>(),
+    ///         WorkerConfig::from_stream::<EmailPriorityStream>(),
+    ///     ],
+    /// )
+    /// .await?;
+    /// ```
+    pub async fn multi(
+        jetstream: Context,
+        processor: P,
+        configs: Vec<WorkerConfig>,
+    ) -> Result<Self, NatsError> {
+        if configs.is_empty() {
+            return Err(NatsError::Config(
+                "at least one stream config is required".to_string(),
+            ));
+        }
+
         let jetstream = Arc::new(jetstream);
         let processor_name = processor.name();
 
-        let consumer = NatsConsumer::new(jetstream.clone(), config.clone());
-        let dlq = Arc::new(DlqManager::new(jetstream.clone(), &config.dlq_stream));
-        let metrics = Arc::new(NatsMetrics::new(&config.stream_name, processor_name));
+        let mut streams = Vec::with_capacity(configs.len());
+        for config in &configs {
+            config.validate()?;
+        }
 
-        // Initialize stream and consumer
-        consumer.init().await?;
+        for config in configs {
+            let consumer = NatsConsumer::new(jetstream.clone(), config.clone());
+            let dlq = Arc::new(DlqManager::new(jetstream.clone(), &config.dlq_stream));
+            let metrics = Arc::new(NatsMetrics::new(&config.stream_name, processor_name));
 
-        // Initialize DLQ stream
-        dlq.ensure_stream().await?;
+            // Initialize stream and consumer
+            consumer.init().await?;
+
+            // Initialize DLQ stream
+            dlq.ensure_stream().await?;
+
+            let idempotency = match config.idempotency_bucket {
+                Some(ref bucket) => {
+                    let store =
+                        KvIdempotencyStore::new(&jetstream, bucket, config.idempotency_ttl).await?;
+                    Some(Arc::new(store) as Arc<dyn IdempotencyStore>)
+                }
+                None => None,
+            };
+
+            streams.push(StreamHandle {
+                consumer,
+                dlq,
+                metrics,
+                idempotency,
+                config,
+            });
+        }
 
         Ok(Self {
-            consumer,
-            dlq,
+            streams,
             processor: Arc::new(processor),
-            config,
-            metrics,
+            stats: WorkerStats::new(),
             health_state: None,
             _marker: std::marker::PhantomData,
         })
@@ -60,87 +128,124 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
 
     /// Set the health state for K8s probe updates.
     ///
-    /// When set, the worker updates `stream_connected` on batch success/failure
-    /// so readiness probes reflect actual NATS connectivity.
+    /// When set, the worker records each stream's fetch success/failure so
+    /// readiness probes reflect actual NATS connectivity of every stream. Job counters are
+    /// shared with the state so the health server can serve them at `/stats`.
     pub fn with_health_state(mut self, state: HealthState) -> Self {
+        self.stats = state.stats();
         self.health_state = Some(state);
         self
     }
 
+    /// Cumulative job counters since the worker started.
+    pub fn stats(&self) -> WorkerStats {
+        self.stats.clone()
+    }
+
+    /// Names of the streams this worker consumes.
+    pub fn stream_names(&self) -> Vec<&str> {
+        self.streams
+            .iter()
+            .map(|stream| stream.config.stream_name.as_str())
+            .collect()
+    }
+
     /// Run the worker loop.
     ///
-    /// The worker will:
+    /// Every stream is polled concurrently. For each stream the worker will:
     /// 1. Fetch messages in batches
     /// 2. Process each message concurrently (up to max_concurrent_jobs)
     /// 3. Ack on success, nak on transient failure, term on permanent failure
     /// 4. Move permanently failed messages to DLQ
-    /// 5. Handle shutdown gracefully
-    pub async fn run(&self, mut shutdown_rx: watch::Receiver<bool>) -> Result<(), NatsError> {
+    /// 5. Handle shutdown gracefully, finishing the in-flight batch first
+    ///
+    /// Returns once every stream has drained.
+    pub async fn run(&self, shutdown_rx: watch::Receiver<bool>) -> Result<(), NatsError> {
+        let loops = self
+            .streams
+            .iter()
+            .map(|stream| self.run_stream(stream, shutdown_rx.clone()));
+        futures::future::join_all(loops).await;
+
+        info!("NATS worker stopped");
+        Ok(())
+    }
+
+    /// Poll a single stream until shutdown.
+    async fn run_stream(&self, stream: &StreamHandle, mut shutdown_rx: watch::Receiver<bool>) {
         info!(
-            stream = %self.config.stream_name,
-            consumer = %self.config.consumer_name,
-            durable = %self.config.durable_name,
-            max_concurrent = %self.config.max_concurrent_jobs,
+            stream = %stream.config.stream_name,
+            consumer = %stream.config.consumer_name,
+            durable = %stream.config.durable_name,
+            max_concurrent = %stream.config.max_concurrent_jobs,
             "Starting NATS worker"
         );
 
         loop {
-            tokio::select! {
-                // Check for shutdown
+            // Only the fetch is cancelled on shutdown; a fetched batch is
+            // always processed to completion.
+            let fetched = tokio::select! {
                 _ = shutdown_rx.changed() => {
                     if *shutdown_rx.borrow() {
-                        info!("Shutdown signal received, stopping worker");
+                        info!(
+                            stream = %stream.config.stream_name,
+                            "Shutdown signal received, stopping consumer"
+                        );
                         break;
                     }
+                    continue;
                 }
+                result = stream.consumer.fetch::<J>(stream.config.batch_size) => result,
+            };
+
+            match fetched {
+                Ok(messages) => {
+                    if let Some(ref state) = self.health_state {
+                        state
+                            .set_stream_status(&stream.config.stream_name, None)
+                            .await;
+                    }
 
-                // Main processing loop
-                result = self.process_batch() => {
-                    match result {
-                        Ok(()) => {
-                            if let Some(ref state) = self.health_state {
-                                state.set_stream_connected(true).await;
-                                state.set_error(None).await;
-                            }
-                        }
-                        Err(e) => {
-                            error!(error = %e, "Error processing batch");
-                            if let Some(ref state) = self.health_state {
-                                state.set_stream_connected(false).await;
-                                state.set_error(Some(e.to_string())).await;
-                            }
-                            tokio::time::sleep(Duration::from_secs(1)).await;
-                        }
+                    if messages.is_empty() {
+                        // No messages, wait before next poll
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        continue;
                     }
+
+                    self.process_batch(stream, messages).await;
+                }
+                Err(e) => {
+                    error!(
+                        stream = %stream.config.stream_name,
+                        error = %e,
+                        "Error processing batch"
+                    );
+                    if let Some(ref state) = self.health_state {
+                        state
+                            .set_stream_status(&stream.config.stream_name, Some(e.to_string()))
+                            .await;
+                    }
+                    tokio::time::sleep(Duration::from_secs(1)).await;
                 }
             }
         }
-
-        info!("NATS worker stopped");
-        Ok(())
     }
 
     /// Process a batch of messages concurrently.
     ///
     /// IMPROVEMENT: Uses a semaphore to limit concurrent processing to max_concurrent_jobs.
-    async fn process_batch(&self) -> Result<(), NatsError> {
-        let messages: Vec<NatsMessage<J>> = self.consumer.fetch(self.config.batch_size).await?;
-
-        if messages.is_empty() {
-            // No messages, wait before next poll
-            tokio::time::sleep(Duration::from_millis(100)).await;
-            return Ok(());
-        }
-
+    async fn process_batch(&self, stream: &StreamHandle, messages: Vec<NatsMessage<J>>) {
         // Create a semaphore to limit concurrent processing
-        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrent_jobs));
+        let semaphore = Arc::new(Semaphore::new(stream.config.max_concurrent_jobs));
         let mut handles = Vec::with_capacity(messages.len());
 
         for message in messages {
-            self.metrics.job_received();
+            stream.metrics.job_received();
+            self.stats.job_received();
 
             if message.is_redelivery() {
                 debug!(
+                    stream = %stream.config.stream_name,
                     job_id = %message.job_id(),
                     sequence = message.sequence,
                     delivery_count = message.delivery_count,
@@ -151,9 +256,11 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
             // Clone Arcs for the spawned task
             let permit = semaphore.clone().acquire_owned().await.unwrap();
             let processor = self.processor.clone();
-            let dlq = self.dlq.clone();
-            let metrics = self.metrics.clone();
-            let config = self.config.clone();
+            let dlq = stream.dlq.clone();
+            let metrics = stream.metrics.clone();
+            let idempotency = stream.idempotency.clone();
+            let stats = self.stats.clone();
+            let config = stream.config.clone();
 
             // Spawn concurrent task
             let handle = tokio::spawn(async move {
@@ -162,6 +269,8 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
                     processor.as_ref(),
                     dlq.as_ref(),
                     metrics.as_ref(),
+                    idempotency.as_deref(),
+                    &stats,
                     &config,
                 )
                 .await;
@@ -180,8 +289,6 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
                 error!(error = %e, "Task panicked");
             }
         }
-
-        Ok(())
     }
 
     /// Process a single message (static method for use in spawned tasks).
@@ -190,12 +297,28 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
         processor: &P,
         dlq: &DlqManager,
         metrics: &NatsMetrics,
-        _config: &WorkerConfig,
+        idempotency: Option<&dyn IdempotencyStore>,
+        stats: &WorkerStats,
+        config: &WorkerConfig,
     ) -> Result<(), NatsError> {
         let job_id = message.job_id();
         let sequence = message.sequence;
         let retry_count = message.job.retry_count();
 
+        if let Some(store) = idempotency {
+            if idempotency::check(store, &job_id).await == Decision::Skip {
+                debug!(
+                    job_id = %job_id,
+                    sequence = sequence,
+                    "Job already processed, skipping duplicate"
+                );
+                metrics.job_duplicate_skipped();
+                stats.job_duplicate_skipped();
+                message.ack().await?;
+                return Ok(());
+            }
+        }
+
         debug!(
             job_id = %job_id,
             sequence = sequence,
@@ -209,9 +332,15 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
 
         match result {
             Ok(()) => {
+                // Record before acking so a redelivery after a lost ack is skipped
+                if let Some(store) = idempotency {
+                    idempotency::record(store, &job_id).await;
+                }
+
                 // Success - acknowledge
                 message.ack().await?;
                 metrics.job_processed(duration);
+                stats.job_succeeded();
 
                 debug!(
                     job_id = %job_id,
@@ -221,7 +350,8 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
                 );
             }
             Err(e) => {
-                Self::handle_error_inner(message, e, dlq, metrics).await?;
+                Self::handle_error_inner(message, e, dlq, metrics, stats, &config.retry_policy)
+                    .await?;
             }
         }
 
@@ -234,12 +364,20 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
         error: ProcessingError,
         dlq: &DlqManager,
         metrics: &NatsMetrics,
+        stats: &WorkerStats,
+        retry_policy: &RetryPolicy,
     ) -> Result<(), NatsError> {
         let job_id = message.job_id();
-        let retry_count = message.job.retry_count();
+        // Naks redeliver the same payload, so the job's own counter does not
+        // advance; fall back to the JetStream delivery count.
+        let retry_count = message
+            .job
+            .retry_count()
+            .max(message.delivery_count.saturating_sub(1));
         let category = error.category();
 
         metrics.job_failed(&format!("{:?}", category));
+        stats.job_failed();
 
         match category {
             ErrorCategory::Permanent => {
@@ -254,29 +392,27 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
                     .await?;
 
                 metrics.job_moved_to_dlq();
+                stats.job_dead_lettered();
 
                 // Terminate (don't redeliver)
                 message.term().await?;
             }
             ErrorCategory::Transient | ErrorCategory::RateLimited => {
-                if error.should_retry(retry_count) {
-                    // Request redelivery with backoff
-                    let delay_ms = error.backoff_delay_ms(retry_count);
-
+                if let Some(delay) = retry_policy.redelivery_delay(&error, retry_count) {
                     warn!(
                         job_id = %job_id,
                         error = %error,
+                        category = %category,
                         retry_count = retry_count,
-                        delay_ms = delay_ms,
-                        "Transient error, will retry"
+                        delay_ms = delay.as_millis() as u64,
+                        "Retryable error, will retry"
                     );
 
                     metrics.job_retried();
+                    stats.job_retried();
 
                     // Nak with delay
-                    message
-                        .nak_with_delay(Duration::from_millis(delay_ms))
-                        .await?;
+                    message.nak_with_delay(delay).await?;
                 } else {
                     // Max retries exceeded, move to DLQ
                     error!(
@@ -290,6 +426,7 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
                         .await?;
 
                     metrics.job_moved_to_dlq();
+                    stats.job_dead_lettered();
 
                     // Terminate
                     message.term().await?;
@@ -300,13 +437,33 @@ impl<J: Job, P: Processor<J> + 'static> NatsWorker<J, P> {
         Ok(())
     }
 
-    /// Get stream info.
+    /// Get stream info for the first configured stream.
+    #[deprecated(note = "only covers the first stream; use `stream_infos` instead")]
     pub async fn stream_info(&self) -> Result<StreamInfo, NatsError> {
-        self.consumer.stream_info().await
+        self.streams[0].consumer.stream_info().await
     }
 
-    /// Get DLQ info.
+    /// Get DLQ info for the first configured stream.
+    #[deprecated(note = "only covers the first stream; use `dlq_infos` instead")]
     pub async fn dlq_info(&self) -> Result<StreamInfo, NatsError> {
-        self.dlq.stream_info().await
+        self.streams[0].dlq.stream_info().await
+    }
+
+    /// Get stream info for every configured stream.
+    pub async fn stream_infos(&self) -> Result<Vec<StreamInfo>, NatsError> {
+        let mut infos = Vec::with_capacity(self.streams.len());
+        for stream in &self.streams {
+            infos.push(stream.consumer.stream_info().await?);
+        }
+        Ok(infos)
+    }
+
+    /// Get DLQ info for every configured stream, in stream order.
+    pub async fn dlq_infos(&self) -> Result<Vec<StreamInfo>, NatsError> {
+        let mut infos = Vec::with_capacity(self.streams.len());
+        for stream in &self.streams {
+            infos.push(stream.dlq.stream_info().await?);
+        }
+        Ok(infos)
     }
 }
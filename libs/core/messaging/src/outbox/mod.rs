@@ -0,0 +1,151 @@
+//! Transactional outbox for enqueueing jobs consistently with DB writes.
+//!
+//! Publishing a job right after committing a domain change loses the job if
+//! the process dies in between. Instead, [`enqueue`] writes the job into the
+//! `outbox` table using the caller's transaction, so it commits (or rolls
+//! back) together with the domain change. An [`OutboxRelay`] then publishes
+//! pending rows and marks them sent.
+//!
+//! Delivery is at-least-once: a crash after publishing but before marking a
+//! row sent republishes it, so processors must be idempotent. Failed
+//! publishes are retried with backoff; after `max_attempts` a row is marked
+//! failed (`failed_at`) and left in the table for inspection.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use messaging::outbox::{self, OutboxRelay, OutboxRelayConfig};
+//! use sea_orm::TransactionTrait;
+//!
+//! let txn = db.begin().await?;
+//! // ... domain writes on `txn` ...
+//! outbox::enqueue(&txn, "jobs.email", &EmailJob::new(...)).await?;
+//! txn.commit().await?;
+//!
+//! // Elsewhere, publish pending rows to NATS JetStream
+//! let relay = OutboxRelay::new(db, jetstream, OutboxRelayConfig::default());
+//! relay.run(shutdown_rx).await?;
+//! ```
+
+mod relay;
+
+pub use relay::{OutboxRelay, OutboxRelayConfig};
+
+use crate::Job;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DbBackend, FromQueryResult, Statement};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Error that can occur in outbox operations.
+#[derive(Debug, Error)]
+pub enum OutboxError {
+    /// Database error
+    #[error("Database error: {0}")]
+    Database(#[from] sea_orm::DbErr),
+
+    /// Serialization error
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// Publish error
+    #[error("Publish error: {0}")]
+    Publish(String),
+}
+
+/// A job waiting in the outbox.
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    /// Where to publish (e.g. a NATS subject)
+    pub destination: String,
+    /// Serialized job
+    pub payload: serde_json::Value,
+    /// Failed publish attempts so far
+    pub attempts: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Backend the relay publishes outbox messages to.
+#[async_trait]
+pub trait OutboxPublisher: Send + Sync {
+    /// Publish a payload, returning once the backend has accepted it.
+    async fn publish(&self, destination: &str, payload: Vec<u8>) -> Result<(), OutboxError>;
+}
+
+#[cfg(feature = "nats")]
+#[async_trait]
+impl OutboxPublisher for async_nats::jetstream::Context {
+    async fn publish(&self, destination: &str, payload: Vec<u8>) -> Result<(), OutboxError> {
+        async_nats::jetstream::Context::publish(self, destination.to_string(), payload.into())
+            .await
+            .map_err(|e| OutboxError::Publish(e.to_string()))?
+            .await
+            .map_err(|e| OutboxError::Publish(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// Write a job into the outbox.
+///
+/// Pass the transaction holding the domain change so both commit atomically.
+/// Returns the outbox row ID.
+pub async fn enqueue<C, J>(conn: &C, destination: &str, job: &J) -> Result<Uuid, OutboxError>
+where
+    C: ConnectionTrait,
+    J: Job,
+{
+    let payload = serde_json::to_value(job)?;
+
+    let stmt = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "INSERT INTO outbox (destination, payload) VALUES ($1, $2) RETURNING id",
+        [destination.into(), payload.into()],
+    );
+
+    #[derive(FromQueryResult)]
+    struct Inserted {
+        id: Uuid,
+    }
+
+    let row = Inserted::find_by_statement(stmt)
+        .one(conn)
+        .await?
+        .ok_or_else(|| sea_orm::DbErr::RecordNotInserted)?;
+
+    Ok(row.id)
+}
+
+/// Number of outbox rows still waiting to be published.
+///
+/// Rows that used up their publish attempts are not counted; see
+/// [`failed_count`].
+pub async fn pending_count<C: ConnectionTrait>(conn: &C) -> Result<u64, OutboxError> {
+    count(
+        conn,
+        "SELECT COUNT(*) AS count FROM outbox WHERE published_at IS NULL AND failed_at IS NULL",
+    )
+    .await
+}
+
+/// Number of outbox rows the relay gave up on after `max_attempts`.
+pub async fn failed_count<C: ConnectionTrait>(conn: &C) -> Result<u64, OutboxError> {
+    count(
+        conn,
+        "SELECT COUNT(*) AS count FROM outbox WHERE failed_at IS NOT NULL",
+    )
+    .await
+}
+
+async fn count<C: ConnectionTrait>(conn: &C, sql: &str) -> Result<u64, OutboxError> {
+    let stmt = Statement::from_sql_and_values(DbBackend::Postgres, sql, []);
+
+    #[derive(FromQueryResult)]
+    struct CountResult {
+        count: i64,
+    }
+
+    let result = CountResult::find_by_statement(stmt).one(conn).await?;
+    Ok(result.map(|r| r.count as u64).unwrap_or(0))
+}
@@ -0,0 +1,271 @@
+//! Relay that publishes pending outbox rows.
+
+use crate::outbox::{OutboxError, OutboxMessage, OutboxPublisher};
+use crate::BackoffStrategy;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, FromQueryResult, Statement, Value};
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+/// Configuration for the outbox relay.
+#[derive(Debug, Clone)]
+pub struct OutboxRelayConfig {
+    /// Maximum rows published per batch
+    pub batch_size: u64,
+    /// Delay between polls when the outbox is empty
+    pub poll_interval: Duration,
+    /// Publish attempts before a row is marked failed and no longer relayed
+    pub max_attempts: i32,
+    /// Delay before retrying a row whose publish failed
+    pub retry_backoff: BackoffStrategy,
+    /// How long claimed rows are held while their batch is published
+    pub lease_duration: Duration,
+}
+
+impl Default for OutboxRelayConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            poll_interval: Duration::from_millis(500),
+            max_attempts: 10,
+            retry_backoff: BackoffStrategy::Exponential {
+                base_ms: 1_000,
+                max_ms: 300_000,
+            },
+            lease_duration: Duration::from_secs(60),
+        }
+    }
+}
+
+impl OutboxRelayConfig {
+    /// Set the batch size.
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the poll interval.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the publish attempts before a row is marked failed.
+    pub fn with_max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the retry backoff for failed publishes.
+    pub fn with_retry_backoff(mut self, backoff: BackoffStrategy) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Set how long claimed rows are held while their batch is published.
+    pub fn with_lease_duration(mut self, duration: Duration) -> Self {
+        self.lease_duration = duration;
+        self
+    }
+}
+
+/// Publishes unpublished outbox rows and marks them sent.
+///
+/// Each batch first leases its rows by setting `locked_until` in a single
+/// statement, then publishes them without holding a transaction or row locks.
+/// Leased rows are skipped by other relays, so several relays can run against
+/// the same table without publishing a row twice concurrently. Rows of a
+/// relay that dies mid-batch are picked up again once their lease expires.
+pub struct OutboxRelay<P> {
+    db: DatabaseConnection,
+    publisher: P,
+    config: OutboxRelayConfig,
+}
+
+impl<P: OutboxPublisher> OutboxRelay<P> {
+    /// Create a new relay.
+    pub fn new(db: DatabaseConnection, publisher: P, config: OutboxRelayConfig) -> Self {
+        Self {
+            db,
+            publisher,
+            config,
+        }
+    }
+
+    /// Run the relay loop until shutdown is signalled.
+    ///
+    /// A batch in progress is finished before stopping.
+    pub async fn run(&self, mut shutdown_rx: watch::Receiver<bool>) -> Result<(), OutboxError> {
+        info!(batch_size = self.config.batch_size, "Starting outbox relay");
+
+        loop {
+            if *shutdown_rx.borrow() {
+                break;
+            }
+
+            let delay = match self.relay_once().await {
+                Ok(0) => self.config.poll_interval,
+                Ok(published) => {
+                    debug!(published, "Relayed outbox batch");
+                    continue;
+                }
+                Err(e) => {
+                    error!(error = %e, "Error relaying outbox batch");
+                    self.config.poll_interval
+                }
+            };
+
+            tokio::select! {
+                _ = shutdown_rx.changed() => {}
+                _ = tokio::time::sleep(delay) => {}
+            }
+        }
+
+        info!("Outbox relay stopped");
+        Ok(())
+    }
+
+    /// Publish one batch of pending rows, oldest first.
+    ///
+    /// Rows for the same destination are published in order: after a failed
+    /// publish, the rest of that destination's rows wait for the failed row's
+    /// retry, while other destinations carry on. A row that fails
+    /// `max_attempts` times is marked failed and stops holding its destination
+    /// back. Returns the number of rows published.
+    pub async fn relay_once(&self) -> Result<usize, OutboxError> {
+        let mut messages = self.claim().await?;
+        // RETURNING does not keep the claim order
+        messages.sort_by_key(|message| (message.created_at, message.id));
+
+        let mut blocked: HashSet<&str> = HashSet::new();
+        let mut published = 0;
+        for message in &messages {
+            if blocked.contains(message.destination.as_str()) {
+                self.execute(
+                    "UPDATE outbox SET locked_until = NULL WHERE id = $1",
+                    vec![message.id.into()],
+                )
+                .await?;
+                continue;
+            }
+
+            let payload = serde_json::to_vec(&message.payload)?;
+            match self.publisher.publish(&message.destination, payload).await {
+                Ok(()) => {
+                    self.execute(
+                        "UPDATE outbox SET published_at = now(), locked_until = NULL WHERE id = $1",
+                        vec![message.id.into()],
+                    )
+                    .await?;
+                    published += 1;
+                }
+                Err(e) => {
+                    if self.record_failure(message, &e).await? {
+                        blocked.insert(message.destination.as_str());
+                    }
+                }
+            }
+        }
+
+        Ok(published)
+    }
+
+    /// Lease the next batch of pending rows.
+    ///
+    /// Rows behind a leased row of the same destination are left alone, so a
+    /// row waiting to be retried is not overtaken.
+    async fn claim(&self) -> Result<Vec<OutboxMessage>, OutboxError> {
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            UPDATE outbox
+            SET locked_until = now() + make_interval(secs => $2)
+            WHERE id IN (
+                SELECT id
+                FROM outbox o
+                WHERE o.published_at IS NULL
+                  AND o.failed_at IS NULL
+                  AND (o.locked_until IS NULL OR o.locked_until <= now())
+                  AND NOT EXISTS (
+                      SELECT 1
+                      FROM outbox earlier
+                      WHERE earlier.destination = o.destination
+                        AND earlier.published_at IS NULL
+                        AND earlier.failed_at IS NULL
+                        AND earlier.locked_until > now()
+                        AND (earlier.created_at, earlier.id) < (o.created_at, o.id)
+                  )
+                ORDER BY o.created_at, o.id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, destination, payload, attempts, created_at
+            "#,
+            [
+                (self.config.batch_size as i64).into(),
+                self.config.lease_duration.as_secs_f64().into(),
+            ],
+        );
+
+        Ok(OutboxMessage::find_by_statement(stmt).all(&self.db).await?)
+    }
+
+    /// Record a failed publish and schedule its retry.
+    ///
+    /// Returns whether the row will be retried; once it has used up
+    /// `max_attempts` it is marked failed instead.
+    async fn record_failure(
+        &self,
+        message: &OutboxMessage,
+        error: &OutboxError,
+    ) -> Result<bool, OutboxError> {
+        let attempts = message.attempts + 1;
+
+        if attempts >= self.config.max_attempts {
+            error!(
+                outbox_id = %message.id,
+                destination = %message.destination,
+                attempts,
+                error = %error,
+                "Outbox message failed permanently"
+            );
+
+            self.execute(
+                "UPDATE outbox SET attempts = attempts + 1, last_error = $2, \
+                 locked_until = NULL, failed_at = now() WHERE id = $1",
+                vec![message.id.into(), error.to_string().into()],
+            )
+            .await?;
+            return Ok(false);
+        }
+
+        let retry_in = self.config.retry_backoff.delay(message.attempts as u32);
+        warn!(
+            outbox_id = %message.id,
+            destination = %message.destination,
+            attempts,
+            retry_in_ms = retry_in.as_millis() as u64,
+            error = %error,
+            "Failed to publish outbox message"
+        );
+
+        self.execute(
+            "UPDATE outbox SET attempts = attempts + 1, last_error = $2, \
+             locked_until = now() + make_interval(secs => $3) WHERE id = $1",
+            vec![
+                message.id.into(),
+                error.to_string().into(),
+                retry_in.as_secs_f64().into(),
+            ],
+        )
+        .await?;
+        Ok(true)
+    }
+
+    async fn execute(&self, sql: &str, values: Vec<Value>) -> Result<(), OutboxError> {
+        let stmt = Statement::from_sql_and_values(DbBackend::Postgres, sql, values);
+        self.db.execute_raw(stmt).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+//! Integration tests for the NATS worker
+//!
+//! These tests use real NATS JetStream via testcontainers (requires Docker):
+//! - Job counters track successes, retries and dead letters
+//! - With the default config, exhausted transient retries reach the DLQ
+//! - A worker consuming several streams delivers jobs from each to the processor
+//! - Priority-enabled streams deliver higher priorities first
+//! - With idempotency enabled, a job seen before is skipped and counted
+
+#![cfg(feature = "nats")]
+
+use async_trait::async_trait;
+use messaging::nats::{NatsProducer, NatsWorker, WorkerConfig};
+use messaging::{Job, JobPriority, ProcessingError, Processor};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use test_utils::TestNats;
+use tokio::sync::watch;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TestJob {
+    id: String,
+    retry_count: u32,
+    priority: JobPriority,
+}
+
+impl TestJob {
+    fn new(id: &str) -> Self {
+        Self::with_priority(id, JobPriority::Normal)
+    }
+
+    fn with_priority(id: &str, priority: JobPriority) -> Self {
+        Self {
+            id: id.to_string(),
+            retry_count: 0,
+            priority,
+        }
+    }
+}
+
+impl Job for TestJob {
+    fn job_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn retry_count(&self) -> u32 {
+        self.retry_count
+    }
+
+    fn with_retry(&self) -> Self {
+        Self {
+            retry_count: self.retry_count + 1,
+            ..self.clone()
+        }
+    }
+
+    fn priority(&self) -> JobPriority {
+        self.priority
+    }
+}
+
+/// Processor that records the ids of the jobs it handled
+#[derive(Clone, Default)]
+struct RecordingProcessor {
+    seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingProcessor {
+    fn seen(&self) -> Vec<String> {
+        self.seen.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Processor<TestJob> for RecordingProcessor {
+    async fn process(&self, job: &TestJob) -> Result<(), ProcessingError> {
+        self.seen.lock().unwrap().push(job.id.clone());
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "recording_processor"
+    }
+}
+
+fn stream_config(stream: &str, subject: &str) -> WorkerConfig {
+    let mut config = WorkerConfig::new(stream)
+        .with_consumer_name("test-worker")
+        .with_durable_name(format!("{}-test-worker", stream.to_lowercase()))
+        .with_fetch_timeout(Duration::from_millis(200));
+    config.subject = subject.to_string();
+    config
+}
+
+/// Run the worker until the processor has seen `count` jobs, then shut it down
+async fn run_until_processed(
+    worker: NatsWorker<TestJob, RecordingProcessor>,
+    processor: &RecordingProcessor,
+    count: usize,
+) -> Arc<NatsWorker<TestJob, RecordingProcessor>> {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let worker = Arc::new(worker);
+    let handle = tokio::spawn({
+        let worker = worker.clone();
+        async move { worker.run(shutdown_rx).await }
+    });
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while processor.seen().len() < count {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("all published jobs should be processed");
+
+    shutdown_tx.send(true).unwrap();
+    handle.await.unwrap().unwrap();
+    worker
+}
+
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_multi_stream_worker_processes_jobs_from_each_stream() {
+    let nats = TestNats::new().await;
+    let processor = RecordingProcessor::default();
+
+    let worker = NatsWorker::multi(
+        nats.jetstream(),
+        processor.clone(),
+        vec![
+            stream_config("EMAILS", "emails.>"),
+            stream_config("EMAILS_PRIORITY", "emails_priority.>"),
+        ],
+    )
+    .await
+    .unwrap();
+    assert_eq!(worker.stream_names(), vec!["EMAILS", "EMAILS_PRIORITY"]);
+
+    NatsProducer::new(nats.jetstream(), "EMAILS", "emails.send")
+        .send(&TestJob::new("regular"))
+        .await
+        .unwrap();
+    NatsProducer::new(nats.jetstream(), "EMAILS_PRIORITY", "emails_priority.send")
+        .send(&TestJob::new("priority"))
+        .await
+        .unwrap();
+
+    let worker = run_until_processed(worker, &processor, 2).await;
+
+    let mut seen = processor.seen();
+    seen.sort();
+    assert_eq!(seen, vec!["priority", "regular"]);
+    assert_eq!(worker.stats().snapshot().succeeded, 2);
+}
+
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_priority_stream_delivers_higher_priority_first() {
+    let nats = TestNats::new().await;
+    let processor = RecordingProcessor::default();
+
+    // Batch size 1 so each fetch picks the most important pending job
+    let config = stream_config("TASKS", "tasks.>")
+        .with_priority(true)
+        .with_batch_size(1);
+    let worker = NatsWorker::new(nats.jetstream(), processor.clone(), config)
+        .await
+        .unwrap();
+
+    let producer = NatsProducer::new(nats.jetstream(), "TASKS", "tasks.>").with_priority(true);
+    let jobs = [
+        TestJob::with_priority("low", JobPriority::Low),
+        TestJob::with_priority("normal", JobPriority::Normal),
+        TestJob::with_priority("critical", JobPriority::Critical),
+    ];
+    assert_eq!(producer.subject_for(&jobs[0]), "tasks.low");
+    assert_eq!(producer.subject_for(&jobs[2]), "tasks.critical");
+    for job in &jobs {
+        producer.send(job).await.unwrap();
+    }
+
+    // Without priority the configured subject is used as-is
+    let plain = NatsProducer::new(nats.jetstream(), "TASKS", "tasks.submit");
+    assert_eq!(plain.subject_for(&jobs[2]), "tasks.submit");
+
+    run_until_processed(worker, &processor, 3).await;
+
+    assert_eq!(processor.seen(), vec!["critical", "normal", "low"]);
+}
+
+/// Processor whose outcome is picked by the job id
+struct ScriptedProcessor;
+
+#[async_trait]
+impl Processor<TestJob> for ScriptedProcessor {
+    async fn process(&self, job: &TestJob) -> Result<(), ProcessingError> {
+        match job.id.as_str() {
+            "poison" => Err(ProcessingError::permanent("bad payload")),
+            "flaky" => Err(ProcessingError::transient("upstream unavailable")),
+            _ => Ok(()),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "scripted_processor"
+    }
+}
+
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_stats_count_each_outcome_once() {
+    let nats = TestNats::new().await;
+
+    let worker = NatsWorker::new(
+        nats.jetstream(),
+        ScriptedProcessor,
+        stream_config("STATS", "stats.>"),
+    )
+    .await
+    .unwrap();
+
+    // "flaky" arrives with its retries already used up, so the transient
+    // error takes the max-retries path to the DLQ
+    let mut exhausted = TestJob::new("flaky");
+    for _ in 0..3 {
+        exhausted = exhausted.with_retry();
+    }
+
+    let producer = NatsProducer::new(nats.jetstream(), "STATS", "stats.jobs");
+    for job in [TestJob::new("ok"), TestJob::new("poison"), exhausted] {
+        producer.send(&job).await.unwrap();
+    }
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let worker = Arc::new(worker);
+    let handle = tokio::spawn({
+        let worker = worker.clone();
+        async move { worker.run(shutdown_rx).await }
+    });
+
+    let stats = worker.stats();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let snapshot = stats.snapshot();
+            if snapshot.succeeded + snapshot.dead_lettered >= 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("all published jobs should be settled");
+
+    shutdown_tx.send(true).unwrap();
+    handle.await.unwrap().unwrap();
+
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.received, 3);
+    assert_eq!(snapshot.processed, 3);
+    assert_eq!(snapshot.succeeded, 1);
+    assert_eq!(snapshot.failed, 2);
+    assert_eq!(snapshot.retried, 0);
+    assert_eq!(snapshot.dead_lettered, 2);
+}
+
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_default_config_dead_letters_exhausted_transient_job() {
+    let nats = TestNats::new().await;
+
+    // Default max_deliver and retry policy: 3 transient retries, then the
+    // 4th delivery must still arrive to move the job to the DLQ
+    let worker = NatsWorker::new(
+        nats.jetstream(),
+        ScriptedProcessor,
+        stream_config("RETRIES", "retries.>"),
+    )
+    .await
+    .unwrap();
+
+    NatsProducer::new(nats.jetstream(), "RETRIES", "retries.jobs")
+        .send(&TestJob::new("flaky"))
+        .await
+        .unwrap();
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let worker = Arc::new(worker);
+    let handle = tokio::spawn({
+        let worker = worker.clone();
+        async move { worker.run(shutdown_rx).await }
+    });
+
+    // Backoff between the retries adds up to about 7 seconds
+    let stats = worker.stats();
+    tokio::time::timeout(Duration::from_secs(30), async {
+        while stats.snapshot().dead_lettered < 1 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("the exhausted job should reach the DLQ");
+
+    shutdown_tx.send(true).unwrap();
+    handle.await.unwrap().unwrap();
+
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.failed, 4);
+    assert_eq!(snapshot.retried, 3);
+    assert_eq!(snapshot.dead_lettered, 1);
+    assert_eq!(worker.dlq_infos().await.unwrap()[0].messages, 1);
+}
+
+#[tokio::test]
+#[ignore] // Requires Docker
+async fn test_duplicate_job_is_skipped_and_counted() {
+    let nats = TestNats::new().await;
+    let processor = RecordingProcessor::default();
+
+    let worker = NatsWorker::new(
+        nats.jetstream(),
+        processor.clone(),
+        stream_config("DEDUP", "dedup.>")
+            .with_idempotency("dedup_processed", Duration::from_secs(3600)),
+    )
+    .await
+    .unwrap();
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let worker = Arc::new(worker);
+    let handle = tokio::spawn({
+        let worker = worker.clone();
+        async move { worker.run(shutdown_rx).await }
+    });
+
+    let producer = NatsProducer::new(nats.jetstream(), "DEDUP", "dedup.jobs");
+    let stats = worker.stats();
+
+    // Publish the second copy only once the first is recorded
+    producer.send(&TestJob::new("once")).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while stats.snapshot().succeeded < 1 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("the first copy should be processed");
+
+    producer.send(&TestJob::new("once")).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while stats.snapshot().duplicates_skipped < 1 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("the second copy should be skipped");
+
+    shutdown_tx.send(true).unwrap();
+    handle.await.unwrap().unwrap();
+
+    assert_eq!(processor.seen(), vec!["once"]);
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.received, 2);
+    assert_eq!(snapshot.processed, 1);
+    assert_eq!(snapshot.duplicates_skipped, 1);
+}
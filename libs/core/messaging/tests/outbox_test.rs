@@ -0,0 +1,327 @@
+//! Integration tests for the transactional outbox
+//!
+//! These tests use real PostgreSQL via testcontainers to ensure:
+//! - Outbox rows commit and roll back with the surrounding transaction
+//! - Rows written before a crash are published after restart
+//! - Failed publishes are recorded and retried
+//! - A row that keeps failing is marked failed instead of blocking the outbox
+//! - A failing destination does not hold back other destinations
+
+#![cfg(feature = "outbox")]
+
+use async_trait::async_trait;
+use messaging::outbox::{self, OutboxError, OutboxPublisher, OutboxRelay, OutboxRelayConfig};
+use messaging::{BackoffStrategy, Job};
+use sea_orm::{DbBackend, FromQueryResult, Statement, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use test_utils::TestDatabase;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TestJob {
+    id: String,
+    retry_count: u32,
+}
+
+impl TestJob {
+    fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            retry_count: 0,
+        }
+    }
+}
+
+impl Job for TestJob {
+    fn job_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn retry_count(&self) -> u32 {
+        self.retry_count
+    }
+
+    fn with_retry(&self) -> Self {
+        Self {
+            retry_count: self.retry_count + 1,
+            ..self.clone()
+        }
+    }
+}
+
+/// Publisher that records what it sent and can be switched to fail, either
+/// entirely or for specific jobs
+#[derive(Clone, Default)]
+struct FakePublisher {
+    sent: Arc<Mutex<Vec<(String, TestJob)>>>,
+    failing: Arc<AtomicBool>,
+    poison: Arc<Mutex<HashSet<String>>>,
+}
+
+impl FakePublisher {
+    fn sent(&self) -> Vec<(String, TestJob)> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+
+    fn poison(&self, job_id: &str) {
+        self.poison.lock().unwrap().insert(job_id.to_string());
+    }
+}
+
+#[async_trait]
+impl OutboxPublisher for FakePublisher {
+    async fn publish(&self, destination: &str, payload: Vec<u8>) -> Result<(), OutboxError> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(OutboxError::Publish("broker unavailable".to_string()));
+        }
+
+        let job: TestJob = serde_json::from_slice(&payload)?;
+        if self.poison.lock().unwrap().contains(&job.id) {
+            return Err(OutboxError::Publish(format!("rejected {}", job.id)));
+        }
+
+        self.sent
+            .lock()
+            .unwrap()
+            .push((destination.to_string(), job));
+        Ok(())
+    }
+}
+
+/// Relay with a near-zero retry backoff, so failed rows are retried by the
+/// next batch
+fn relay(db: &TestDatabase, publisher: FakePublisher) -> OutboxRelay<FakePublisher> {
+    relay_with(db, publisher, test_config())
+}
+
+fn relay_with(
+    db: &TestDatabase,
+    publisher: FakePublisher,
+    config: OutboxRelayConfig,
+) -> OutboxRelay<FakePublisher> {
+    OutboxRelay::new(db.connection(), publisher, config)
+}
+
+fn test_config() -> OutboxRelayConfig {
+    OutboxRelayConfig::default().with_retry_backoff(BackoffStrategy::Fixed { delay_ms: 1 })
+}
+
+/// Let a failed row's retry backoff elapse
+async fn wait_for_retry() {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+}
+
+#[derive(Debug, FromQueryResult)]
+struct OutboxRow {
+    attempts: i32,
+    last_error: Option<String>,
+    failed: bool,
+}
+
+async fn outbox_row(db: &TestDatabase, id: uuid::Uuid) -> OutboxRow {
+    let stmt = Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT attempts, last_error, failed_at IS NOT NULL AS failed FROM outbox WHERE id = $1",
+        [id.into()],
+    );
+    OutboxRow::find_by_statement(stmt)
+        .one(&db.connection())
+        .await
+        .unwrap()
+        .expect("outbox row should exist")
+}
+
+#[tokio::test]
+async fn test_committed_jobs_are_relayed() {
+    let db = TestDatabase::new().await;
+    let conn = db.connection();
+
+    let txn = conn.begin().await.unwrap();
+    outbox::enqueue(&txn, "jobs.email", &TestJob::new("a"))
+        .await
+        .unwrap();
+    outbox::enqueue(&txn, "jobs.email", &TestJob::new("b"))
+        .await
+        .unwrap();
+    txn.commit().await.unwrap();
+
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 2);
+
+    let publisher = FakePublisher::default();
+    let published = relay(&db, publisher.clone()).relay_once().await.unwrap();
+
+    assert_eq!(published, 2);
+    assert_eq!(
+        publisher.sent(),
+        vec![
+            ("jobs.email".to_string(), TestJob::new("a")),
+            ("jobs.email".to_string(), TestJob::new("b")),
+        ]
+    );
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 0);
+
+    // Already published rows are not sent again
+    let published = relay(&db, publisher.clone()).relay_once().await.unwrap();
+    assert_eq!(published, 0);
+    assert_eq!(publisher.sent().len(), 2);
+}
+
+#[tokio::test]
+async fn test_rolled_back_jobs_are_discarded() {
+    let db = TestDatabase::new().await;
+    let conn = db.connection();
+
+    let txn = conn.begin().await.unwrap();
+    outbox::enqueue(&txn, "jobs.email", &TestJob::new("a"))
+        .await
+        .unwrap();
+    txn.rollback().await.unwrap();
+
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 0);
+
+    let publisher = FakePublisher::default();
+    relay(&db, publisher.clone()).relay_once().await.unwrap();
+    assert!(publisher.sent().is_empty());
+}
+
+#[tokio::test]
+async fn test_crash_between_write_and_publish() {
+    let db = TestDatabase::new().await;
+
+    // The process commits the domain change and its job, then dies before
+    // any relay publishes it.
+    {
+        let conn = db.connection();
+        let txn = conn.begin().await.unwrap();
+        outbox::enqueue(&txn, "jobs.email", &TestJob::new("a"))
+            .await
+            .unwrap();
+        txn.commit().await.unwrap();
+    }
+
+    // After restart, a fresh relay picks the job up.
+    let publisher = FakePublisher::default();
+    let published = relay(&db, publisher.clone()).relay_once().await.unwrap();
+
+    assert_eq!(published, 1);
+    assert_eq!(
+        publisher.sent(),
+        vec![("jobs.email".to_string(), TestJob::new("a"))]
+    );
+    assert_eq!(outbox::pending_count(&db.connection()).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_failed_publish_is_retried() {
+    let db = TestDatabase::new().await;
+    let conn = db.connection();
+
+    let id = outbox::enqueue(&conn, "jobs.email", &TestJob::new("a"))
+        .await
+        .unwrap();
+
+    let publisher = FakePublisher::default();
+    publisher.set_failing(true);
+
+    let published = relay(&db, publisher.clone()).relay_once().await.unwrap();
+    assert_eq!(published, 0);
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 1);
+
+    let row = outbox_row(&db, id).await;
+    assert_eq!(row.attempts, 1);
+    assert!(row.last_error.unwrap().contains("broker unavailable"));
+    assert!(!row.failed);
+
+    publisher.set_failing(false);
+    wait_for_retry().await;
+    let published = relay(&db, publisher.clone()).relay_once().await.unwrap();
+
+    assert_eq!(published, 1);
+    assert_eq!(
+        publisher.sent(),
+        vec![("jobs.email".to_string(), TestJob::new("a"))]
+    );
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_poison_row_is_failed_after_max_attempts() {
+    let db = TestDatabase::new().await;
+    let conn = db.connection();
+
+    let poison_id = outbox::enqueue(&conn, "jobs.email", &TestJob::new("poison"))
+        .await
+        .unwrap();
+    outbox::enqueue(&conn, "jobs.email", &TestJob::new("b"))
+        .await
+        .unwrap();
+
+    let publisher = FakePublisher::default();
+    publisher.poison("poison");
+    let relay = relay_with(&db, publisher.clone(), test_config().with_max_attempts(3));
+
+    // The later row waits behind the failing one while it is being retried
+    for _ in 0..2 {
+        assert_eq!(relay.relay_once().await.unwrap(), 0);
+        wait_for_retry().await;
+    }
+    assert!(publisher.sent().is_empty());
+
+    // The last attempt gives up on the poison row and releases the next one
+    assert_eq!(relay.relay_once().await.unwrap(), 1);
+    assert_eq!(
+        publisher.sent(),
+        vec![("jobs.email".to_string(), TestJob::new("b"))]
+    );
+
+    let row = outbox_row(&db, poison_id).await;
+    assert_eq!(row.attempts, 3);
+    assert!(row.failed);
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 0);
+    assert_eq!(outbox::failed_count(&conn).await.unwrap(), 1);
+
+    // Failed rows are not relayed again
+    wait_for_retry().await;
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+    assert_eq!(outbox_row(&db, poison_id).await.attempts, 3);
+}
+
+#[tokio::test]
+async fn test_failing_destination_does_not_block_others() {
+    let db = TestDatabase::new().await;
+    let conn = db.connection();
+
+    outbox::enqueue(&conn, "jobs.email", &TestJob::new("a"))
+        .await
+        .unwrap();
+    outbox::enqueue(&conn, "jobs.sms", &TestJob::new("b"))
+        .await
+        .unwrap();
+    outbox::enqueue(&conn, "jobs.email", &TestJob::new("c"))
+        .await
+        .unwrap();
+
+    let publisher = FakePublisher::default();
+    publisher.poison("a");
+    let config = OutboxRelayConfig::default()
+        .with_retry_backoff(BackoffStrategy::Fixed { delay_ms: 60_000 });
+    let relay = relay_with(&db, publisher.clone(), config);
+
+    assert_eq!(relay.relay_once().await.unwrap(), 1);
+    assert_eq!(
+        publisher.sent(),
+        vec![("jobs.sms".to_string(), TestJob::new("b"))]
+    );
+
+    // "c" stays behind "a" until its retry, so email order is kept
+    assert_eq!(relay.relay_once().await.unwrap(), 0);
+    assert_eq!(publisher.sent().len(), 1);
+    assert_eq!(outbox::pending_count(&conn).await.unwrap(), 2);
+}
@@ -20,6 +20,7 @@
 //! // Auto-generated constants:
 //! assert_eq!(User::COLLECTION, "users");
 //! assert_eq!(User::URL, "/user");
+//! assert_eq!(User::URL_WITH_ID, "/user/{id}");
 //! assert_eq!(User::TAG, "Users");
 //! ```
 //!
@@ -61,6 +62,8 @@ struct ApiResourceInput {
     url: Option<String>,
     #[darling(default)]
     tag: Option<String>,
+    #[darling(default)]
+    id_param: Option<String>,
 }
 
 /// Derives the `ApiResource` trait implementation with automatic defaults.
@@ -73,10 +76,12 @@ struct ApiResourceInput {
 /// - `collection`: Override the default pluralized collection name (default: pluralized struct name)
 /// - `url`: Override the default URL path (default: `/lowercase_struct_name`)
 /// - `tag`: Override the default API tag (default: capitalized collection name)
+/// - `id_param`: Name of the path parameter in `URL_WITH_ID` (default: `id`)
 ///
 /// # Generated Constants
 ///
 /// - `URL`: The base URL path for this resource
+/// - `URL_WITH_ID`: The URL path for a single resource (e.g., "/product/{id}")
 /// - `COLLECTION`: The database collection or table name
 /// - `TAG`: The API documentation tag
 ///
@@ -167,6 +172,9 @@ fn impl_api_resource(receiver: ApiResourceInput) -> proc_macro2::TokenStream {
 
     let url = receiver.url.unwrap_or_else(|| format!("/{}", name));
 
+    let id_param = receiver.id_param.as_deref().unwrap_or("id");
+    let url_with_id = format!("{}/{{{}}}", url, id_param);
+
     let tag = receiver
         .tag
         .unwrap_or_else(|| capitalize_first_letter(&collection));
@@ -174,6 +182,7 @@ fn impl_api_resource(receiver: ApiResourceInput) -> proc_macro2::TokenStream {
     quote! {
         impl core_proc_macros::ApiResource for #ident {
             const URL: &'static str = #url;
+            const URL_WITH_ID: &'static str = #url_with_id;
             const COLLECTION: &'static str = #collection;
             const TAG: &'static str = #tag;
         }
@@ -26,6 +26,7 @@ pub struct Story {
 fn test_basic_user() {
     assert_eq!(User::COLLECTION, "users");
     assert_eq!(User::URL, "/user");
+    assert_eq!(User::URL_WITH_ID, "/user/{id}");
     assert_eq!(User::TAG, "Users");
 }
 
@@ -33,6 +34,7 @@ fn test_basic_user() {
 fn test_custom_attributes() {
     assert_eq!(Person::COLLECTION, "people");
     assert_eq!(Person::URL, "/api/users");
+    assert_eq!(Person::URL_WITH_ID, "/api/users/{id}");
     assert_eq!(Person::TAG, "User Management");
 }
 
@@ -24,6 +24,7 @@
 //! // - Snake_case is converted to Title Case for tags
 //! assert_eq!(Model::COLLECTION, "projects");
 //! assert_eq!(Model::URL, "/projects");
+//! assert_eq!(Model::URL_WITH_ID, "/projects/{id}");
 //! assert_eq!(Model::TAG, "Projects");
 //! ```
 //!
@@ -39,6 +40,7 @@
 //!
 //! assert_eq!(Model::COLLECTION, "cloud_resources");
 //! assert_eq!(Model::URL, "/cloud-resources");  // Hyphen for URL
+//! assert_eq!(Model::URL_WITH_ID, "/cloud-resources/{id}");
 //! assert_eq!(Model::TAG, "Cloud Resources");  // Title Case
 //! ```
 //!
@@ -82,6 +84,8 @@ struct SeaOrmResourceInput {
     url: Option<String>,
     #[darling(default)]
     tag: Option<String>,
+    #[darling(default)]
+    id_param: Option<String>,
 }
 
 /// Derives the `ApiResource` trait implementation for sea-orm entities.
@@ -94,10 +98,12 @@ struct SeaOrmResourceInput {
 /// - `collection`: Override the collection name (default: table_name from sea_orm)
 /// - `url`: Override the default URL path (default: `/table_name`)
 /// - `tag`: Override the default API tag (default: capitalized table_name)
+/// - `id_param`: Name of the path parameter in `URL_WITH_ID` (default: `id`)
 ///
 /// # Generated Constants
 ///
 /// - `URL`: The base URL path for this resource (plural, e.g., "/projects")
+/// - `URL_WITH_ID`: The URL path for a single resource (e.g., "/projects/{id}")
 /// - `COLLECTION`: The database collection or table name
 /// - `TAG`: The API documentation tag
 ///
@@ -211,6 +217,9 @@ fn impl_sea_orm_resource(receiver: SeaOrmResourceInput) -> syn::Result<proc_macr
         .url
         .unwrap_or_else(|| format!("/{}", underscores_to_hyphens(&table_name)));
 
+    let id_param = receiver.id_param.as_deref().unwrap_or("id");
+    let url_with_id = format!("{}/{{{}}}", url, id_param);
+
     // Convert snake_case to Title Case for better API documentation
     let tag = receiver
         .tag
@@ -219,6 +228,7 @@ fn impl_sea_orm_resource(receiver: SeaOrmResourceInput) -> syn::Result<proc_macr
     Ok(quote! {
         impl core_proc_macros::ApiResource for #ident {
             const URL: &'static str = #url;
+            const URL_WITH_ID: &'static str = #url_with_id;
             const COLLECTION: &'static str = #collection;
             const TAG: &'static str = #tag;
         }
@@ -340,6 +350,43 @@ mod tests {
         assert!(output_str.contains(r#"const TAG : & 'static str = "Project Catalog""#));
     }
 
+    #[test]
+    fn test_url_with_id() {
+        let input = quote! {
+            #[sea_orm(table_name = "projects")]
+            pub struct Model {
+                id: String,
+            }
+        };
+
+        let ast: DeriveInput = syn::parse2(input).unwrap();
+        let receiver = SeaOrmResourceInput::from_derive_input(&ast).unwrap();
+        let output = impl_sea_orm_resource(receiver).unwrap();
+        let output_str = output.to_string();
+
+        assert!(output_str.contains(r#"const URL_WITH_ID : & 'static str = "/projects/{id}""#));
+    }
+
+    #[test]
+    fn test_custom_id_param() {
+        let input = quote! {
+            #[sea_orm(table_name = "projects")]
+            #[sea_orm_resource(url = "/v1/projects", id_param = "uuid")]
+            pub struct Model {
+                id: String,
+            }
+        };
+
+        let ast: DeriveInput = syn::parse2(input).unwrap();
+        let receiver = SeaOrmResourceInput::from_derive_input(&ast).unwrap();
+        let output = impl_sea_orm_resource(receiver).unwrap();
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains(r#"const URL_WITH_ID : & 'static str = "/v1/projects/{uuid}""#)
+        );
+    }
+
     #[test]
     fn test_missing_table_name() {
         let input = quote! {
@@ -398,6 +445,9 @@ mod tests {
 
         assert!(output_str.contains(r#"const COLLECTION : & 'static str = "cloud_resources""#));
         assert!(output_str.contains(r#"const URL : & 'static str = "/cloud-resources""#));
+        assert!(
+            output_str.contains(r#"const URL_WITH_ID : & 'static str = "/cloud-resources/{id}""#)
+        );
         assert!(output_str.contains(r#"const TAG : & 'static str = "Cloud Resources""#));
     }
 }
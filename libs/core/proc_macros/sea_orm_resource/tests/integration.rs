@@ -21,6 +21,7 @@ impl ActiveModelBehavior for ActiveModel {}
 #[test]
 fn test_generated_constants() {
     assert_eq!(Model::URL, "/projects");
+    assert_eq!(Model::URL_WITH_ID, "/projects/{id}");
     assert_eq!(Model::COLLECTION, "projects");
     assert_eq!(Model::TAG, "Projects");
 }
@@ -33,7 +34,7 @@ mod custom {
         Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, SeaOrmResource,
     )]
     #[sea_orm(table_name = "users")]
-    #[sea_orm_resource(url = "/v2/users", tag = "User Management")]
+    #[sea_orm_resource(url = "/v2/users", tag = "User Management", id_param = "uuid")]
     pub struct Model {
         #[sea_orm(primary_key)]
         pub id: i32,
@@ -48,6 +49,7 @@ mod custom {
     #[test]
     fn test_custom_url_and_tag() {
         assert_eq!(Model::URL, "/v2/users");
+        assert_eq!(Model::URL_WITH_ID, "/v2/users/{uuid}");
         assert_eq!(Model::COLLECTION, "users");
         assert_eq!(Model::TAG, "User Management");
     }
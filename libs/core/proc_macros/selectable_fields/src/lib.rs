@@ -49,12 +49,28 @@
 //!     internal_notes: String,  // Admin only
 //! }
 //! ```
+//!
+//! Field groups:
+//!
+//! ```ignore
+//! #[derive(SelectableFields)]
+//! pub struct User {
+//!     #[field(group = "summary")]
+//!     id: String,
+//!     #[field(group = "summary")]
+//!     username: String,
+//!     bio: String,
+//! }
+//!
+//! // ?fields=summary selects id and username
+//! ```
 
 extern crate proc_macro;
 
 use darling::{FromDeriveInput, FromField};
 use proc_macro::TokenStream;
 use quote::quote;
+use std::collections::BTreeMap;
 use syn::DeriveInput;
 
 #[derive(FromDeriveInput)]
@@ -77,6 +93,9 @@ struct SelectableField {
     /// Rename the field in the API
     #[darling(default)]
     rename: Option<String>,
+    /// Group name that selects this field along with the rest of the group
+    #[darling(default)]
+    group: Option<String>,
 }
 
 /// Derives the `SelectableFields` trait for dynamic field selection with security.
@@ -89,6 +108,8 @@ struct SelectableField {
 /// - `skip`: Exclude a field from selection (restricted field, never accessible)
 /// - `role`: Minimum role required to access this field ("anonymous", "user", "admin")
 /// - `rename`: Use a different name for the field in API responses
+/// - `group`: Add the field to a named group; requesting the group name selects
+///   all of its fields
 ///
 /// # Generated Trait Implementation
 ///
@@ -96,6 +117,7 @@ struct SelectableField {
 /// - `available_fields()`: Returns all non-skipped field names
 /// - `restricted_fields()`: Returns fields marked with `skip`
 /// - `field_access()`: Returns role requirements for each field
+/// - `field_groups()`: Returns the fields in each group
 ///
 /// # Requirements
 ///
@@ -167,6 +189,7 @@ fn impl_selectable_fields(receiver: SelectableInput) -> proc_macro2::TokenStream
     let mut available_fields = Vec::new();
     let mut restricted_fields = Vec::new();
     let mut field_access_items = Vec::new();
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
 
     for field in fields {
         let field_ident = field.ident.expect("Only named fields are supported");
@@ -179,6 +202,10 @@ fn impl_selectable_fields(receiver: SelectableInput) -> proc_macro2::TokenStream
             // Available field
             available_fields.push(field_name.clone());
 
+            if let Some(group) = field.group {
+                groups.entry(group).or_default().push(field_name.clone());
+            }
+
             // Determine role requirement
             let role = match field.role.as_deref() {
                 Some("user") | Some("User") => quote! { field_selector::UserRole::User },
@@ -201,6 +228,12 @@ fn impl_selectable_fields(receiver: SelectableInput) -> proc_macro2::TokenStream
         }
     }
 
+    let group_items = groups.iter().map(|(group, members)| {
+        quote! {
+            (#group, vec![#(#members),*])
+        }
+    });
+
     quote! {
         impl field_selector::SelectableFields for #ident {
             fn available_fields() -> Vec<&'static str> {
@@ -216,6 +249,12 @@ fn impl_selectable_fields(receiver: SelectableInput) -> proc_macro2::TokenStream
                     #(#field_access_items),*
                 ]
             }
+
+            fn field_groups() -> ::std::collections::HashMap<&'static str, Vec<&'static str>> {
+                ::std::collections::HashMap::from([
+                    #(#group_items),*
+                ])
+            }
         }
     }
 }
@@ -340,6 +379,33 @@ mod tests {
         assert!(output_str.contains("UserRole :: Admin"));
     }
 
+    #[test]
+    fn test_field_groups() {
+        let input = quote! {
+            pub struct User {
+                #[field(group = "summary")]
+                id: String,
+                #[field(group = "summary", rename = "login")]
+                username: String,
+                #[field(group = "contact")]
+                email: String,
+                bio: String,
+                #[field(skip, group = "summary")]
+                password: String,
+            }
+        };
+
+        let ast: DeriveInput = syn::parse2(input).unwrap();
+        let receiver = SelectableInput::from_derive_input(&ast).unwrap();
+        let output = impl_selectable_fields(receiver);
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("fn field_groups"));
+        assert!(output_str.contains(r#"("contact" , vec ! ["email"])"#));
+        // Renamed fields use the API name; skipped fields never join a group
+        assert!(output_str.contains(r#"("summary" , vec ! ["id" , "login"])"#));
+    }
+
     #[test]
     fn test_all_fields_public() {
         let input = quote! {
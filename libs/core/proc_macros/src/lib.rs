@@ -25,11 +25,14 @@ pub use sea_orm_resource::SeaOrmResource;
 /// }
 ///
 /// assert_eq!(User::URL, "/user");
+/// assert_eq!(User::URL_WITH_ID, "/user/{id}");
 /// assert_eq!(User::COLLECTION, "users");
 /// ```
 pub trait ApiResource {
     /// The base URL path for this resource (e.g., "/user")
     const URL: &'static str;
+    /// The URL path for a single resource, with an axum path parameter (e.g., "/user/{id}")
+    const URL_WITH_ID: &'static str;
     /// The database collection or table name (e.g., "users")
     const COLLECTION: &'static str;
     /// The API documentation tag (e.g., "Users")
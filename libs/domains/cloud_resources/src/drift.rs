@@ -0,0 +1,211 @@
+//! Drift detection against a desired-state spec
+//!
+//! IaC tools describe what a resource should look like; drift is any field
+//! where the stored (observed) resource differs. Only fields set on the
+//! [`DesiredState`] are compared, so a spec can pin just the fields it owns.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::{Display, EnumString};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::models::{CloudResource, ResourceStatus, ResourceType, Tag};
+
+/// Desired state of a resource, as declared by IaC
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct DesiredState {
+    pub resource_type: Option<ResourceType>,
+    pub status: Option<ResourceStatus>,
+    /// Compared as a set; order does not matter
+    pub tags: Option<Vec<Tag>>,
+}
+
+/// Field that can drift from its desired value
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DriftField {
+    ResourceType,
+    Status,
+    Tags,
+}
+
+/// A single drifted field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldDrift {
+    pub field: DriftField,
+    /// Desired value
+    pub before: Value,
+    /// Observed value
+    pub after: Value,
+}
+
+/// Drift finding for one resource
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Drift {
+    pub resource_id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub changes: Vec<FieldDrift>,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// Query filters for listing drift findings
+#[derive(Debug, Clone, Default, Deserialize, ToSchema, IntoParams)]
+pub struct DriftFilter {
+    pub project_id: Option<Uuid>,
+}
+
+/// Compare a resource against its desired state, returning `None` if it matches
+pub fn detect_drift(actual: &CloudResource, desired: &DesiredState) -> Option<Drift> {
+    let mut changes = Vec::new();
+
+    if let Some(resource_type) = desired.resource_type
+        && resource_type != actual.resource_type
+    {
+        changes.push(FieldDrift {
+            field: DriftField::ResourceType,
+            before: Value::String(resource_type.to_string()),
+            after: Value::String(actual.resource_type.to_string()),
+        });
+    }
+
+    if let Some(status) = desired.status
+        && status != actual.status
+    {
+        changes.push(FieldDrift {
+            field: DriftField::Status,
+            before: Value::String(status.to_string()),
+            after: Value::String(actual.status.to_string()),
+        });
+    }
+
+    if let Some(tags) = &desired.tags
+        && !same_tags(tags, &actual.tags)
+    {
+        changes.push(FieldDrift {
+            field: DriftField::Tags,
+            before: serde_json::to_value(tags).unwrap_or_default(),
+            after: serde_json::to_value(&actual.tags).unwrap_or_default(),
+        });
+    }
+
+    if changes.is_empty() {
+        return None;
+    }
+
+    Some(Drift {
+        resource_id: actual.id,
+        project_id: actual.project_id,
+        name: actual.name.clone(),
+        changes,
+        detected_at: Utc::now(),
+    })
+}
+
+fn same_tags(a: &[Tag], b: &[Tag]) -> bool {
+    let key = |t: &&Tag| (t.key.clone(), t.value.clone());
+    let mut a: Vec<_> = a.iter().collect();
+    let mut b: Vec<_> = b.iter().collect();
+    a.sort_by_key(key);
+    b.sort_by_key(key);
+    a == b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateCloudResource;
+
+    fn tag(key: &str, value: &str) -> Tag {
+        Tag {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn resource(tags: Vec<Tag>) -> CloudResource {
+        let mut resource = CloudResource::new(CreateCloudResource {
+            project_id: Uuid::now_v7(),
+            name: "db-main".to_string(),
+            resource_type: ResourceType::Database,
+            region: "us-east-1".to_string(),
+            configuration: Value::Null,
+            cost_per_hour: None,
+            tags,
+        });
+        resource.status = ResourceStatus::Active;
+        resource
+    }
+
+    #[test]
+    fn test_no_drift() {
+        let actual = resource(vec![tag("env", "prod"), tag("team", "data")]);
+        let desired = DesiredState {
+            resource_type: Some(ResourceType::Database),
+            status: Some(ResourceStatus::Active),
+            // Order is irrelevant
+            tags: Some(vec![tag("team", "data"), tag("env", "prod")]),
+        };
+
+        assert_eq!(detect_drift(&actual, &desired), None);
+    }
+
+    #[test]
+    fn test_unspecified_fields_are_ignored() {
+        let actual = resource(vec![tag("owner", "someone")]);
+
+        assert_eq!(detect_drift(&actual, &DesiredState::default()), None);
+    }
+
+    #[test]
+    fn test_added_tag_drift() {
+        let actual = resource(vec![tag("env", "prod"), tag("debug", "true")]);
+        let desired = DesiredState {
+            tags: Some(vec![tag("env", "prod")]),
+            ..Default::default()
+        };
+
+        let drift = detect_drift(&actual, &desired).expect("tags should drift");
+
+        assert_eq!(drift.resource_id, actual.id);
+        assert_eq!(
+            drift.changes,
+            vec![FieldDrift {
+                field: DriftField::Tags,
+                before: serde_json::json!([{ "key": "env", "value": "prod" }]),
+                after: serde_json::json!([
+                    { "key": "env", "value": "prod" },
+                    { "key": "debug", "value": "true" }
+                ]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_changed_type_drift() {
+        let actual = resource(vec![]);
+        let desired = DesiredState {
+            resource_type: Some(ResourceType::Storage),
+            status: Some(ResourceStatus::Active),
+            tags: None,
+        };
+
+        let drift = detect_drift(&actual, &desired).expect("type should drift");
+
+        assert_eq!(drift.changes.len(), 1);
+        assert_eq!(drift.changes[0].field, DriftField::ResourceType);
+        assert_eq!(
+            drift.changes[0].before,
+            Value::String("storage".to_string())
+        );
+        assert_eq!(
+            drift.changes[0].after,
+            Value::String("database".to_string())
+        );
+    }
+}
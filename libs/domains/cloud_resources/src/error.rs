@@ -18,6 +18,9 @@ pub enum CloudResourceError {
     #[error("Invalid cloud resource status transition: {0}")]
     InvalidStatusTransition(String),
 
+    #[error("Invalid input: {0}")]
+    Validation(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -36,6 +39,7 @@ impl From<CloudResourceError> for AppError {
                 name
             )),
             CloudResourceError::InvalidStatusTransition(msg) => AppError::BadRequest(msg),
+            CloudResourceError::Validation(msg) => AppError::BadRequest(msg),
             CloudResourceError::Internal(msg) => AppError::InternalServerError(msg),
         }
     }
@@ -20,8 +20,12 @@ use utoipa::{OpenApi, ToSchema};
 use uuid::Uuid;
 
 use crate::{
+    drift::{DesiredState, Drift, DriftField, DriftFilter, FieldDrift},
     error::CloudResourceResult,
-    models::{CloudResource, CloudResourceFilter, CreateCloudResource, UpdateCloudResource},
+    models::{
+        CloudResource, CloudResourceFilter, CreateCloudResource, SyncCloudResources, SyncSummary,
+        UpdateCloudResource,
+    },
     repository::CloudResourceRepository,
     service::CloudResourceService,
 };
@@ -37,6 +41,9 @@ use crate::{
         update_cloud_resource,
         delete_cloud_resource,
         soft_delete_cloud_resource,
+        sync_cloud_resources,
+        check_drift,
+        list_drifted,
     ),
     components(
         schemas(
@@ -44,6 +51,12 @@ use crate::{
             CreateCloudResource,
             UpdateCloudResource,
             CloudResourceFilter,
+            SyncCloudResources,
+            SyncSummary,
+            DesiredState,
+            Drift,
+            DriftField,
+            FieldDrift,
             MessageResponse
         ),
         responses(
@@ -81,6 +94,9 @@ where
                 .delete(delete_cloud_resource),
         )
         .route("/project/{project_id}", get(list_by_project))
+        .route("/sync", post(sync_cloud_resources))
+        .route("/drift", get(list_drifted))
+        .route("/{id}/drift", post(check_drift))
         .route("/{id}/soft-delete", post(soft_delete_cloud_resource))
         .with_state(service)
 }
@@ -302,3 +318,101 @@ where
         }),
     ))
 }
+
+/// Sync cloud resources with a provider's current inventory
+#[utoipa::path(
+    post,
+    path = "/sync",
+    tag = "cloud-resources",
+    request_body = SyncCloudResources,
+    responses(
+        (status = 200, description = "Inventory reconciled", body = SyncSummary),
+        (status = 400, response = BadRequestValidationResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    )
+)]
+async fn sync_cloud_resources<R>(
+    State(service): State<Arc<CloudResourceService<R>>>,
+    headers: HeaderMap,
+    Json(input): Json<SyncCloudResources>,
+) -> CloudResourceResult<impl IntoResponse>
+where
+    R: CloudResourceRepository,
+{
+    let provider = input.provider;
+    let project_ids = input.project_ids;
+    let summary = service
+        .sync(provider, project_ids.clone(), input.resources)
+        .await?;
+
+    AuditEvent::new(
+        None, // TODO: Add user_id when authentication is implemented
+        "cloud_resource.sync",
+        None,
+        AuditOutcome::Success,
+    )
+    .with_ip(extract_ip_from_headers(&headers))
+    .with_user_agent(extract_user_agent(&headers))
+    .with_details(json!({
+        "provider": provider.to_string(),
+        "project_ids": project_ids,
+        "created": summary.created,
+        "updated": summary.updated,
+        "removed": summary.removed,
+    }))
+    .log();
+
+    Ok(Json(summary))
+}
+
+/// Check a cloud resource for drift against its desired state
+///
+/// Returns the drift finding, or `null` if the resource matches.
+#[utoipa::path(
+    post,
+    path = "/{id}/drift",
+    tag = "cloud-resources",
+    params(
+        ("id" = Uuid, Path, description = "Cloud resource ID")
+    ),
+    request_body = DesiredState,
+    responses(
+        (status = 200, description = "Drift check result", body = Option<Drift>),
+        (status = 400, response = BadRequestUuidResponse),
+        (status = 404, response = NotFoundResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    )
+)]
+async fn check_drift<R>(
+    State(service): State<Arc<CloudResourceService<R>>>,
+    Path(id): Path<Uuid>,
+    Json(desired): Json<DesiredState>,
+) -> CloudResourceResult<impl IntoResponse>
+where
+    R: CloudResourceRepository,
+{
+    let drift = service.check_drift(id, desired).await?;
+    Ok(Json(drift))
+}
+
+/// List cloud resources that have drifted from their desired state
+#[utoipa::path(
+    get,
+    path = "/drift",
+    tag = "cloud-resources",
+    params(DriftFilter),
+    responses(
+        (status = 200, description = "Drifted cloud resources", body = Vec<Drift>),
+        (status = 500, response = InternalServerErrorResponse)
+    )
+)]
+async fn list_drifted<R>(
+    State(service): State<Arc<CloudResourceService<R>>>,
+    Query(filter): Query<DriftFilter>,
+) -> CloudResourceResult<impl IntoResponse>
+where
+    R: CloudResourceRepository,
+{
+    let drifts = service.list_drifted(filter.project_id).await?;
+    Ok(Json(drifts))
+}
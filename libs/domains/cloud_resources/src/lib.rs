@@ -46,21 +46,27 @@
 //! # }
 //! ```
 
+pub mod drift;
 pub mod entity;
 pub mod error;
 pub mod handlers;
+pub mod metrics;
 pub mod models;
 pub mod postgres;
 pub mod repository;
 pub mod service;
+pub mod sync;
 
 // Re-export commonly used types
+pub use drift::{DesiredState, Drift, DriftField, DriftFilter, FieldDrift};
 pub use error::{CloudResourceError, CloudResourceResult};
 pub use handlers::ApiDoc;
+pub use metrics::ResourceMetrics;
 pub use models::{
-    CloudResource, CloudResourceFilter, CreateCloudResource, ResourceStatus, ResourceType, Tag,
-    UpdateCloudResource,
+    CloudResource, CloudResourceFilter, CreateCloudResource, ResourceStatus, ResourceType,
+    SyncCloudResources, SyncSummary, Tag, UpdateCloudResource,
 };
 pub use postgres::PgCloudResourceRepository;
 pub use repository::CloudResourceRepository;
 pub use service::CloudResourceService;
+pub use sync::{SyncPlan, plan_sync};
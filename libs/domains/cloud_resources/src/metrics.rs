@@ -0,0 +1,61 @@
+//! Prometheus metrics for cloud resources.
+
+use metrics::{counter, histogram};
+use std::time::Duration;
+
+use crate::models::SyncSummary;
+
+/// Metrics for cloud resource inventory operations.
+#[derive(Clone)]
+pub struct ResourceMetrics {
+    provider: String,
+}
+
+impl ResourceMetrics {
+    /// Create new metrics for a provider.
+    pub fn new(provider: &str) -> Self {
+        Self {
+            provider: provider.to_string(),
+        }
+    }
+
+    /// Record a completed sync and the changes it applied.
+    pub fn sync_completed(&self, summary: &SyncSummary, duration: Duration) {
+        counter!(
+            "cloud_resources_sync_total",
+            "provider" => self.provider.clone(),
+            "outcome" => "success"
+        )
+        .increment(1);
+
+        for (action, count) in [
+            ("created", summary.created),
+            ("updated", summary.updated),
+            ("removed", summary.removed),
+            ("unchanged", summary.unchanged),
+        ] {
+            counter!(
+                "cloud_resources_synced_total",
+                "provider" => self.provider.clone(),
+                "action" => action
+            )
+            .increment(count as u64);
+        }
+
+        histogram!(
+            "cloud_resources_sync_duration_seconds",
+            "provider" => self.provider.clone()
+        )
+        .record(duration.as_secs_f64());
+    }
+
+    /// Record a failed sync.
+    pub fn sync_failed(&self) {
+        counter!(
+            "cloud_resources_sync_total",
+            "provider" => self.provider.clone(),
+            "outcome" => "failure"
+        )
+        .increment(1);
+    }
+}
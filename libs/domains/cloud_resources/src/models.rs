@@ -185,3 +185,25 @@ impl CloudResource {
         self.updated_at = Utc::now();
     }
 }
+
+/// DTO for reconciling a provider's current inventory against stored resources
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SyncCloudResources {
+    /// Provider the inventory was collected from
+    pub provider: domain_projects::CloudProvider,
+    /// Projects the inventory covers; resources in other projects are left untouched
+    #[validate(length(min = 1))]
+    pub project_ids: Vec<Uuid>,
+    /// Every resource currently reported by the provider for those projects
+    #[validate(nested)]
+    pub resources: Vec<CreateCloudResource>,
+}
+
+/// Counts of changes applied by a sync
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SyncSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+}
@@ -1,17 +1,25 @@
 use async_trait::async_trait;
 use database::BaseRepository;
+use domain_projects::CloudProvider;
 use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait,
+    FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement,
+    TransactionTrait,
 };
 use uuid::Uuid;
 
 use crate::{
+    drift::Drift,
     entity,
     error::{CloudResourceError, CloudResourceResult},
-    models::{CloudResource, CloudResourceFilter, CreateCloudResource, UpdateCloudResource},
+    models::{
+        CloudResource, CloudResourceFilter, CreateCloudResource, ResourceStatus, SyncSummary,
+        UpdateCloudResource,
+    },
     repository::CloudResourceRepository,
+    sync::plan_sync,
 };
 
 pub struct PgCloudResourceRepository {
@@ -26,6 +34,54 @@ impl PgCloudResourceRepository {
     }
 }
 
+/// Row shape for drift findings joined with their resource
+#[derive(Debug, FromQueryResult)]
+struct DriftRow {
+    resource_id: Uuid,
+    project_id: Uuid,
+    name: String,
+    changes: serde_json::Value,
+    detected_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<DriftRow> for Drift {
+    type Error = CloudResourceError;
+
+    fn try_from(row: DriftRow) -> Result<Self, Self::Error> {
+        let changes = serde_json::from_value(row.changes).map_err(|e| {
+            CloudResourceError::Internal(format!("Invalid drift changes in database: {}", e))
+        })?;
+
+        Ok(Self {
+            resource_id: row.resource_id,
+            project_id: row.project_id,
+            name: row.name,
+            changes,
+            detected_at: row.detected_at,
+        })
+    }
+}
+
+/// Full-row ActiveModel for writing back a modified resource
+fn to_active_model(resource: &CloudResource) -> entity::ActiveModel {
+    entity::ActiveModel {
+        id: Set(resource.id),
+        project_id: Set(resource.project_id),
+        name: Set(resource.name.clone()),
+        resource_type: Set(resource.resource_type.to_string()),
+        status: Set(resource.status.to_string()),
+        region: Set(resource.region.clone()),
+        configuration: Set(resource.configuration.clone()),
+        cost_per_hour: Set(resource.cost_per_hour),
+        monthly_cost_estimate: Set(resource.monthly_cost_estimate),
+        tags: Set(serde_json::to_value(&resource.tags).unwrap()),
+        enabled: Set(resource.enabled),
+        created_at: Set(resource.created_at.into()),
+        updated_at: Set(resource.updated_at.into()),
+        deleted_at: Set(resource.deleted_at.map(|dt| dt.into())),
+    }
+}
+
 #[async_trait]
 impl CloudResourceRepository for PgCloudResourceRepository {
     async fn create(&self, input: CreateCloudResource) -> CloudResourceResult<CloudResource> {
@@ -212,4 +268,144 @@ impl CloudResourceRepository for PgCloudResourceRepository {
 
         Ok(count as usize)
     }
+
+    async fn project_ids_by_provider(
+        &self,
+        provider: CloudProvider,
+        project_ids: &[Uuid],
+    ) -> CloudResourceResult<Vec<Uuid>> {
+        let ids = domain_projects::entity::Entity::find()
+            .select_only()
+            .column(domain_projects::entity::Column::Id)
+            .filter(domain_projects::entity::Column::Id.is_in(project_ids.iter().copied()))
+            .filter(domain_projects::entity::Column::CloudProvider.eq(provider))
+            .into_tuple::<Uuid>()
+            .all(self.base.db())
+            .await
+            .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+
+        Ok(ids)
+    }
+
+    async fn sync(
+        &self,
+        project_ids: &[Uuid],
+        incoming: Vec<CreateCloudResource>,
+    ) -> CloudResourceResult<SyncSummary> {
+        let txn = self
+            .base
+            .db()
+            .begin()
+            .await
+            .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+
+        // Lock the scoped rows so concurrent syncs of the same projects
+        // plan against each other's results
+        let existing = entity::Entity::find()
+            .filter(entity::Column::ProjectId.is_in(project_ids.iter().copied()))
+            .lock_exclusive()
+            .all(&txn)
+            .await
+            .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+
+        let plan = plan_sync(existing.into_iter().map(|m| m.into()).collect(), incoming);
+        let summary = plan.summary();
+        if plan.is_empty() {
+            txn.commit()
+                .await
+                .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+            return Ok(summary);
+        }
+
+        for input in plan.create {
+            // The provider reported it, so it already exists and is running
+            let mut active_model: entity::ActiveModel = input.into();
+            active_model.status = Set(ResourceStatus::Active.to_string());
+
+            active_model
+                .insert(&txn)
+                .await
+                .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+        }
+
+        for resource in &plan.update {
+            to_active_model(resource)
+                .update(&txn)
+                .await
+                .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+        }
+
+        if !plan.remove.is_empty() {
+            let now = chrono::Utc::now();
+            entity::Entity::update_many()
+                .col_expr(
+                    entity::Column::Status,
+                    Expr::value(ResourceStatus::Deleted.to_string()),
+                )
+                .col_expr(entity::Column::DeletedAt, Expr::value(now))
+                .col_expr(entity::Column::UpdatedAt, Expr::value(now))
+                .filter(entity::Column::Id.is_in(plan.remove))
+                .exec(&txn)
+                .await
+                .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+
+        Ok(summary)
+    }
+
+    async fn save_drift(&self, resource_id: Uuid, drift: Option<Drift>) -> CloudResourceResult<()> {
+        let stmt = match drift {
+            Some(drift) => {
+                let changes = serde_json::to_value(&drift.changes).map_err(|e| {
+                    CloudResourceError::Internal(format!("Failed to serialize drift: {}", e))
+                })?;
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"
+                    INSERT INTO cloud_resource_drifts (resource_id, changes, detected_at)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (resource_id)
+                    DO UPDATE SET changes = EXCLUDED.changes, detected_at = EXCLUDED.detected_at
+                    "#,
+                    [resource_id.into(), changes.into(), drift.detected_at.into()],
+                )
+            }
+            None => Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM cloud_resource_drifts WHERE resource_id = $1",
+                [resource_id.into()],
+            ),
+        };
+
+        self.base
+            .db()
+            .execute_raw(stmt)
+            .await
+            .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn list_drifts(&self, project_id: Option<Uuid>) -> CloudResourceResult<Vec<Drift>> {
+        let sql = r#"
+            SELECT d.resource_id, r.project_id, r.name, d.changes, d.detected_at
+            FROM cloud_resource_drifts d
+            JOIN cloud_resources r ON r.id = d.resource_id
+            WHERE $1::uuid IS NULL OR r.project_id = $1
+            ORDER BY d.detected_at DESC
+        "#;
+
+        let stmt = Statement::from_sql_and_values(DbBackend::Postgres, sql, [project_id.into()]);
+
+        let rows = DriftRow::find_by_statement(stmt)
+            .all(self.base.db())
+            .await
+            .map_err(|e| CloudResourceError::Internal(format!("Database error: {}", e)))?;
+
+        rows.into_iter().map(Drift::try_from).collect()
+    }
 }
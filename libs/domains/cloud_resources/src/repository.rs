@@ -1,9 +1,14 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
+use domain_projects::CloudProvider;
+
 use crate::{
+    drift::Drift,
     error::CloudResourceResult,
-    models::{CloudResource, CloudResourceFilter, CreateCloudResource, UpdateCloudResource},
+    models::{
+        CloudResource, CloudResourceFilter, CreateCloudResource, SyncSummary, UpdateCloudResource,
+    },
 };
 
 /// Repository trait for cloud resource operations
@@ -36,4 +41,31 @@ pub trait CloudResourceRepository: Send + Sync {
 
     /// Count cloud resources by project
     async fn count_by_project(&self, project_id: Uuid) -> CloudResourceResult<usize>;
+
+    /// Those of `project_ids` that are projects hosted on a cloud provider
+    async fn project_ids_by_provider(
+        &self,
+        provider: CloudProvider,
+        project_ids: &[Uuid],
+    ) -> CloudResourceResult<Vec<Uuid>>;
+
+    /// Reconcile the resources of `project_ids` with `incoming` in a single
+    /// transaction
+    ///
+    /// Stored resources are read (including soft-deleted ones) and locked in
+    /// the same transaction that applies the [`plan_sync`] result. Resources
+    /// in other projects are never touched.
+    ///
+    /// [`plan_sync`]: crate::sync::plan_sync
+    async fn sync(
+        &self,
+        project_ids: &[Uuid],
+        incoming: Vec<CreateCloudResource>,
+    ) -> CloudResourceResult<SyncSummary>;
+
+    /// Store the latest drift finding for a resource, or clear it when `None`
+    async fn save_drift(&self, resource_id: Uuid, drift: Option<Drift>) -> CloudResourceResult<()>;
+
+    /// List stored drift findings, most recent first
+    async fn list_drifts(&self, project_id: Option<Uuid>) -> CloudResourceResult<Vec<Drift>>;
 }
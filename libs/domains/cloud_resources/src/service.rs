@@ -1,9 +1,17 @@
+use std::collections::HashSet;
+use std::time::Instant;
+
+use domain_projects::CloudProvider;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
+    drift::{self, DesiredState, Drift},
     error::{CloudResourceError, CloudResourceResult},
-    models::{CloudResource, CloudResourceFilter, CreateCloudResource, UpdateCloudResource},
+    metrics::ResourceMetrics,
+    models::{
+        CloudResource, CloudResourceFilter, CreateCloudResource, SyncSummary, UpdateCloudResource,
+    },
     repository::CloudResourceRepository,
 };
 
@@ -94,4 +102,124 @@ impl<R: CloudResourceRepository> CloudResourceService<R> {
     pub async fn count_by_project(&self, project_id: Uuid) -> CloudResourceResult<usize> {
         self.repository.count_by_project(project_id).await
     }
+
+    /// Reconcile stored resources with a provider's current inventory
+    ///
+    /// `project_ids` is the scope of the inventory: only resources in those
+    /// projects are compared, and every incoming resource must belong to one
+    /// of them. Resources are matched by project and name. New ones are
+    /// inserted, changed ones updated, and stored ones missing from
+    /// `incoming` are marked as deleted, all in one transaction.
+    pub async fn sync(
+        &self,
+        provider: CloudProvider,
+        project_ids: Vec<Uuid>,
+        incoming: Vec<CreateCloudResource>,
+    ) -> CloudResourceResult<SyncSummary> {
+        let metrics = ResourceMetrics::new(&provider.to_string());
+        let start = Instant::now();
+
+        let result = self.sync_inner(provider, project_ids, incoming).await;
+        match &result {
+            Ok(summary) => metrics.sync_completed(summary, start.elapsed()),
+            Err(_) => metrics.sync_failed(),
+        }
+        result
+    }
+
+    async fn sync_inner(
+        &self,
+        provider: CloudProvider,
+        project_ids: Vec<Uuid>,
+        incoming: Vec<CreateCloudResource>,
+    ) -> CloudResourceResult<SyncSummary> {
+        if project_ids.is_empty() {
+            return Err(CloudResourceError::Validation(
+                "At least one project must be in the sync scope".to_string(),
+            ));
+        }
+
+        let scope: HashSet<Uuid> = project_ids.iter().copied().collect();
+        let provider_projects: HashSet<Uuid> = self
+            .repository
+            .project_ids_by_provider(provider, &project_ids)
+            .await?
+            .into_iter()
+            .collect();
+
+        if let Some(project_id) = scope.iter().find(|id| !provider_projects.contains(id)) {
+            return Err(CloudResourceError::Validation(format!(
+                "Project {} is not a {} project",
+                project_id, provider
+            )));
+        }
+
+        let mut keys = HashSet::new();
+        for input in &incoming {
+            input
+                .validate()
+                .map_err(|e| CloudResourceError::Validation(e.to_string()))?;
+
+            if !scope.contains(&input.project_id) {
+                return Err(CloudResourceError::Validation(format!(
+                    "Project {} is not in the sync scope",
+                    input.project_id
+                )));
+            }
+
+            if !keys.insert((input.project_id, input.name.as_str())) {
+                return Err(CloudResourceError::Validation(format!(
+                    "Duplicate resource '{}' in project {}",
+                    input.name, input.project_id
+                )));
+            }
+        }
+
+        let scope: Vec<Uuid> = scope.into_iter().collect();
+        let summary = self.repository.sync(&scope, incoming).await?;
+
+        tracing::info!(
+            provider = %provider,
+            projects = scope.len(),
+            created = summary.created,
+            updated = summary.updated,
+            removed = summary.removed,
+            unchanged = summary.unchanged,
+            "Synced cloud resources"
+        );
+        Ok(summary)
+    }
+
+    /// Compare a resource against its desired state
+    pub fn detect_drift(&self, actual: &CloudResource, desired: &DesiredState) -> Option<Drift> {
+        drift::detect_drift(actual, desired)
+    }
+
+    /// Check a stored resource for drift and record the finding
+    ///
+    /// A resource that matches its desired state has any previous finding cleared.
+    pub async fn check_drift(
+        &self,
+        id: Uuid,
+        desired: DesiredState,
+    ) -> CloudResourceResult<Option<Drift>> {
+        let actual = self.get(id).await?;
+        let drift = self.detect_drift(&actual, &desired);
+
+        self.repository.save_drift(id, drift.clone()).await?;
+
+        if let Some(drift) = &drift {
+            tracing::warn!(
+                resource_id = %id,
+                fields = ?drift.changes.iter().map(|c| c.field).collect::<Vec<_>>(),
+                "Cloud resource drifted from desired state"
+            );
+        }
+        Ok(drift)
+    }
+
+    /// List resources with recorded drift
+    pub async fn list_drifted(&self, project_id: Option<Uuid>) -> CloudResourceResult<Vec<Drift>> {
+        self.repository.list_drifts(project_id).await
+    }
 }
@@ -0,0 +1,242 @@
+//! Inventory reconciliation for cloud resources
+//!
+//! A sync compares what a provider currently reports against what is stored.
+//! Resources are matched by `(project_id, name)`, which is unique per project.
+//! The diff is computed here as a pure function so it can be tested without a
+//! database; the repository reads the stored resources and applies the
+//! resulting plan in one transaction.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::models::{CloudResource, CreateCloudResource, ResourceStatus, SyncSummary};
+
+/// Changes needed to bring stored resources in line with the incoming inventory
+#[derive(Debug, Clone, Default)]
+pub struct SyncPlan {
+    /// Resources reported by the provider that we don't know about
+    pub create: Vec<CreateCloudResource>,
+    /// Stored resources with changed fields, already merged with the incoming data
+    pub update: Vec<CloudResource>,
+    /// Stored resources the provider no longer reports
+    pub remove: Vec<Uuid>,
+    /// Stored resources that match the incoming data exactly
+    pub unchanged: usize,
+}
+
+impl SyncPlan {
+    /// Summary of the changes this plan applies
+    pub fn summary(&self) -> SyncSummary {
+        SyncSummary {
+            created: self.create.len(),
+            updated: self.update.len(),
+            removed: self.remove.len(),
+            unchanged: self.unchanged,
+        }
+    }
+
+    /// Returns true if applying the plan would not change anything
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.update.is_empty() && self.remove.is_empty()
+    }
+}
+
+/// Diff `existing` resources against the `incoming` inventory
+///
+/// `existing` should include soft-deleted resources so that a resource which
+/// reappears is revived instead of colliding with the unique name index.
+pub fn plan_sync(existing: Vec<CloudResource>, incoming: Vec<CreateCloudResource>) -> SyncPlan {
+    let mut plan = SyncPlan::default();
+    let mut by_key: HashMap<(Uuid, String), CloudResource> = existing
+        .into_iter()
+        .map(|r| ((r.project_id, r.name.clone()), r))
+        .collect();
+    let mut seen = HashSet::new();
+
+    for input in incoming {
+        let key = (input.project_id, input.name.clone());
+        if !seen.insert(key.clone()) {
+            // Duplicates are rejected by the service; keep the first occurrence
+            continue;
+        }
+
+        match by_key.remove(&key) {
+            None => plan.create.push(input),
+            Some(current) => match merge(&current, input) {
+                Some(updated) => plan.update.push(updated),
+                None => plan.unchanged += 1,
+            },
+        }
+    }
+
+    plan.remove = by_key
+        .into_values()
+        .filter(|r| r.status != ResourceStatus::Deleted)
+        .map(|r| r.id)
+        .collect();
+    plan.remove.sort();
+
+    plan
+}
+
+/// Merge incoming data into a stored resource, returning `None` if nothing changed
+fn merge(current: &CloudResource, input: CreateCloudResource) -> Option<CloudResource> {
+    let revived = current.status == ResourceStatus::Deleted;
+    let changed = revived
+        || current.resource_type != input.resource_type
+        || current.region != input.region
+        || current.configuration != input.configuration
+        || current.cost_per_hour != input.cost_per_hour
+        || current.tags != input.tags;
+
+    if !changed {
+        return None;
+    }
+
+    let mut updated = current.clone();
+    updated.resource_type = input.resource_type;
+    updated.region = input.region;
+    updated.configuration = input.configuration;
+    updated.cost_per_hour = input.cost_per_hour;
+    updated.monthly_cost_estimate = input.cost_per_hour.map(|hourly| hourly * 24.0 * 30.0);
+    updated.tags = input.tags;
+    if revived {
+        updated.status = ResourceStatus::Active;
+        updated.deleted_at = None;
+    }
+    updated.updated_at = Utc::now();
+
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ResourceType, Tag};
+    use serde_json::json;
+
+    fn incoming(project_id: Uuid, name: &str) -> CreateCloudResource {
+        CreateCloudResource {
+            project_id,
+            name: name.to_string(),
+            resource_type: ResourceType::Compute,
+            region: "us-east-1".to_string(),
+            configuration: json!({ "size": "small" }),
+            cost_per_hour: Some(0.1),
+            tags: vec![],
+        }
+    }
+
+    fn stored(project_id: Uuid, name: &str) -> CloudResource {
+        let mut resource = CloudResource::new(incoming(project_id, name));
+        resource.status = ResourceStatus::Active;
+        resource
+    }
+
+    #[test]
+    fn test_new_resource_is_created() {
+        let project_id = Uuid::now_v7();
+
+        let plan = plan_sync(vec![], vec![incoming(project_id, "vm-1")]);
+
+        assert_eq!(plan.create.len(), 1);
+        assert_eq!(plan.create[0].name, "vm-1");
+        assert_eq!(
+            plan.summary(),
+            SyncSummary {
+                created: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_identical_resource_is_unchanged() {
+        let project_id = Uuid::now_v7();
+
+        let plan = plan_sync(
+            vec![stored(project_id, "vm-1")],
+            vec![incoming(project_id, "vm-1")],
+        );
+
+        assert!(plan.is_empty());
+        assert_eq!(plan.unchanged, 1);
+    }
+
+    #[test]
+    fn test_changed_resource_is_updated() {
+        let project_id = Uuid::now_v7();
+        let existing = stored(project_id, "vm-1");
+        let mut changed = incoming(project_id, "vm-1");
+        changed.configuration = json!({ "size": "large" });
+        changed.tags = vec![Tag {
+            key: "env".to_string(),
+            value: "prod".to_string(),
+        }];
+
+        let plan = plan_sync(vec![existing.clone()], vec![changed]);
+
+        assert_eq!(plan.update.len(), 1);
+        let updated = &plan.update[0];
+        assert_eq!(updated.id, existing.id);
+        assert_eq!(updated.configuration, json!({ "size": "large" }));
+        assert_eq!(updated.tags.len(), 1);
+        assert_eq!(updated.status, ResourceStatus::Active);
+    }
+
+    #[test]
+    fn test_missing_resource_is_removed() {
+        let project_id = Uuid::now_v7();
+        let gone = stored(project_id, "vm-gone");
+
+        let plan = plan_sync(
+            vec![stored(project_id, "vm-1"), gone.clone()],
+            vec![incoming(project_id, "vm-1")],
+        );
+
+        assert_eq!(plan.remove, vec![gone.id]);
+        assert_eq!(plan.unchanged, 1);
+    }
+
+    #[test]
+    fn test_already_deleted_resource_is_not_removed_again() {
+        let project_id = Uuid::now_v7();
+        let mut deleted = stored(project_id, "vm-old");
+        deleted.soft_delete();
+
+        let plan = plan_sync(vec![deleted], vec![]);
+
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn test_reappearing_resource_is_revived() {
+        let project_id = Uuid::now_v7();
+        let mut deleted = stored(project_id, "vm-1");
+        deleted.soft_delete();
+
+        let plan = plan_sync(vec![deleted.clone()], vec![incoming(project_id, "vm-1")]);
+
+        assert_eq!(plan.update.len(), 1);
+        assert_eq!(plan.update[0].id, deleted.id);
+        assert_eq!(plan.update[0].status, ResourceStatus::Active);
+        assert!(plan.update[0].deleted_at.is_none());
+    }
+
+    #[test]
+    fn test_same_name_in_different_projects_is_distinct() {
+        let project_a = Uuid::now_v7();
+        let project_b = Uuid::now_v7();
+
+        let plan = plan_sync(
+            vec![stored(project_a, "vm-1")],
+            vec![incoming(project_a, "vm-1"), incoming(project_b, "vm-1")],
+        );
+
+        assert_eq!(plan.create.len(), 1);
+        assert_eq!(plan.create[0].project_id, project_b);
+        assert_eq!(plan.unchanged, 1);
+    }
+}
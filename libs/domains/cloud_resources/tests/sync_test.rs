@@ -0,0 +1,106 @@
+//! Integration tests for provider inventory sync
+//!
+//! These tests use a real PostgreSQL database via testcontainers:
+//! - A sync only reconciles the projects in its scope
+//! - Resources outside the scope are rejected
+
+use domain_cloud_resources::{
+    CloudResourceError, CloudResourceService, CreateCloudResource, PgCloudResourceRepository,
+    ResourceType, SyncSummary,
+};
+use domain_projects::{
+    CloudProvider, CreateProject, Environment, PgProjectRepository, ProjectRepository,
+};
+use serde_json::json;
+use test_utils::{TestDataBuilder, TestDatabase};
+use uuid::Uuid;
+
+async fn create_project(db: &TestDatabase, builder: &TestDataBuilder, suffix: &str) -> Uuid {
+    let project = PgProjectRepository::new(db.connection())
+        .create(CreateProject {
+            name: builder.name("project", suffix),
+            user_id: builder.user_id(),
+            description: String::new(),
+            cloud_provider: CloudProvider::Aws,
+            region: "us-east-1".to_string(),
+            environment: Environment::Development,
+            budget_limit: None,
+            tags: vec![],
+        })
+        .await
+        .unwrap();
+    project.id
+}
+
+fn resource(project_id: Uuid, name: &str) -> CreateCloudResource {
+    CreateCloudResource {
+        project_id,
+        name: name.to_string(),
+        resource_type: ResourceType::Compute,
+        region: "us-east-1".to_string(),
+        configuration: json!({ "size": "small" }),
+        cost_per_hour: Some(0.1),
+        tags: vec![],
+    }
+}
+
+#[tokio::test]
+async fn test_sync_leaves_other_projects_untouched() {
+    let db = TestDatabase::new().await;
+    let builder = TestDataBuilder::from_test_name("sync_scoped_to_project");
+    db.create_test_user(builder.user_id()).await;
+
+    let project_a = create_project(&db, &builder, "a").await;
+    let project_b = create_project(&db, &builder, "b").await;
+    let service = CloudResourceService::new(PgCloudResourceRepository::new(db.connection()));
+
+    service
+        .sync(
+            CloudProvider::Aws,
+            vec![project_a, project_b],
+            vec![resource(project_a, "vm-a"), resource(project_b, "vm-b")],
+        )
+        .await
+        .unwrap();
+
+    // Project A's inventory is now empty; project B is not part of this sync
+    let summary = service
+        .sync(CloudProvider::Aws, vec![project_a], vec![])
+        .await
+        .unwrap();
+    assert_eq!(
+        summary,
+        SyncSummary {
+            removed: 1,
+            ..Default::default()
+        }
+    );
+
+    assert!(service.list_by_project(project_a).await.unwrap().is_empty());
+    let remaining = service.list_by_project(project_b).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].name, "vm-b");
+}
+
+#[tokio::test]
+async fn test_sync_rejects_resources_outside_scope() {
+    let db = TestDatabase::new().await;
+    let builder = TestDataBuilder::from_test_name("sync_rejects_out_of_scope");
+    db.create_test_user(builder.user_id()).await;
+
+    let project_a = create_project(&db, &builder, "a").await;
+    let project_b = create_project(&db, &builder, "b").await;
+    let service = CloudResourceService::new(PgCloudResourceRepository::new(db.connection()));
+
+    let result = service
+        .sync(
+            CloudProvider::Aws,
+            vec![project_a],
+            vec![resource(project_b, "vm-b")],
+        )
+        .await;
+    assert!(matches!(result, Err(CloudResourceError::Validation(_))));
+
+    let result = service.sync(CloudProvider::Aws, vec![], vec![]).await;
+    assert!(matches!(result, Err(CloudResourceError::Validation(_))));
+}
@@ -2,6 +2,8 @@ use axum_helpers::{AppError, impl_into_response_via_app_error};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::models::ProjectDependents;
+
 #[derive(Debug, Error)]
 pub enum ProjectError {
     #[error("Project not found: {0}")]
@@ -16,6 +18,16 @@ pub enum ProjectError {
     #[error("Unauthorized access to project {0}")]
     Unauthorized(Uuid),
 
+    #[error(
+        "Project {id} still has {} cloud resource(s) and {} task(s)",
+        dependents.cloud_resources,
+        dependents.tasks
+    )]
+    HasDependents {
+        id: Uuid,
+        dependents: ProjectDependents,
+    },
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -33,6 +45,7 @@ impl From<ProjectError> for AppError {
             ProjectError::Unauthorized(id) => {
                 AppError::Forbidden(format!("Access denied to project {}", id))
             }
+            err @ ProjectError::HasDependents { .. } => AppError::Conflict(err.to_string()),
             ProjectError::Internal(msg) => AppError::InternalServerError(msg),
         }
     }
@@ -20,7 +20,10 @@ use utoipa::OpenApi;
 
 use crate::entity;
 use crate::error::ProjectResult;
-use crate::models::{CreateProject, Project, ProjectFilter, UpdateProject};
+use crate::models::{
+    CreateProject, DeleteMode, DeleteProjectParams, Project, ProjectDependents, ProjectFilter,
+    RenameTag, RenameTagResult, TagMatch, TagUsage, TagUsageFilter, UpdateProject,
+};
 use crate::repository::ProjectRepository;
 use crate::service::ProjectService;
 
@@ -36,9 +39,22 @@ use crate::service::ProjectService;
         activate_project,
         suspend_project,
         archive_project,
+        list_tags,
+        rename_tag,
     ),
     components(
-        schemas(Project, CreateProject, UpdateProject, ProjectFilter),
+        schemas(
+            Project,
+            CreateProject,
+            UpdateProject,
+            ProjectFilter,
+            DeleteMode,
+            ProjectDependents,
+            TagMatch,
+            TagUsage,
+            RenameTag,
+            RenameTagResult
+        ),
         responses(
             NotFoundResponse,
             BadRequestValidationResponse,
@@ -59,6 +75,8 @@ pub fn router<R: ProjectRepository + 'static>(service: ProjectService<R>) -> Rou
 
     Router::new()
         .route("/", get(list_projects).post(create_project))
+        .route("/tags", get(list_tags))
+        .route("/tags/rename", post(rename_tag))
         .route(
             "/{id}",
             get(get_project).put(update_project).delete(delete_project),
@@ -177,17 +195,23 @@ async fn update_project<R: ProjectRepository>(
 }
 
 /// Delete a project
+///
+/// `mode` controls what happens to cloud resources and tasks that reference
+/// the project: `restrict` (default) refuses with 409, `cascade` deletes them,
+/// `detach` unlinks tasks.
 #[utoipa::path(
     delete,
     path = "/{id}",
     tag = entity::Model::TAG,
     params(
-        ("id" = Uuid, Path, description = "Project ID")
+        ("id" = Uuid, Path, description = "Project ID"),
+        DeleteProjectParams
     ),
     responses(
         (status = 204, description = "Project deleted successfully"),
         (status = 400, response = BadRequestUuidResponse),
         (status = 404, response = NotFoundResponse),
+        (status = 409, response = ConflictResponse),
         (status = 500, response = InternalServerErrorResponse)
     )
 )]
@@ -195,8 +219,9 @@ async fn delete_project<R: ProjectRepository>(
     State(service): State<Arc<ProjectService<R>>>,
     headers: HeaderMap,
     UuidPath(id): UuidPath,
+    Query(params): Query<DeleteProjectParams>,
 ) -> ProjectResult<impl IntoResponse> {
-    service.delete_project(id).await?;
+    let affected = service.delete_project(id, params.mode).await?;
 
     // Audit log successful deletion
     AuditEvent::new(
@@ -207,6 +232,11 @@ async fn delete_project<R: ProjectRepository>(
     )
     .with_ip(extract_ip_from_headers(&headers))
     .with_user_agent(extract_user_agent(&headers))
+    .with_details(json!({
+        "mode": params.mode.to_string(),
+        "cloud_resources": affected.cloud_resources,
+        "tasks": affected.tasks,
+    }))
     .log();
 
     Ok(StatusCode::NO_CONTENT)
@@ -280,3 +310,61 @@ async fn archive_project<R: ProjectRepository>(
     let project = service.archive_project(id).await?;
     Ok(Json(project))
 }
+
+/// List distinct project tags with usage counts
+#[utoipa::path(
+    get,
+    path = "/tags",
+    tag = entity::Model::TAG,
+    params(TagUsageFilter),
+    responses(
+        (status = 200, description = "Tags with the number of projects using each", body = Vec<TagUsage>),
+        (status = 500, response = InternalServerErrorResponse)
+    )
+)]
+async fn list_tags<R: ProjectRepository>(
+    State(service): State<Arc<ProjectService<R>>>,
+    Query(filter): Query<TagUsageFilter>,
+) -> ProjectResult<Json<Vec<TagUsage>>> {
+    let tags = service.list_tags(filter).await?;
+    Ok(Json(tags))
+}
+
+/// Rename or merge a tag across projects
+#[utoipa::path(
+    post,
+    path = "/tags/rename",
+    tag = entity::Model::TAG,
+    request_body = RenameTag,
+    responses(
+        (status = 200, description = "Tag renamed", body = RenameTagResult),
+        (status = 400, response = BadRequestValidationResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    )
+)]
+async fn rename_tag<R: ProjectRepository>(
+    State(service): State<Arc<ProjectService<R>>>,
+    headers: HeaderMap,
+    ValidatedJson(input): ValidatedJson<RenameTag>,
+) -> ProjectResult<Json<RenameTagResult>> {
+    let details = json!({
+        "from": input.from,
+        "to": input.to,
+    });
+    let user_id = input.user_id;
+
+    let result = service.rename_tag(input).await?;
+
+    AuditEvent::new(
+        user_id.map(|id| id.to_string()),
+        "project.tag_rename",
+        None,
+        AuditOutcome::Success,
+    )
+    .with_ip(extract_ip_from_headers(&headers))
+    .with_user_agent(extract_user_agent(&headers))
+    .with_details(details)
+    .log();
+
+    Ok(Json(result))
+}
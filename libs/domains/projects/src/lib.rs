@@ -58,8 +58,9 @@ pub mod service;
 pub use error::{ProjectError, ProjectResult};
 pub use handlers::ApiDoc;
 pub use models::{
-    CloudProvider, CreateProject, Environment, Project, ProjectFilter, ProjectStatus, Tag,
-    UpdateProject,
+    CloudProvider, CreateProject, DeleteMode, DeleteProjectParams, Environment, Project,
+    ProjectDependents, ProjectFilter, ProjectStatus, RenameTag, RenameTagResult, Tag, TagMatch,
+    TagSelector, TagUsage, TagUsageFilter, UpdateProject,
 };
 pub use postgres::PgProjectRepository;
 pub use repository::ProjectRepository;
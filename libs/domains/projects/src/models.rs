@@ -114,6 +114,56 @@ pub enum Environment {
     Production,
 }
 
+/// How dependent rows are handled when a project is deleted
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DeleteMode {
+    /// Refuse to delete while cloud resources or tasks reference the project
+    #[default]
+    Restrict,
+    /// Delete cloud resources and tasks together with the project
+    Cascade,
+    /// Unlink tasks from the project; cloud resources still restrict deletion
+    /// because they cannot exist without a project
+    Detach,
+}
+
+/// Query parameters for deleting a project
+#[derive(Debug, Clone, Default, Deserialize, ToSchema, IntoParams)]
+pub struct DeleteProjectParams {
+    #[serde(default)]
+    pub mode: DeleteMode,
+}
+
+/// Counts of rows that reference a project
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ProjectDependents {
+    /// Cloud resources belonging to the project
+    pub cloud_resources: u64,
+    /// Tasks linked to the project
+    pub tasks: u64,
+}
+
+impl ProjectDependents {
+    /// Returns true if nothing references the project
+    pub fn is_empty(&self) -> bool {
+        self.cloud_resources == 0 && self.tasks == 0
+    }
+}
+
 /// Project entity - represents a cloud project
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Project {
@@ -189,6 +239,56 @@ pub struct UpdateProject {
     pub enabled: Option<bool>,
 }
 
+/// How multiple tags in a filter are combined
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TagMatch {
+    /// Project must carry every requested tag
+    #[default]
+    All,
+    /// Project must carry at least one requested tag
+    Any,
+}
+
+/// A tag to match in a filter; without a value, any tag with the key matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagSelector {
+    pub key: String,
+    pub value: Option<String>,
+}
+
+impl TagSelector {
+    /// Parse `key` or `key:value`
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (key, value) = match raw.split_once(':') {
+            Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+            None => (raw.trim(), None),
+        };
+
+        if key.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
 /// Query filters for listing projects
 #[derive(Debug, Clone, Deserialize, ToSchema, IntoParams)]
 pub struct ProjectFilter {
@@ -197,6 +297,11 @@ pub struct ProjectFilter {
     pub environment: Option<Environment>,
     pub status: Option<ProjectStatus>,
     pub enabled: Option<bool>,
+    /// Comma-separated tags, each `key` or `key:value` (e.g. `env:prod,billing`)
+    pub tags: Option<String>,
+    /// Whether all (default) or any of `tags` must match
+    #[serde(default)]
+    pub tag_match: TagMatch,
     #[serde(default = "default_limit")]
     pub limit: usize,
     #[serde(default)]
@@ -215,12 +320,61 @@ impl Default for ProjectFilter {
             environment: None,
             status: None,
             enabled: None,
+            tags: None,
+            tag_match: TagMatch::default(),
             limit: default_limit(),
             offset: 0,
         }
     }
 }
 
+impl ProjectFilter {
+    /// Parsed tag selectors from the `tags` parameter
+    pub fn tag_selectors(&self) -> Vec<TagSelector> {
+        self.tags
+            .as_deref()
+            .map(|tags| tags.split(',').filter_map(TagSelector::parse).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Tag with the number of projects using it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct TagUsage {
+    pub key: String,
+    pub value: String,
+    /// Number of projects carrying this tag
+    pub count: u64,
+}
+
+/// Query filters for listing tags
+#[derive(Debug, Clone, Default, Deserialize, ToSchema, IntoParams)]
+pub struct TagUsageFilter {
+    /// Restrict to projects owned by this user
+    pub user_id: Option<Uuid>,
+}
+
+/// DTO for renaming a tag across projects
+///
+/// Projects that already carry `to` end up with a single copy, which merges
+/// the two tags.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct RenameTag {
+    #[validate(nested)]
+    pub from: Tag,
+    #[validate(nested)]
+    pub to: Tag,
+    /// Restrict the rename to projects owned by this user
+    pub user_id: Option<Uuid>,
+}
+
+/// Result of a tag rename
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RenameTagResult {
+    /// Number of projects whose tags changed
+    pub projects_updated: u64,
+}
+
 impl Project {
     /// Create a new project from CreateProject DTO
     pub fn new(input: CreateProject) -> Self {
@@ -1,16 +1,22 @@
 use async_trait::async_trait;
 use database::BaseRepository;
 use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect,
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait,
+    FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement,
+    TransactionTrait,
 };
+use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
     entity,
     error::{ProjectError, ProjectResult},
-    models::{CreateProject, Project, ProjectFilter, UpdateProject},
+    models::{
+        CreateProject, DeleteMode, Project, ProjectDependents, ProjectFilter, RenameTag, TagMatch,
+        TagUsage, TagUsageFilter, UpdateProject,
+    },
     repository::ProjectRepository,
 };
 
@@ -26,6 +32,70 @@ impl PgProjectRepository {
     }
 }
 
+fn db_error(e: sea_orm::DbErr) -> ProjectError {
+    ProjectError::Internal(format!("Database error: {}", e))
+}
+
+/// Count live (not soft-deleted) rows referencing a project on the given
+/// connection or transaction
+async fn count_dependents_on<C: ConnectionTrait>(
+    conn: &C,
+    id: Uuid,
+) -> ProjectResult<ProjectDependents> {
+    let sql = r#"
+        SELECT
+            (SELECT COUNT(*) FROM cloud_resources
+             WHERE project_id = $1 AND deleted_at IS NULL) AS cloud_resources,
+            (SELECT COUNT(*) FROM tasks
+             WHERE project_id = $1 AND deleted_at IS NULL) AS tasks
+    "#;
+
+    let stmt = Statement::from_sql_and_values(DbBackend::Postgres, sql, [id.into()]);
+
+    let row = conn
+        .query_one_raw(stmt)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| ProjectError::Internal("Failed to count project dependents".to_string()))?;
+
+    let cloud_resources: i64 = row.try_get("", "cloud_resources").map_err(db_error)?;
+    let tasks: i64 = row.try_get("", "tasks").map_err(db_error)?;
+
+    Ok(ProjectDependents {
+        cloud_resources: cloud_resources as u64,
+        tasks: tasks as u64,
+    })
+}
+
+/// Row shape for tag usage aggregation
+#[derive(Debug, FromQueryResult)]
+struct TagUsageRow {
+    key: String,
+    value: String,
+    count: i64,
+}
+
+impl From<TagUsageRow> for TagUsage {
+    fn from(row: TagUsageRow) -> Self {
+        Self {
+            key: row.key,
+            value: row.value,
+            count: row.count as u64,
+        }
+    }
+}
+
+/// Execute a statement binding the project id as `$1`, returning rows affected
+async fn execute_for_project<C: ConnectionTrait>(
+    conn: &C,
+    sql: &str,
+    id: Uuid,
+) -> ProjectResult<u64> {
+    let stmt = Statement::from_sql_and_values(DbBackend::Postgres, sql, [id.into()]);
+    let result = conn.execute_raw(stmt).await.map_err(db_error)?;
+    Ok(result.rows_affected())
+}
+
 #[async_trait]
 impl ProjectRepository for PgProjectRepository {
     async fn create(&self, input: CreateProject) -> ProjectResult<Project> {
@@ -83,6 +153,25 @@ impl ProjectRepository for PgProjectRepository {
             query = query.filter(entity::Column::Enabled.eq(enabled));
         }
 
+        // Tags are a JSONB array of {key, value}; containment uses the GIN index
+        let selectors = filter.tag_selectors();
+        if !selectors.is_empty() {
+            let mut condition = match filter.tag_match {
+                TagMatch::All => Condition::all(),
+                TagMatch::Any => Condition::any(),
+            };
+
+            for selector in selectors {
+                let needle = match selector.value {
+                    Some(value) => json!([{ "key": selector.key, "value": value }]),
+                    None => json!([{ "key": selector.key }]),
+                };
+                condition = condition.add(Expr::cust_with_values("tags @> $1::jsonb", [needle]));
+            }
+
+            query = query.filter(condition);
+        }
+
         // Apply pagination and ordering
         query = query
             .order_by_desc(entity::Column::CreatedAt)
@@ -171,6 +260,77 @@ impl ProjectRepository for PgProjectRepository {
         }
     }
 
+    async fn count_dependents(&self, id: Uuid) -> ProjectResult<ProjectDependents> {
+        count_dependents_on(self.base.db(), id).await
+    }
+
+    async fn delete_with_mode(
+        &self,
+        id: Uuid,
+        mode: DeleteMode,
+    ) -> ProjectResult<Option<ProjectDependents>> {
+        let txn = self.base.db().begin().await.map_err(db_error)?;
+
+        // Lock the project row so dependents can't be added while we decide
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT id FROM projects WHERE id = $1 FOR UPDATE",
+            [id.into()],
+        );
+        if txn.query_one_raw(stmt).await.map_err(db_error)?.is_none() {
+            return Ok(None);
+        }
+
+        let dependents = count_dependents_on(&txn, id).await?;
+
+        let affected = match mode {
+            DeleteMode::Restrict => {
+                if !dependents.is_empty() {
+                    return Err(ProjectError::HasDependents { id, dependents });
+                }
+                ProjectDependents::default()
+            }
+            DeleteMode::Cascade => ProjectDependents {
+                tasks: execute_for_project(&txn, "DELETE FROM tasks WHERE project_id = $1", id)
+                    .await?,
+                cloud_resources: execute_for_project(
+                    &txn,
+                    "DELETE FROM cloud_resources WHERE project_id = $1",
+                    id,
+                )
+                .await?,
+            },
+            DeleteMode::Detach => {
+                // cloud_resources.project_id is NOT NULL, so they can only be deleted
+                if dependents.cloud_resources > 0 {
+                    return Err(ProjectError::HasDependents { id, dependents });
+                }
+                ProjectDependents {
+                    cloud_resources: 0,
+                    tasks: execute_for_project(
+                        &txn,
+                        "UPDATE tasks SET project_id = NULL, updated_at = now() WHERE project_id = $1",
+                        id,
+                    )
+                    .await?,
+                }
+            }
+        };
+
+        execute_for_project(&txn, "DELETE FROM projects WHERE id = $1", id).await?;
+
+        txn.commit().await.map_err(db_error)?;
+
+        tracing::info!(
+            project_id = %id,
+            mode = %mode,
+            cloud_resources = affected.cloud_resources,
+            tasks = affected.tasks,
+            "Deleted project"
+        );
+        Ok(Some(affected))
+    }
+
     async fn exists_by_name(&self, user_id: Uuid, name: &str) -> ProjectResult<bool> {
         let exists = entity::Entity::find()
             .filter(entity::Column::UserId.eq(user_id))
@@ -183,6 +343,74 @@ impl ProjectRepository for PgProjectRepository {
         Ok(exists)
     }
 
+    async fn list_tags(&self, filter: TagUsageFilter) -> ProjectResult<Vec<TagUsage>> {
+        let sql = r#"
+            SELECT t->>'key' AS key, COALESCE(t->>'value', '') AS value,
+                   COUNT(DISTINCT projects.id) AS count
+            FROM projects
+            CROSS JOIN LATERAL jsonb_array_elements(
+                CASE WHEN jsonb_typeof(tags) = 'array' THEN tags ELSE '[]'::jsonb END
+            ) AS t
+            WHERE $1::uuid IS NULL OR user_id = $1
+            GROUP BY 1, 2
+            ORDER BY count DESC, key, value
+        "#;
+
+        let stmt =
+            Statement::from_sql_and_values(DbBackend::Postgres, sql, [filter.user_id.into()]);
+
+        let rows = TagUsageRow::find_by_statement(stmt)
+            .all(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn rename_tag(&self, input: RenameTag) -> ProjectResult<u64> {
+        // Single statement so the rename is atomic across projects. Duplicates
+        // produced by the rename are collapsed, keeping the first position.
+        let sql = r#"
+            UPDATE projects p
+            SET tags = (
+                SELECT COALESCE(jsonb_agg(d.elem ORDER BY d.ord), '[]'::jsonb)
+                FROM (
+                    SELECT DISTINCT ON (r.elem) r.elem, r.ord
+                    FROM (
+                        SELECT CASE WHEN t.e = $1::jsonb THEN $2::jsonb ELSE t.e END AS elem, t.ord
+                        FROM jsonb_array_elements(p.tags) WITH ORDINALITY AS t(e, ord)
+                    ) r
+                    ORDER BY r.elem, r.ord
+                ) d
+            ),
+            updated_at = now()
+            WHERE p.tags @> jsonb_build_array($1::jsonb)
+              AND ($3::uuid IS NULL OR p.user_id = $3)
+        "#;
+
+        let from = serde_json::to_value(&input.from)
+            .map_err(|e| ProjectError::Internal(format!("Failed to serialize tag: {}", e)))?;
+        let to = serde_json::to_value(&input.to)
+            .map_err(|e| ProjectError::Internal(format!("Failed to serialize tag: {}", e)))?;
+
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            sql,
+            [from.into(), to.into(), input.user_id.into()],
+        );
+
+        let result = self.base.db().execute_raw(stmt).await.map_err(db_error)?;
+        let updated = result.rows_affected();
+
+        tracing::info!(
+            from = %format!("{}:{}", input.from.key, input.from.value),
+            to = %format!("{}:{}", input.to.key, input.to.value),
+            updated,
+            "Renamed project tag"
+        );
+        Ok(updated)
+    }
+
     async fn count_by_user(&self, user_id: Uuid) -> ProjectResult<usize> {
         let count = entity::Entity::find()
             .filter(entity::Column::UserId.eq(user_id))
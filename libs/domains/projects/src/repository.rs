@@ -2,7 +2,10 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::error::ProjectResult;
-use crate::models::{CreateProject, Project, ProjectFilter, UpdateProject};
+use crate::models::{
+    CreateProject, DeleteMode, Project, ProjectDependents, ProjectFilter, RenameTag, TagUsage,
+    TagUsageFilter, UpdateProject,
+};
 
 /// Repository trait for Project persistence
 ///
@@ -26,9 +29,30 @@ pub trait ProjectRepository: Send + Sync {
     /// Delete a project by ID
     async fn delete(&self, id: Uuid) -> ProjectResult<bool>;
 
+    /// Count cloud resources and tasks referencing a project
+    async fn count_dependents(&self, id: Uuid) -> ProjectResult<ProjectDependents>;
+
+    /// Delete a project, handling dependents according to `mode`, in one transaction
+    ///
+    /// Returns the dependents that were deleted or detached, or `None` if the
+    /// project does not exist.
+    async fn delete_with_mode(
+        &self,
+        id: Uuid,
+        mode: DeleteMode,
+    ) -> ProjectResult<Option<ProjectDependents>>;
+
     /// Check if a project name exists for a user
     async fn exists_by_name(&self, user_id: Uuid, name: &str) -> ProjectResult<bool>;
 
     /// Count projects for a user
     async fn count_by_user(&self, user_id: Uuid) -> ProjectResult<usize>;
+
+    /// List distinct tags with the number of projects using each
+    async fn list_tags(&self, filter: TagUsageFilter) -> ProjectResult<Vec<TagUsage>>;
+
+    /// Replace one tag with another on every matching project atomically
+    ///
+    /// Returns the number of projects updated.
+    async fn rename_tag(&self, input: RenameTag) -> ProjectResult<u64>;
 }
@@ -4,7 +4,10 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::error::{ProjectError, ProjectResult};
-use crate::models::{CreateProject, Project, ProjectFilter, ProjectStatus, UpdateProject};
+use crate::models::{
+    CreateProject, DeleteMode, Project, ProjectDependents, ProjectFilter, ProjectStatus, RenameTag,
+    RenameTagResult, TagUsage, TagUsageFilter, UpdateProject,
+};
 use crate::repository::ProjectRepository;
 
 /// Service layer for Project business logic
@@ -72,6 +75,28 @@ impl<R: ProjectRepository> ProjectService<R> {
         self.repository.list(filter).await
     }
 
+    /// List distinct tags with usage counts
+    pub async fn list_tags(&self, filter: TagUsageFilter) -> ProjectResult<Vec<TagUsage>> {
+        self.repository.list_tags(filter).await
+    }
+
+    /// Rename (or merge) a tag across all matching projects
+    #[instrument(skip(self, input), fields(from_key = %input.from.key, to_key = %input.to.key))]
+    pub async fn rename_tag(&self, input: RenameTag) -> ProjectResult<RenameTagResult> {
+        input
+            .validate()
+            .map_err(|e| ProjectError::Validation(e.to_string()))?;
+
+        if input.from == input.to {
+            return Err(ProjectError::Validation(
+                "Source and target tags are identical".to_string(),
+            ));
+        }
+
+        let projects_updated = self.repository.rename_tag(input).await?;
+        Ok(RenameTagResult { projects_updated })
+    }
+
     /// Update a project
     #[instrument(skip(self, input), fields(project_id = %id))]
     pub async fn update_project(&self, id: Uuid, input: UpdateProject) -> ProjectResult<Project> {
@@ -99,27 +124,41 @@ impl<R: ProjectRepository> ProjectService<R> {
         self.update_project(id, input).await
     }
 
-    /// Delete a project
-    #[instrument(skip(self), fields(project_id = %id))]
-    pub async fn delete_project(&self, id: Uuid) -> ProjectResult<()> {
-        let deleted = self.repository.delete(id).await?;
-
-        if !deleted {
-            return Err(ProjectError::NotFound(id));
-        }
-
-        Ok(())
+    /// Delete a project, handling cloud resources and tasks according to `mode`
+    ///
+    /// Returns the number of dependents that were deleted or detached.
+    #[instrument(skip(self), fields(project_id = %id, mode = %mode))]
+    pub async fn delete_project(
+        &self,
+        id: Uuid,
+        mode: DeleteMode,
+    ) -> ProjectResult<ProjectDependents> {
+        self.repository
+            .delete_with_mode(id, mode)
+            .await?
+            .ok_or(ProjectError::NotFound(id))
     }
 
     /// Delete a project, verifying user ownership
-    pub async fn delete_project_for_user(&self, id: Uuid, user_id: Uuid) -> ProjectResult<()> {
+    pub async fn delete_project_for_user(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        mode: DeleteMode,
+    ) -> ProjectResult<ProjectDependents> {
         let project = self.get_project(id).await?;
 
         if project.user_id != user_id {
             return Err(ProjectError::Unauthorized(id));
         }
 
-        self.delete_project(id).await
+        self.delete_project(id, mode).await
+    }
+
+    /// Count cloud resources and tasks that reference a project
+    pub async fn project_dependents(&self, id: Uuid) -> ProjectResult<ProjectDependents> {
+        self.get_project(id).await?;
+        self.repository.count_dependents(id).await
     }
 
     /// Activate a project (change status to Active)
@@ -252,6 +291,25 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_delete_project_not_found() {
+        let mut mock_repo = MockProjectRepository::new();
+        let id = Uuid::now_v7();
+
+        mock_repo
+            .expect_delete_with_mode()
+            .with(
+                mockall::predicate::eq(id),
+                mockall::predicate::eq(DeleteMode::Restrict),
+            )
+            .returning(|_, _| Ok(None));
+
+        let service = ProjectService::new(mock_repo);
+        let result = service.delete_project(id, DeleteMode::Restrict).await;
+
+        assert!(matches!(result, Err(ProjectError::NotFound(missing)) if missing == id));
+    }
+
     #[tokio::test]
     async fn test_can_create_first_project() {
         let mut mock_repo = MockProjectRepository::new();
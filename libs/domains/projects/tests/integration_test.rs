@@ -7,6 +7,7 @@
 //! - Concurrent operations are handled properly
 
 use domain_projects::*;
+use sea_orm::ConnectionTrait;
 use test_utils::{TestDataBuilder, TestDatabase, assertions::*};
 use uuid::Uuid;
 
@@ -323,6 +324,185 @@ async fn test_list_projects_with_filters() {
     assert_eq!(page2.len(), 2, "second page should have 2 items");
 }
 
+// ============================================================================
+// Tag Tests
+// ============================================================================
+
+fn tag(key: &str, value: &str) -> Tag {
+    Tag {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// Create projects for one user with the given tag sets
+async fn seed_tagged_projects(
+    db: &TestDatabase,
+    repo: &PgProjectRepository,
+    builder: &TestDataBuilder,
+    tag_sets: Vec<Vec<Tag>>,
+) -> (Uuid, Vec<Project>) {
+    let user_id = db.create_test_user(builder.user_id()).await;
+    let mut projects = Vec::new();
+
+    for (i, tags) in tag_sets.into_iter().enumerate() {
+        let input = CreateProject {
+            name: builder.name("project", &format!("tagged-{}", i)),
+            user_id,
+            description: String::new(),
+            cloud_provider: CloudProvider::Aws,
+            region: "us-east-1".to_string(),
+            environment: Environment::Development,
+            budget_limit: None,
+            tags,
+        };
+        projects.push(repo.create(input).await.unwrap());
+    }
+
+    (user_id, projects)
+}
+
+#[tokio::test]
+async fn test_list_projects_tag_match_all_vs_any() {
+    let db = TestDatabase::new().await;
+    let repo = PgProjectRepository::new(db.connection());
+    let builder = TestDataBuilder::from_test_name("tag_match");
+
+    let (user_id, projects) = seed_tagged_projects(
+        &db,
+        &repo,
+        &builder,
+        vec![
+            vec![tag("env", "prod"), tag("team", "billing")],
+            vec![tag("env", "prod")],
+            vec![tag("team", "billing")],
+            vec![tag("env", "dev")],
+        ],
+    )
+    .await;
+
+    // AND: only the project carrying both tags
+    let filter = ProjectFilter {
+        user_id: Some(user_id),
+        tags: Some("env:prod,team:billing".to_string()),
+        tag_match: TagMatch::All,
+        ..Default::default()
+    };
+    let results = repo.list(filter).await.unwrap();
+    assert_eq!(results.len(), 1, "AND should match one project");
+    assert_uuid_eq(results[0].id, projects[0].id, "AND match");
+
+    // OR: any project carrying either tag
+    let filter = ProjectFilter {
+        user_id: Some(user_id),
+        tags: Some("env:prod,team:billing".to_string()),
+        tag_match: TagMatch::Any,
+        ..Default::default()
+    };
+    let results = repo.list(filter).await.unwrap();
+    assert_eq!(results.len(), 3, "OR should match three projects");
+
+    // Key-only selector matches any value
+    let filter = ProjectFilter {
+        user_id: Some(user_id),
+        tags: Some("env".to_string()),
+        ..Default::default()
+    };
+    let results = repo.list(filter).await.unwrap();
+    assert_eq!(
+        results.len(),
+        3,
+        "key-only selector should match every env tag"
+    );
+}
+
+#[tokio::test]
+async fn test_list_tags_with_counts() {
+    let db = TestDatabase::new().await;
+    let repo = PgProjectRepository::new(db.connection());
+    let builder = TestDataBuilder::from_test_name("list_tags");
+
+    let (user_id, _) = seed_tagged_projects(
+        &db,
+        &repo,
+        &builder,
+        vec![
+            vec![tag("env", "prod"), tag("team", "billing")],
+            vec![tag("env", "prod")],
+            // A repeated tag still counts the project once
+            vec![tag("env", "prod"), tag("env", "prod")],
+        ],
+    )
+    .await;
+
+    let tags = repo
+        .list_tags(TagUsageFilter {
+            user_id: Some(user_id),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        tags,
+        vec![
+            TagUsage {
+                key: "env".to_string(),
+                value: "prod".to_string(),
+                count: 3,
+            },
+            TagUsage {
+                key: "team".to_string(),
+                value: "billing".to_string(),
+                count: 1,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_rename_tag_across_projects() {
+    let db = TestDatabase::new().await;
+    let repo = PgProjectRepository::new(db.connection());
+    let service = ProjectService::new(PgProjectRepository::new(db.connection()));
+    let builder = TestDataBuilder::from_test_name("rename_tag");
+
+    let (user_id, projects) = seed_tagged_projects(
+        &db,
+        &repo,
+        &builder,
+        vec![
+            vec![tag("env", "production"), tag("team", "billing")],
+            vec![tag("env", "production")],
+            // Already has the target tag: rename merges instead of duplicating
+            vec![tag("env", "prod"), tag("env", "production")],
+            vec![tag("env", "dev")],
+        ],
+    )
+    .await;
+
+    let result = service
+        .rename_tag(RenameTag {
+            from: tag("env", "production"),
+            to: tag("env", "prod"),
+            user_id: Some(user_id),
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.projects_updated, 3);
+
+    let first = repo.get_by_id(projects[0].id).await.unwrap().unwrap();
+    assert_eq!(first.tags, vec![tag("env", "prod"), tag("team", "billing")]);
+
+    let second = repo.get_by_id(projects[1].id).await.unwrap().unwrap();
+    assert_eq!(second.tags, vec![tag("env", "prod")]);
+
+    let merged = repo.get_by_id(projects[2].id).await.unwrap().unwrap();
+    assert_eq!(merged.tags, vec![tag("env", "prod")]);
+
+    let untouched = repo.get_by_id(projects[3].id).await.unwrap().unwrap();
+    assert_eq!(untouched.tags, vec![tag("env", "dev")]);
+}
+
 // ============================================================================
 // Service Tests
 // ============================================================================
@@ -463,6 +643,215 @@ async fn test_service_authorization() {
     );
 }
 
+// ============================================================================
+// Delete Mode Tests
+// ============================================================================
+
+/// Create a project with one cloud resource and two tasks referencing it
+async fn seed_project_with_dependents(
+    db: &TestDatabase,
+    service: &ProjectService<PgProjectRepository>,
+    builder: &TestDataBuilder,
+) -> Project {
+    let user_id = db.create_test_user(builder.user_id()).await;
+
+    let project = service
+        .create_project(CreateProject {
+            name: builder.name("project", "with-deps"),
+            user_id,
+            description: String::new(),
+            cloud_provider: CloudProvider::Aws,
+            region: "us-east-1".to_string(),
+            environment: Environment::Development,
+            budget_limit: None,
+            tags: vec![],
+        })
+        .await
+        .unwrap();
+
+    db.connection
+        .execute_unprepared(&format!(
+            "INSERT INTO cloud_resources (project_id, name, resource_type, region) \
+             VALUES ('{}', 'vm-1', 'compute', 'us-east-1')",
+            project.id
+        ))
+        .await
+        .unwrap();
+    db.connection
+        .execute_unprepared(&format!(
+            "INSERT INTO tasks (title, project_id) VALUES ('task-1', '{0}'), ('task-2', '{0}')",
+            project.id
+        ))
+        .await
+        .unwrap();
+
+    project
+}
+
+async fn count_rows(db: &TestDatabase, sql: String) -> i64 {
+    let row = db
+        .connection
+        .query_one_raw(sea_orm::Statement::from_string(
+            sea_orm::DbBackend::Postgres,
+            sql,
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    row.try_get("", "count").unwrap()
+}
+
+#[tokio::test]
+async fn test_delete_restrict_with_dependents() {
+    let db = TestDatabase::new().await;
+    let service = ProjectService::new(PgProjectRepository::new(db.connection()));
+    let builder = TestDataBuilder::from_test_name("delete_restrict");
+
+    let project = seed_project_with_dependents(&db, &service, &builder).await;
+
+    let dependents = service.project_dependents(project.id).await.unwrap();
+    assert_eq!(dependents.cloud_resources, 1);
+    assert_eq!(dependents.tasks, 2);
+
+    let result = service
+        .delete_project(project.id, DeleteMode::Restrict)
+        .await;
+    assert!(
+        matches!(result, Err(ProjectError::HasDependents { dependents: d, .. }) if d == dependents),
+        "restrict should refuse to delete a project with dependents"
+    );
+
+    let retrieved = service.get_project(project.id).await;
+    assert!(retrieved.is_ok(), "project should still exist");
+}
+
+#[tokio::test]
+async fn test_dependents_exclude_soft_deleted_rows() {
+    let db = TestDatabase::new().await;
+    let service = ProjectService::new(PgProjectRepository::new(db.connection()));
+    let builder = TestDataBuilder::from_test_name("dependents_soft_deleted");
+
+    let project = seed_project_with_dependents(&db, &service, &builder).await;
+
+    db.connection
+        .execute_unprepared(&format!(
+            "UPDATE tasks SET deleted_at = now() WHERE project_id = '{0}' AND title = 'task-1'; \
+             UPDATE cloud_resources SET deleted_at = now() WHERE project_id = '{0}'",
+            project.id
+        ))
+        .await
+        .unwrap();
+
+    let dependents = service.project_dependents(project.id).await.unwrap();
+    assert_eq!(dependents.cloud_resources, 0);
+    assert_eq!(dependents.tasks, 1);
+}
+
+#[tokio::test]
+async fn test_delete_restrict_without_dependents() {
+    let db = TestDatabase::new().await;
+    let service = ProjectService::new(PgProjectRepository::new(db.connection()));
+    let builder = TestDataBuilder::from_test_name("delete_restrict_empty");
+
+    let user_id = db.create_test_user(builder.user_id()).await;
+    let project = service
+        .create_project(CreateProject {
+            name: builder.name("project", "no-deps"),
+            user_id,
+            description: String::new(),
+            cloud_provider: CloudProvider::Gcp,
+            region: "europe-west1".to_string(),
+            environment: Environment::Development,
+            budget_limit: None,
+            tags: vec![],
+        })
+        .await
+        .unwrap();
+
+    let affected = service
+        .delete_project(project.id, DeleteMode::Restrict)
+        .await
+        .unwrap();
+    assert!(affected.is_empty());
+
+    let result = service
+        .delete_project(project.id, DeleteMode::Restrict)
+        .await;
+    assert!(matches!(result, Err(ProjectError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_delete_cascade_removes_dependents() {
+    let db = TestDatabase::new().await;
+    let service = ProjectService::new(PgProjectRepository::new(db.connection()));
+    let builder = TestDataBuilder::from_test_name("delete_cascade");
+
+    let project = seed_project_with_dependents(&db, &service, &builder).await;
+
+    let affected = service
+        .delete_project(project.id, DeleteMode::Cascade)
+        .await
+        .unwrap();
+    assert_eq!(affected.cloud_resources, 1);
+    assert_eq!(affected.tasks, 2);
+
+    let tasks = count_rows(
+        &db,
+        "SELECT COUNT(*) AS count FROM tasks WHERE title IN ('task-1', 'task-2')".to_string(),
+    )
+    .await;
+    assert_eq!(tasks, 0, "tasks should be deleted with the project");
+
+    let resources = count_rows(
+        &db,
+        format!(
+            "SELECT COUNT(*) AS count FROM cloud_resources WHERE project_id = '{}'",
+            project.id
+        ),
+    )
+    .await;
+    assert_eq!(
+        resources, 0,
+        "cloud resources should be deleted with the project"
+    );
+}
+
+#[tokio::test]
+async fn test_delete_detach_unlinks_tasks() {
+    let db = TestDatabase::new().await;
+    let service = ProjectService::new(PgProjectRepository::new(db.connection()));
+    let builder = TestDataBuilder::from_test_name("delete_detach");
+
+    let project = seed_project_with_dependents(&db, &service, &builder).await;
+
+    // Cloud resources can't be detached, so the project is still protected
+    let result = service.delete_project(project.id, DeleteMode::Detach).await;
+    assert!(matches!(result, Err(ProjectError::HasDependents { .. })));
+
+    db.connection
+        .execute_unprepared(&format!(
+            "DELETE FROM cloud_resources WHERE project_id = '{}'",
+            project.id
+        ))
+        .await
+        .unwrap();
+
+    let affected = service
+        .delete_project(project.id, DeleteMode::Detach)
+        .await
+        .unwrap();
+    assert_eq!(affected.cloud_resources, 0);
+    assert_eq!(affected.tasks, 2);
+
+    let orphaned = count_rows(
+        &db,
+        "SELECT COUNT(*) AS count FROM tasks WHERE title IN ('task-1', 'task-2') AND project_id IS NULL"
+            .to_string(),
+    )
+    .await;
+    assert_eq!(orphaned, 2, "tasks should survive with project_id cleared");
+}
+
 // ============================================================================
 // Concurrent Operations Tests
 // ============================================================================
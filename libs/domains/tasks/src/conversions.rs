@@ -131,9 +131,12 @@ impl TryFrom<CreateRequest> for CreateTask {
             title: proto.title,
             description: proto.description,
             project_id: opt_bytes_to_uuid(proto.project_id)?,
+            // Subtasks and recurrence aren't part of the gRPC contract yet
+            parent_id: None,
             priority: proto.priority.try_into()?,
             status: proto.status.try_into()?,
             due_date: opt_timestamp_to_datetime(proto.due_date),
+            recurrence: None,
         })
     }
 }
@@ -149,9 +152,11 @@ impl TryFrom<UpdateByIdRequest> for UpdateTask {
             description: proto.description,
             completed: proto.completed,
             project_id: proto.project_id.map(|bytes| bytes_to_uuid(&bytes).ok()),
+            parent_id: None,
             priority: proto.priority.map(|p| p.try_into()).transpose()?,
             status: proto.status.map(|s| s.try_into()).transpose()?,
             due_date: proto.due_date.map(|ts| Some(timestamp_to_datetime(ts))),
+            recurrence: None,
         })
     }
 }
@@ -170,6 +175,7 @@ impl TryFrom<CreateResponse> for Task {
             description: proto.description,
             completed: proto.completed,
             project_id: opt_bytes_to_uuid(proto.project_id).ok().flatten(),
+            parent_id: None,
             priority: proto.priority.try_into().unwrap_or_else(|e| {
                 tracing::warn!("Invalid priority in CreateResponse, defaulting: {e}");
                 Default::default()
@@ -179,8 +185,10 @@ impl TryFrom<CreateResponse> for Task {
                 Default::default()
             }),
             due_date: opt_timestamp_to_datetime(proto.due_date),
+            recurrence: None,
             created_at: timestamp_to_datetime(proto.created_at),
             updated_at: timestamp_to_datetime(proto.updated_at),
+            deleted_at: None,
         })
     }
 }
@@ -195,6 +203,7 @@ impl TryFrom<GetByIdResponse> for Task {
             description: proto.description,
             completed: proto.completed,
             project_id: opt_bytes_to_uuid(proto.project_id).ok().flatten(),
+            parent_id: None,
             priority: proto.priority.try_into().unwrap_or_else(|e| {
                 tracing::warn!("Invalid priority in GetByIdResponse, defaulting: {e}");
                 Default::default()
@@ -204,8 +213,10 @@ impl TryFrom<GetByIdResponse> for Task {
                 Default::default()
             }),
             due_date: opt_timestamp_to_datetime(proto.due_date),
+            recurrence: None,
             created_at: timestamp_to_datetime(proto.created_at),
             updated_at: timestamp_to_datetime(proto.updated_at),
+            deleted_at: None,
         })
     }
 }
@@ -220,6 +231,7 @@ impl TryFrom<UpdateByIdResponse> for Task {
             description: proto.description,
             completed: proto.completed,
             project_id: opt_bytes_to_uuid(proto.project_id).ok().flatten(),
+            parent_id: None,
             priority: proto.priority.try_into().unwrap_or_else(|e| {
                 tracing::warn!("Invalid priority in UpdateByIdResponse, defaulting: {e}");
                 Default::default()
@@ -229,8 +241,10 @@ impl TryFrom<UpdateByIdResponse> for Task {
                 Default::default()
             }),
             due_date: opt_timestamp_to_datetime(proto.due_date),
+            recurrence: None,
             created_at: timestamp_to_datetime(proto.created_at),
             updated_at: timestamp_to_datetime(proto.updated_at),
+            deleted_at: None,
         })
     }
 }
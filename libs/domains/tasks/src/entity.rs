@@ -1,4 +1,4 @@
-use crate::models::{TaskPriority, TaskStatus};
+use crate::models::{RecurrenceRule, TaskPriority, TaskStatus};
 use core_proc_macros::SeaOrmResource;
 use sea_orm::entity::prelude::*;
 use sea_orm::ActiveValue::Set;
@@ -15,11 +15,15 @@ pub struct Model {
     pub description: String,
     pub completed: bool,
     pub project_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
     pub priority: TaskPriority,
     pub status: TaskStatus,
     pub due_date: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub recurrence: Option<Json>, // JSONB RecurrenceRule
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -36,11 +40,14 @@ impl From<Model> for crate::models::Task {
             description: model.description,
             completed: model.completed,
             project_id: model.project_id,
+            parent_id: model.parent_id,
             priority: model.priority,
             status: model.status,
             due_date: model.due_date.map(Into::into),
+            recurrence: recurrence_from_json(model.recurrence),
             created_at: model.created_at.into(),
             updated_at: model.updated_at.into(),
+            deleted_at: model.deleted_at.map(Into::into),
         }
     }
 }
@@ -54,11 +61,25 @@ impl From<crate::models::CreateTask> for ActiveModel {
             description: Set(input.description),
             completed: Set(false),
             project_id: Set(input.project_id),
+            parent_id: Set(input.parent_id),
             priority: Set(input.priority),
             status: Set(input.status),
             due_date: Set(input.due_date.map(Into::into)),
+            recurrence: Set(recurrence_to_json(input.recurrence)),
             created_at: Set(chrono::Utc::now().into()),
             updated_at: Set(chrono::Utc::now().into()),
+            deleted_at: Set(None),
         }
     }
 }
+
+pub(crate) fn recurrence_to_json(rule: Option<RecurrenceRule>) -> Option<Json> {
+    rule.map(|rule| serde_json::to_value(rule).expect("Failed to serialize recurrence rule"))
+}
+
+fn recurrence_from_json(json: Option<Json>) -> Option<RecurrenceRule> {
+    // A rule we can't read is dropped so the task itself still loads
+    serde_json::from_value(json?)
+        .map_err(|e| tracing::warn!("Failed to parse task recurrence from JSON: {e}"))
+        .ok()
+}
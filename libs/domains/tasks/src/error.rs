@@ -10,6 +10,16 @@ pub enum TaskError {
     #[error("Invalid input: {0}")]
     Validation(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// A batch item failed; nothing in the batch was persisted
+    #[error("Batch item {index} failed: {source}")]
+    BatchItem {
+        index: usize,
+        source: Box<TaskError>,
+    },
+
     #[error("Internal error: {0}")]
     Internal(String),
 
@@ -19,11 +29,28 @@ pub enum TaskError {
 
 pub type TaskResult<T> = Result<T, TaskError>;
 
+impl TaskError {
+    /// Attribute this error to item `index` of a batch
+    pub fn at_batch_index(self, index: usize) -> Self {
+        TaskError::BatchItem {
+            index,
+            source: Box::new(self),
+        }
+    }
+}
+
 impl From<TaskError> for AppError {
     fn from(err: TaskError) -> Self {
         match err {
             TaskError::NotFound(id) => AppError::NotFound(format!("Task {} not found", id)),
             TaskError::Validation(msg) => AppError::BadRequest(msg),
+            TaskError::Conflict(msg) => AppError::Conflict(msg),
+            TaskError::BatchItem { index, source } => match *source {
+                TaskError::Validation(msg) => {
+                    AppError::BadRequest(format!("Batch item {}: {}", index, msg))
+                }
+                source => AppError::from(source),
+            },
             TaskError::Internal(msg) => AppError::InternalServerError(msg),
             TaskError::Database(msg) => {
                 AppError::InternalServerError(format!("Database error: {}", msg))
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
@@ -8,7 +8,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::error::TaskResult;
-use crate::models::{CreateTask, Task, TaskFilter, UpdateTask};
+use crate::models::{CreateTask, Task, TaskFilter, TaskTree, UpdateTask};
 use crate::repository::TaskRepository;
 use crate::service::TaskService;
 
@@ -17,6 +17,7 @@ use crate::service::TaskService;
     get,
     path = "",
     tag = "tasks-direct",
+    params(TaskFilter),
     responses(
         (status = 200, description = "List of tasks", body = Vec<Task>),
         (status = 500, description = "Internal server error")
@@ -24,16 +25,8 @@ use crate::service::TaskService;
 )]
 pub async fn list_tasks<R: TaskRepository>(
     State(service): State<Arc<TaskService<R>>>,
+    Query(filter): Query<TaskFilter>,
 ) -> TaskResult<Json<Vec<Task>>> {
-    let filter = TaskFilter {
-        project_id: None,
-        status: None,
-        priority: None,
-        completed: None,
-        limit: 50,
-        offset: 0,
-    };
-
     let tasks = service.list_tasks(filter).await?;
     Ok(Json(tasks))
 }
@@ -64,6 +57,103 @@ pub async fn get_task<R: TaskRepository>(
     Ok(Json(task))
 }
 
+/// List direct subtasks of a task
+#[utoipa::path(
+    get,
+    path = "/{id}/children",
+    tag = "tasks-direct",
+    params(
+        ("id" = String, Path, description = "Parent task ID")
+    ),
+    responses(
+        (status = 200, description = "Subtasks, oldest first", body = Vec<Task>),
+        (status = 400, description = "Invalid task ID"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_children<R: TaskRepository>(
+    State(service): State<Arc<TaskService<R>>>,
+    Path(id): Path<String>,
+) -> TaskResult<impl IntoResponse> {
+    let task_id = Uuid::parse_str(&id)
+        .map_err(|_| crate::error::TaskError::Validation("Invalid task ID".to_string()))?;
+
+    let children = service.list_children(task_id).await?;
+    Ok(Json(children))
+}
+
+/// Get a task with all of its subtasks nested
+#[utoipa::path(
+    get,
+    path = "/{id}/tree",
+    tag = "tasks-direct",
+    params(
+        ("id" = String, Path, description = "Root task ID")
+    ),
+    responses(
+        (status = 200, description = "Task tree", body = TaskTree),
+        (status = 400, description = "Invalid task ID"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_task_tree<R: TaskRepository>(
+    State(service): State<Arc<TaskService<R>>>,
+    Path(id): Path<String>,
+) -> TaskResult<impl IntoResponse> {
+    let task_id = Uuid::parse_str(&id)
+        .map_err(|_| crate::error::TaskError::Validation("Invalid task ID".to_string()))?;
+
+    let tree = service.get_task_tree(task_id).await?;
+    Ok(Json(tree))
+}
+
+/// List soft-deleted tasks
+#[utoipa::path(
+    get,
+    path = "/deleted",
+    tag = "tasks-direct",
+    params(TaskFilter),
+    responses(
+        (status = 200, description = "Soft-deleted tasks, most recently deleted first", body = Vec<Task>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_deleted_tasks<R: TaskRepository>(
+    State(service): State<Arc<TaskService<R>>>,
+    Query(filter): Query<TaskFilter>,
+) -> TaskResult<Json<Vec<Task>>> {
+    let tasks = service.list_deleted(filter).await?;
+    Ok(Json(tasks))
+}
+
+/// Restore a soft-deleted task
+#[utoipa::path(
+    post,
+    path = "/{id}/restore",
+    tag = "tasks-direct",
+    params(
+        ("id" = String, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task restored", body = Task),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Task not found or not deleted"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn restore_task<R: TaskRepository>(
+    State(service): State<Arc<TaskService<R>>>,
+    Path(id): Path<String>,
+) -> TaskResult<impl IntoResponse> {
+    let task_id = Uuid::parse_str(&id)
+        .map_err(|_| crate::error::TaskError::Validation("Invalid task ID".to_string()))?;
+
+    let task = service.restore_task(task_id).await?;
+    Ok(Json(task))
+}
+
 /// Create a new task
 #[utoipa::path(
     post,
@@ -112,7 +202,7 @@ pub async fn update_task<R: TaskRepository>(
     Ok(Json(task))
 }
 
-/// Delete a task
+/// Soft-delete a task
 #[utoipa::path(
     delete,
     path = "/{id}",
@@ -124,6 +214,7 @@ pub async fn update_task<R: TaskRepository>(
         (status = 204, description = "Task deleted successfully"),
         (status = 400, description = "Invalid request"),
         (status = 404, description = "Task not found"),
+        (status = 409, description = "Task has subtasks and cascade delete is off"),
         (status = 500, description = "Internal server error")
     )
 )]
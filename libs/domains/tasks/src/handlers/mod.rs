@@ -1,13 +1,16 @@
 mod direct;
 mod grpc;
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 use rpc::tasks::tasks_service_client::TasksServiceClient;
 use std::sync::Arc;
 use tonic::transport::Channel;
 use utoipa::OpenApi;
 
-use crate::models::{CreateTask, Task, UpdateTask};
+use crate::models::{CreateTask, Task, TaskTree, UpdateTask};
 use crate::repository::TaskRepository;
 use crate::service::TaskService;
 
@@ -17,12 +20,16 @@ use crate::service::TaskService;
     paths(
         direct::list_tasks,
         direct::get_task,
+        direct::list_children,
+        direct::get_task_tree,
         direct::create_task,
         direct::update_task,
         direct::delete_task,
+        direct::list_deleted_tasks,
+        direct::restore_task,
     ),
     components(
-        schemas(Task, CreateTask, UpdateTask)
+        schemas(Task, TaskTree, CreateTask, UpdateTask)
     ),
     tags(
         (name = "tasks-direct", description = "Direct database task operations")
@@ -61,6 +68,10 @@ pub fn direct_router<R: TaskRepository + 'static>(service: TaskService<R>) -> Ro
                 .put(direct::update_task)
                 .delete(direct::delete_task),
         )
+        .route("/{id}/children", get(direct::list_children))
+        .route("/{id}/tree", get(direct::get_task_tree))
+        .route("/{id}/restore", post(direct::restore_task))
+        .route("/deleted", get(direct::list_deleted_tasks))
         .with_state(shared_service)
 }
 
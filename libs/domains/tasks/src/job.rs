@@ -0,0 +1,90 @@
+//! TaskCommand - background work for the tasks worker
+//!
+//! Commands implement `messaging::Job`, so they can be queued on any
+//! messaging backend and handled by [`TaskProcessor`](crate::TaskProcessor).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::CreateTask;
+
+/// What a command asks the worker to do
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TaskAction {
+    /// Mark a task completed, scheduling its next occurrence if it recurs
+    Complete { task_id: Uuid },
+    /// Create a task, e.g. the next occurrence of a recurring one
+    ///
+    /// `task_id` is fixed when the command is issued, so a redelivered
+    /// command finds the task it already created instead of adding another.
+    Create { task_id: Uuid, input: CreateTask },
+}
+
+/// Task command for the tasks worker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskCommand {
+    /// Unique command ID, kept across retries for idempotency
+    pub id: Uuid,
+
+    /// The work to do
+    pub action: TaskAction,
+
+    /// Current retry count
+    #[serde(default)]
+    pub retry_count: u32,
+
+    /// When the command was issued
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskCommand {
+    fn new(action: TaskAction) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            action,
+            retry_count: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Command to complete a task
+    pub fn complete(task_id: Uuid) -> Self {
+        Self::new(TaskAction::Complete { task_id })
+    }
+
+    /// Command to create a task under a new ID
+    pub fn create(input: CreateTask) -> Self {
+        Self::create_with_id(Uuid::now_v7(), input)
+    }
+
+    /// Command to create a task under `task_id`
+    pub fn create_with_id(task_id: Uuid, input: CreateTask) -> Self {
+        Self::new(TaskAction::Create { task_id, input })
+    }
+}
+
+impl messaging::Job for TaskCommand {
+    fn job_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn retry_count(&self) -> u32 {
+        self.retry_count
+    }
+
+    fn with_retry(&self) -> Self {
+        Self {
+            retry_count: self.retry_count + 1,
+            ..self.clone()
+        }
+    }
+
+    fn job_type(&self) -> &'static str {
+        match self.action {
+            TaskAction::Complete { .. } => "task_complete",
+            TaskAction::Create { .. } => "task_create",
+        }
+    }
+}
@@ -42,18 +42,23 @@ pub mod conversions;
 pub mod entity;
 pub mod error;
 pub mod handlers;
+pub mod job;
 pub mod models;
 pub mod postgres;
+pub mod processor;
 pub mod repository;
 pub mod service;
 
 // Re-export commonly used types
 pub use error::{TaskError, TaskResult};
 pub use handlers::{DirectApiDoc, GrpcApiDoc};
+pub use job::{TaskAction, TaskCommand};
 pub use models::{
-    CreateTask, Task, TaskFilter, TaskPriority, TaskResponse, TaskStatus, UpdateTask,
+    CreateTask, RecurrenceFrequency, RecurrenceRule, Task, TaskFilter, TaskPriority, TaskResponse,
+    TaskStatus, TaskTree, UpdateTask,
 };
 pub use postgres::PgTaskRepository;
+pub use processor::{TaskCommandQueue, TaskProcessor, DEFAULT_MAX_OCCURRENCES};
 pub use repository::TaskRepository;
 pub use service::TaskService;
 
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Months, Utc};
 use sea_orm::{DeriveActiveEnum, EnumIter};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
@@ -75,6 +75,69 @@ pub enum TaskStatus {
     Done,
 }
 
+/// Unit of a recurrence interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurrenceFrequency {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Schedule for a task that regenerates once it is completed
+///
+/// The next occurrence is due `interval` units after the completion time,
+/// so a late completion pushes the whole series back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema, TS)]
+#[ts(export)]
+pub struct RecurrenceRule {
+    /// Unit of the repeat interval
+    pub frequency: RecurrenceFrequency,
+    /// Repeat every `interval` units
+    #[serde(default = "default_interval")]
+    #[validate(range(min = 1))]
+    pub interval: u32,
+    /// Stop the series after this many occurrences
+    #[validate(range(min = 1))]
+    pub max_occurrences: Option<u32>,
+    /// Position of this task in its series, starting at 1
+    #[serde(default = "default_occurrence")]
+    #[validate(range(min = 1))]
+    pub occurrence: u32,
+}
+
+fn default_interval() -> u32 {
+    1
+}
+
+fn default_occurrence() -> u32 {
+    1
+}
+
+impl RecurrenceRule {
+    /// When the occurrence after one completed at `completed_at` is due,
+    /// or `None` if that is out of range
+    pub fn next_after(&self, completed_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let interval = i64::from(self.interval);
+        match self.frequency {
+            RecurrenceFrequency::Hourly => {
+                completed_at.checked_add_signed(Duration::try_hours(interval)?)
+            }
+            RecurrenceFrequency::Daily => {
+                completed_at.checked_add_signed(Duration::try_days(interval)?)
+            }
+            RecurrenceFrequency::Weekly => {
+                completed_at.checked_add_signed(Duration::try_weeks(interval)?)
+            }
+            RecurrenceFrequency::Monthly => {
+                completed_at.checked_add_months(Months::new(self.interval))
+            }
+        }
+    }
+}
+
 /// Task entity - represents a task
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, TS)]
 #[ts(export)]
@@ -91,6 +154,9 @@ pub struct Task {
     /// Optional project association
     #[ts(as = "Option<String>")]
     pub project_id: Option<Uuid>,
+    /// Parent task when this is a subtask
+    #[ts(as = "Option<String>")]
+    pub parent_id: Option<Uuid>,
     /// Task priority
     pub priority: TaskPriority,
     /// Task status
@@ -98,16 +164,21 @@ pub struct Task {
     /// Optional due date
     #[ts(as = "Option<String>")]
     pub due_date: Option<DateTime<Utc>>,
+    /// Schedule for regenerating the task once completed
+    pub recurrence: Option<RecurrenceRule>,
     /// Creation timestamp
     #[ts(as = "String")]
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
     #[ts(as = "String")]
     pub updated_at: DateTime<Utc>,
+    /// When the task was soft-deleted, if it has been
+    #[ts(as = "Option<String>")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// DTO for creating a new task
-#[derive(Debug, Clone, Deserialize, Validate, ToSchema, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema, TS)]
 #[ts(export)]
 pub struct CreateTask {
     #[validate(length(min = 1, max = 255))]
@@ -116,12 +187,17 @@ pub struct CreateTask {
     pub description: String,
     #[ts(as = "Option<String>")]
     pub project_id: Option<Uuid>,
+    #[ts(as = "Option<String>")]
+    pub parent_id: Option<Uuid>,
     #[serde(default)]
     pub priority: TaskPriority,
     #[serde(default)]
     pub status: TaskStatus,
     #[ts(as = "Option<String>")]
     pub due_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    #[validate(nested)]
+    pub recurrence: Option<RecurrenceRule>,
 }
 
 /// DTO for updating an existing task
@@ -134,10 +210,14 @@ pub struct UpdateTask {
     pub completed: Option<bool>,
     #[ts(as = "Option<Option<String>>")]
     pub project_id: Option<Option<Uuid>>,
+    #[ts(as = "Option<Option<String>>")]
+    pub parent_id: Option<Option<Uuid>>,
     pub priority: Option<TaskPriority>,
     pub status: Option<TaskStatus>,
     #[ts(as = "Option<Option<String>>")]
     pub due_date: Option<Option<DateTime<Utc>>>,
+    #[validate(nested)]
+    pub recurrence: Option<Option<RecurrenceRule>>,
 }
 
 /// Query filters for listing tasks
@@ -147,6 +227,11 @@ pub struct TaskFilter {
     pub status: Option<TaskStatus>,
     pub priority: Option<TaskPriority>,
     pub completed: Option<bool>,
+    /// Full-text search over title and description; results are ordered by relevance
+    pub query: Option<String>,
+    /// Include soft-deleted tasks alongside live ones
+    #[serde(default)]
+    pub include_deleted: bool,
     #[serde(default = "default_limit")]
     pub limit: usize,
     #[serde(default)]
@@ -165,11 +250,14 @@ pub struct TaskResponse {
     pub description: String,
     pub completed: bool,
     pub project_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
     pub priority: TaskPriority,
     pub status: TaskStatus,
     pub due_date: Option<DateTime<Utc>>,
+    pub recurrence: Option<RecurrenceRule>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl From<Task> for TaskResponse {
@@ -180,16 +268,46 @@ impl From<Task> for TaskResponse {
             description: task.description,
             completed: task.completed,
             project_id: task.project_id,
+            parent_id: task.parent_id,
             priority: task.priority,
             status: task.status,
             due_date: task.due_date,
+            recurrence: task.recurrence,
             created_at: task.created_at,
             updated_at: task.updated_at,
+            deleted_at: task.deleted_at,
         }
     }
 }
 
+/// A task with its subtasks, nested to any depth
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TaskTree {
+    pub task: Task,
+    #[schema(no_recursion)]
+    pub children: Vec<TaskTree>,
+}
+
 impl Task {
+    /// Substring fallback for `TaskFilter::query` where full-text search
+    /// isn't available: every word must appear (case-insensitively) in the
+    /// title or description. Returns how many words hit the title, so
+    /// callers can rank title matches first; `None` means no match.
+    pub fn text_match_score(&self, query: &str) -> Option<usize> {
+        let title = self.title.to_lowercase();
+        let description = self.description.to_lowercase();
+
+        let mut title_hits = 0;
+        for word in query.split_whitespace().map(str::to_lowercase) {
+            if title.contains(&word) {
+                title_hits += 1;
+            } else if !description.contains(&word) {
+                return None;
+            }
+        }
+        Some(title_hits)
+    }
+
     /// Apply updates from UpdateTask DTO
     pub fn apply_update(&mut self, update: UpdateTask) {
         if let Some(title) = update.title {
@@ -204,6 +322,9 @@ impl Task {
         if let Some(project_id) = update.project_id {
             self.project_id = project_id;
         }
+        if let Some(parent_id) = update.parent_id {
+            self.parent_id = parent_id;
+        }
         if let Some(priority) = update.priority {
             self.priority = priority;
         }
@@ -213,6 +334,134 @@ impl Task {
         if let Some(due_date) = update.due_date {
             self.due_date = due_date;
         }
+        if let Some(recurrence) = update.recurrence {
+            self.recurrence = recurrence;
+        }
         self.updated_at = chrono::Utc::now();
     }
+
+    /// The follow-up task for a recurring task completed at `completed_at`
+    ///
+    /// `None` when the task doesn't recur or its series is over; a series
+    /// ends at the rule's `max_occurrences` and never runs past `cap`.
+    pub fn next_occurrence(&self, completed_at: DateTime<Utc>, cap: u32) -> Option<CreateTask> {
+        let rule = self.recurrence.as_ref()?;
+        let limit = rule.max_occurrences.map_or(cap, |max| max.min(cap));
+        if rule.occurrence >= limit {
+            return None;
+        }
+
+        Some(CreateTask {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            project_id: self.project_id,
+            parent_id: self.parent_id,
+            priority: self.priority,
+            status: TaskStatus::Todo,
+            due_date: Some(rule.next_after(completed_at)?),
+            recurrence: Some(RecurrenceRule {
+                occurrence: rule.occurrence + 1,
+                ..rule.clone()
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(title: &str, description: &str) -> Task {
+        let now = Utc::now();
+        Task {
+            id: Uuid::now_v7(),
+            title: title.to_string(),
+            description: description.to_string(),
+            completed: false,
+            project_id: None,
+            parent_id: None,
+            priority: TaskPriority::Medium,
+            status: TaskStatus::Todo,
+            due_date: None,
+            recurrence: None,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_text_match_score() {
+        let t = task("Deploy Billing service", "roll out to staging");
+
+        assert_eq!(t.text_match_score("billing DEPLOY"), Some(2));
+        assert_eq!(t.text_match_score("billing staging"), Some(1));
+        assert_eq!(t.text_match_score("staging"), Some(0));
+        assert_eq!(t.text_match_score("billing kubernetes"), None);
+    }
+
+    fn daily(occurrence: u32, max_occurrences: Option<u32>) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: RecurrenceFrequency::Daily,
+            interval: 1,
+            max_occurrences,
+            occurrence,
+        }
+    }
+
+    #[test]
+    fn test_recurrence_next_after() {
+        let at = DateTime::parse_from_rfc3339("2024-01-31T09:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let next = |frequency, interval| {
+            RecurrenceRule {
+                frequency,
+                interval,
+                max_occurrences: None,
+                occurrence: 1,
+            }
+            .next_after(at)
+            .unwrap()
+            .to_rfc3339()
+        };
+
+        assert_eq!(
+            next(RecurrenceFrequency::Hourly, 2),
+            "2024-01-31T11:30:00+00:00"
+        );
+        assert_eq!(
+            next(RecurrenceFrequency::Daily, 1),
+            "2024-02-01T09:30:00+00:00"
+        );
+        assert_eq!(
+            next(RecurrenceFrequency::Weekly, 1),
+            "2024-02-07T09:30:00+00:00"
+        );
+        // Month ends clamp to the last day of the shorter month
+        assert_eq!(
+            next(RecurrenceFrequency::Monthly, 1),
+            "2024-02-29T09:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence_respects_caps() {
+        let now = Utc::now();
+        let mut t = task("Water plants", "");
+        assert!(t.next_occurrence(now, 100).is_none());
+
+        t.recurrence = Some(daily(1, Some(3)));
+        let next = t.next_occurrence(now, 100).unwrap();
+        assert_eq!(next.status, TaskStatus::Todo);
+        assert_eq!(next.recurrence.unwrap().occurrence, 2);
+
+        // The rule's own limit
+        t.recurrence = Some(daily(3, Some(3)));
+        assert!(t.next_occurrence(now, 100).is_none());
+
+        // The caller's hard cap wins over an open-ended rule
+        t.recurrence = Some(daily(100, None));
+        assert!(t.next_occurrence(now, 100).is_none());
+    }
 }
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use database::BaseRepository;
+use sea_orm::sea_query::Expr;
 use sea_orm::ActiveValue::Set;
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait,
+    Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Statement,
+    TransactionTrait,
 };
 use uuid::Uuid;
 
@@ -14,6 +16,10 @@ use crate::{
     repository::TaskRepository,
 };
 
+/// Weighted document for full-text search; title terms (A) outrank
+/// description terms (B). Must match the expression of `idx_tasks_search`.
+const SEARCH_DOCUMENT: &str = "setweight(to_tsvector('english', title), 'A') || setweight(to_tsvector('english', description), 'B')";
+
 pub struct PgTaskRepository {
     base: BaseRepository<entity::Entity>,
 }
@@ -24,6 +30,57 @@ impl PgTaskRepository {
             base: BaseRepository::new(db),
         }
     }
+
+    /// Tasks that haven't been soft-deleted
+    fn live() -> Select<entity::Entity> {
+        entity::Entity::find().filter(entity::Column::DeletedAt.is_null())
+    }
+
+    fn filtered(filter: &TaskFilter) -> Select<entity::Entity> {
+        let mut query = if filter.include_deleted {
+            entity::Entity::find()
+        } else {
+            Self::live()
+        };
+
+        if let Some(project_id) = filter.project_id {
+            query = query.filter(entity::Column::ProjectId.eq(project_id));
+        }
+
+        if let Some(status) = filter.status {
+            query = query.filter(entity::Column::Status.eq(status));
+        }
+
+        if let Some(priority) = filter.priority {
+            query = query.filter(entity::Column::Priority.eq(priority));
+        }
+
+        if let Some(completed) = filter.completed {
+            query = query.filter(entity::Column::Completed.eq(completed));
+        }
+
+        if let Some(text) = filter
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+        {
+            query = query
+                .filter(Expr::cust_with_values(
+                    format!("({SEARCH_DOCUMENT}) @@ plainto_tsquery('english', $1)"),
+                    [text],
+                ))
+                .order_by(
+                    Expr::cust_with_values(
+                        format!("ts_rank({SEARCH_DOCUMENT}, plainto_tsquery('english', $1))"),
+                        [text],
+                    ),
+                    Order::Desc,
+                );
+        }
+
+        query
+    }
 }
 
 #[async_trait]
@@ -43,35 +100,58 @@ impl TaskRepository for PgTaskRepository {
         Ok(model.into())
     }
 
-    async fn get_by_id(&self, id: Uuid) -> TaskResult<Option<Task>> {
+    async fn create_with_id(&self, id: Uuid, input: CreateTask) -> TaskResult<Task> {
+        let mut active_model: entity::ActiveModel = input.into();
+        active_model.id = Set(id);
+
         let model = self
             .base
-            .find_by_id(id)
+            .insert(active_model)
             .await
             .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
 
-        Ok(model.map(|m| m.into()))
+        tracing::info!(task_id = %model.id, "Created task");
+        Ok(model.into())
     }
 
-    async fn list(&self, filter: TaskFilter) -> TaskResult<Vec<Task>> {
-        let mut query = entity::Entity::find();
+    async fn create_batch(&self, inputs: Vec<CreateTask>) -> TaskResult<Vec<Task>> {
+        let txn = self
+            .base
+            .db()
+            .begin()
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
 
-        // Apply filters
-        if let Some(project_id) = filter.project_id {
-            query = query.filter(entity::Column::ProjectId.eq(project_id));
+        let mut tasks = Vec::with_capacity(inputs.len());
+        for (index, input) in inputs.into_iter().enumerate() {
+            let active_model: entity::ActiveModel = input.into();
+            // Returning early drops `txn`, which rolls the batch back
+            let model = active_model.insert(&txn).await.map_err(|e| {
+                TaskError::Internal(format!("Database error: {}", e)).at_batch_index(index)
+            })?;
+            tasks.push(model.into());
         }
 
-        if let Some(status) = filter.status {
-            query = query.filter(entity::Column::Status.eq(status));
-        }
+        txn.commit()
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
 
-        if let Some(priority) = filter.priority {
-            query = query.filter(entity::Column::Priority.eq(priority));
-        }
+        tracing::info!(count = tasks.len(), "Created task batch");
+        Ok(tasks)
+    }
 
-        if let Some(completed) = filter.completed {
-            query = query.filter(entity::Column::Completed.eq(completed));
-        }
+    async fn get_by_id(&self, id: Uuid) -> TaskResult<Option<Task>> {
+        let model = Self::live()
+            .filter(entity::Column::Id.eq(id))
+            .one(self.base.db())
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
+
+        Ok(model.map(|m| m.into()))
+    }
+
+    async fn list(&self, filter: TaskFilter) -> TaskResult<Vec<Task>> {
+        let mut query = Self::filtered(&filter);
 
         // Apply pagination and ordering
         query = query
@@ -89,9 +169,9 @@ impl TaskRepository for PgTaskRepository {
 
     async fn update(&self, id: Uuid, input: UpdateTask) -> TaskResult<Task> {
         // Fetch existing task
-        let model = self
-            .base
-            .find_by_id(id)
+        let model = Self::live()
+            .filter(entity::Column::Id.eq(id))
+            .one(self.base.db())
             .await
             .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?
             .ok_or(TaskError::NotFound(id))?;
@@ -109,11 +189,14 @@ impl TaskRepository for PgTaskRepository {
             description: Set(task.description.clone()),
             completed: Set(task.completed),
             project_id: Set(task.project_id),
+            parent_id: Set(task.parent_id),
             priority: Set(task.priority),
             status: Set(task.status),
             due_date: Set(task.due_date.map(Into::into)),
+            recurrence: Set(entity::recurrence_to_json(task.recurrence.clone())),
             created_at: Set(task.created_at.into()),
             updated_at: Set(task.updated_at.into()),
+            deleted_at: Set(task.deleted_at.map(Into::into)),
         };
 
         // Update using base repository
@@ -128,22 +211,94 @@ impl TaskRepository for PgTaskRepository {
     }
 
     async fn delete(&self, id: Uuid) -> TaskResult<bool> {
-        let rows_affected = self
+        let now = chrono::Utc::now();
+        let result = entity::Entity::update_many()
+            .col_expr(entity::Column::DeletedAt, Expr::value(now))
+            .col_expr(entity::Column::UpdatedAt, Expr::value(now))
+            .filter(entity::Column::Id.eq(id))
+            .filter(entity::Column::DeletedAt.is_null())
+            .exec(self.base.db())
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
+
+        if result.rows_affected > 0 {
+            tracing::info!(task_id = %id, "Soft deleted task");
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    async fn delete_subtree(&self, id: Uuid) -> TaskResult<u64> {
+        // UNION (not UNION ALL) stops the walk should a cycle ever exist
+        let sql = r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id FROM tasks WHERE id = $1 AND deleted_at IS NULL
+                UNION
+                SELECT t.id FROM tasks t
+                JOIN subtree s ON t.parent_id = s.id
+                WHERE t.deleted_at IS NULL
+            )
+            UPDATE tasks SET deleted_at = now(), updated_at = now()
+            WHERE id IN (SELECT id FROM subtree)
+        "#;
+
+        let stmt = Statement::from_sql_and_values(DbBackend::Postgres, sql, [id.into()]);
+        let result = self
             .base
-            .delete_by_id(id)
+            .db()
+            .execute_raw(stmt)
             .await
             .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
 
-        if rows_affected > 0 {
-            tracing::info!(task_id = %id, "Deleted task");
+        let deleted = result.rows_affected();
+        if deleted > 0 {
+            tracing::info!(task_id = %id, count = deleted, "Soft deleted task subtree");
+        }
+        Ok(deleted)
+    }
+
+    async fn restore(&self, id: Uuid) -> TaskResult<bool> {
+        let result = entity::Entity::update_many()
+            .col_expr(
+                entity::Column::DeletedAt,
+                Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None),
+            )
+            .col_expr(entity::Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+            .filter(entity::Column::Id.eq(id))
+            .filter(entity::Column::DeletedAt.is_not_null())
+            .exec(self.base.db())
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
+
+        if result.rows_affected > 0 {
+            tracing::info!(task_id = %id, "Restored task");
             Ok(true)
         } else {
             Ok(false)
         }
     }
 
+    async fn list_deleted(&self, filter: TaskFilter) -> TaskResult<Vec<Task>> {
+        let filter = TaskFilter {
+            include_deleted: true,
+            ..filter
+        };
+
+        let models = Self::filtered(&filter)
+            .filter(entity::Column::DeletedAt.is_not_null())
+            .order_by_desc(entity::Column::DeletedAt)
+            .limit(filter.limit as u64)
+            .offset(filter.offset as u64)
+            .all(self.base.db())
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
+
+        Ok(models.into_iter().map(|m| m.into()).collect())
+    }
+
     async fn count(&self) -> TaskResult<usize> {
-        let count = entity::Entity::find()
+        let count = Self::live()
             .count(self.base.db())
             .await
             .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
@@ -151,8 +306,19 @@ impl TaskRepository for PgTaskRepository {
         Ok(count as usize)
     }
 
+    async fn list_children(&self, parent_id: Uuid) -> TaskResult<Vec<Task>> {
+        let models = Self::live()
+            .filter(entity::Column::ParentId.eq(parent_id))
+            .order_by_asc(entity::Column::CreatedAt)
+            .all(self.base.db())
+            .await
+            .map_err(|e| TaskError::Internal(format!("Database error: {}", e)))?;
+
+        Ok(models.into_iter().map(|m| m.into()).collect())
+    }
+
     async fn count_by_project(&self, project_id: Uuid) -> TaskResult<usize> {
-        let count = entity::Entity::find()
+        let count = Self::live()
             .filter(entity::Column::ProjectId.eq(project_id))
             .count(self.base.db())
             .await
@@ -0,0 +1,418 @@
+//! TaskProcessor - Implements `messaging::Processor` for task commands
+//!
+//! Completing a recurring task schedules its next occurrence by enqueueing
+//! a `TaskAction::Create` command, which the same processor handles later.
+//! The follow-up's ID is derived from the completed task and occurrence
+//! number, so retries and redeliveries converge on a single follow-up.
+
+use async_trait::async_trait;
+use messaging::{ProcessingError, Processor};
+use std::sync::Arc;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::error::TaskError;
+use crate::job::{TaskAction, TaskCommand};
+use crate::models::CreateTask;
+use crate::repository::TaskRepository;
+use crate::service::TaskService;
+
+/// Hard limit on the length of any recurring series, so a bad rule can't
+/// regenerate a task forever
+pub const DEFAULT_MAX_OCCURRENCES: u32 = 1000;
+
+/// Destination for commands the processor issues itself
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TaskCommandQueue: Send + Sync {
+    async fn enqueue(&self, command: TaskCommand) -> Result<(), ProcessingError>;
+}
+
+#[cfg(feature = "nats")]
+#[async_trait]
+impl TaskCommandQueue for messaging::NatsProducer {
+    async fn enqueue(&self, command: TaskCommand) -> Result<(), ProcessingError> {
+        self.send(&command)
+            .await
+            .map(|_| ())
+            .map_err(|e| ProcessingError::transient(e.to_string()))
+    }
+}
+
+/// Processor for `TaskCommand` jobs
+pub struct TaskProcessor<R: TaskRepository, Q: TaskCommandQueue> {
+    service: Arc<TaskService<R>>,
+    queue: Q,
+    max_occurrences: u32,
+}
+
+impl<R: TaskRepository, Q: TaskCommandQueue> TaskProcessor<R, Q> {
+    pub fn new(service: TaskService<R>, queue: Q) -> Self {
+        Self {
+            service: Arc::new(service),
+            queue,
+            max_occurrences: DEFAULT_MAX_OCCURRENCES,
+        }
+    }
+
+    /// Cap every recurring series at `max_occurrences`, whatever its rule says
+    pub fn with_max_occurrences(mut self, max_occurrences: u32) -> Self {
+        self.max_occurrences = max_occurrences;
+        self
+    }
+
+    /// Complete a task and enqueue its next occurrence, if any
+    ///
+    /// A task that is already completed isn't updated again, but its
+    /// follow-up is still enqueued: the previous attempt may have failed
+    /// between completing and enqueueing. The follow-up has a deterministic
+    /// ID, so enqueueing it twice still creates only one task.
+    async fn complete(&self, task_id: Uuid) -> Result<(), ProcessingError> {
+        let task = self
+            .service
+            .get_task(task_id)
+            .await
+            .map_err(processing_error)?;
+
+        let task = if task.completed {
+            debug!(task_id = %task_id, "Task already completed, re-enqueueing follow-up");
+            task
+        } else {
+            self.service
+                .complete_task(task_id)
+                .await
+                .map_err(processing_error)?
+        };
+
+        if let Some(next) = task.next_occurrence(task.updated_at, self.max_occurrences) {
+            let occurrence = next.recurrence.as_ref().map_or(0, |rule| rule.occurrence);
+            let next_id = follow_up_id(task_id, occurrence);
+            info!(
+                task_id = %task_id,
+                next_id = %next_id,
+                due_date = ?next.due_date,
+                "Scheduling next occurrence"
+            );
+            self.queue
+                .enqueue(TaskCommand::create_with_id(next_id, next))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a task unless an earlier delivery of the command already did
+    async fn create(&self, task_id: Uuid, input: &CreateTask) -> Result<(), ProcessingError> {
+        match self.service.get_task(task_id).await {
+            Ok(_) => {
+                debug!(task_id = %task_id, "Task already created, skipping");
+                return Ok(());
+            }
+            Err(TaskError::NotFound(_)) => {}
+            Err(e) => return Err(processing_error(e)),
+        }
+
+        let task = self
+            .service
+            .create_task_with_id(task_id, input.clone())
+            .await
+            .map_err(processing_error)?;
+
+        info!(task_id = %task.id, "Created task from command");
+        Ok(())
+    }
+}
+
+/// ID of the `occurrence`-th task in the series continued by `task_id`
+fn follow_up_id(task_id: Uuid, occurrence: u32) -> Uuid {
+    Uuid::new_v5(&task_id, format!("occurrence:{}", occurrence).as_bytes())
+}
+
+/// Bad input won't succeed on retry; storage failures might
+fn processing_error(err: TaskError) -> ProcessingError {
+    match err {
+        TaskError::NotFound(_)
+        | TaskError::Validation(_)
+        | TaskError::Conflict(_)
+        | TaskError::BatchItem { .. } => ProcessingError::permanent(err.to_string()),
+        TaskError::Internal(_) | TaskError::Database(_) => {
+            ProcessingError::transient(err.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl<R: TaskRepository + 'static, Q: TaskCommandQueue + 'static> Processor<TaskCommand>
+    for TaskProcessor<R, Q>
+{
+    async fn process(&self, job: &TaskCommand) -> Result<(), ProcessingError> {
+        debug!(job_id = %job.id, action = ?job.action, "Processing task command");
+
+        match &job.action {
+            TaskAction::Complete { task_id } => self.complete(*task_id).await,
+            TaskAction::Create { task_id, input } => self.create(*task_id, input).await,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "task_processor"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RecurrenceFrequency, RecurrenceRule, Task};
+    use crate::repository::MockTaskRepository;
+    use chrono::{DateTime, Duration, Utc};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    fn completed_at() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-03-10T17:45:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn recurring_task(recurrence: Option<RecurrenceRule>) -> Task {
+        let created = completed_at() - Duration::days(1);
+        Task {
+            id: Uuid::now_v7(),
+            title: "Stand-up notes".to_string(),
+            description: "Post in the team channel".to_string(),
+            completed: false,
+            project_id: Some(Uuid::now_v7()),
+            parent_id: None,
+            priority: Default::default(),
+            status: Default::default(),
+            due_date: Some(created),
+            recurrence,
+            created_at: created,
+            updated_at: created,
+            deleted_at: None,
+        }
+    }
+
+    fn daily() -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: RecurrenceFrequency::Daily,
+            interval: 1,
+            max_occurrences: None,
+            occurrence: 1,
+        }
+    }
+
+    /// Repository holding `task`; updates mark it completed at `completed_at()`
+    fn repo_completing(task: Task) -> MockTaskRepository {
+        let mut repo = MockTaskRepository::new();
+
+        let stored = task.clone();
+        repo.expect_get_by_id()
+            .returning(move |_| Ok(Some(stored.clone())));
+        repo.expect_update().times(1).returning(move |_, update| {
+            let mut task = task.clone();
+            task.apply_update(update);
+            task.updated_at = completed_at();
+            Ok(task)
+        });
+
+        repo
+    }
+
+    /// Queue that records everything enqueued
+    fn recording_queue() -> (MockTaskCommandQueue, Arc<Mutex<Vec<TaskCommand>>>) {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let recorder = sent.clone();
+        let mut queue = MockTaskCommandQueue::new();
+        queue.expect_enqueue().returning(move |command| {
+            recorder.lock().unwrap().push(command);
+            Ok(())
+        });
+        (queue, sent)
+    }
+
+    #[tokio::test]
+    async fn test_completing_daily_task_enqueues_next_occurrence() {
+        let task = recurring_task(Some(daily()));
+        let task_id = task.id;
+        let (queue, sent) = recording_queue();
+        let processor = TaskProcessor::new(TaskService::new(repo_completing(task.clone())), queue);
+
+        processor
+            .process(&TaskCommand::complete(task_id))
+            .await
+            .unwrap();
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        let TaskAction::Create {
+            task_id: next_id,
+            input,
+        } = &sent[0].action
+        else {
+            panic!("expected a create command, got {:?}", sent[0].action);
+        };
+        assert_eq!(*next_id, follow_up_id(task_id, 2));
+        assert_eq!(input.title, task.title);
+        assert_eq!(input.project_id, task.project_id);
+        assert_eq!(input.due_date, Some(completed_at() + Duration::days(1)));
+        assert_eq!(input.recurrence.as_ref().unwrap().occurrence, 2);
+    }
+
+    #[tokio::test]
+    async fn test_completing_plain_task_enqueues_nothing() {
+        let task = recurring_task(None);
+        let task_id = task.id;
+        let mut queue = MockTaskCommandQueue::new();
+        queue.expect_enqueue().never();
+        let processor = TaskProcessor::new(TaskService::new(repo_completing(task)), queue);
+
+        processor
+            .process(&TaskCommand::complete(task_id))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_series_stops_at_max_occurrences() {
+        let task = recurring_task(Some(RecurrenceRule {
+            occurrence: 5,
+            ..daily()
+        }));
+        let task_id = task.id;
+        let mut queue = MockTaskCommandQueue::new();
+        queue.expect_enqueue().never();
+        let processor = TaskProcessor::new(TaskService::new(repo_completing(task)), queue)
+            .with_max_occurrences(5);
+
+        processor
+            .process(&TaskCommand::complete(task_id))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_already_completed_task_reenqueues_same_follow_up() {
+        let task = Task {
+            completed: true,
+            updated_at: completed_at(),
+            ..recurring_task(Some(daily()))
+        };
+        let task_id = task.id;
+        let mut repo = MockTaskRepository::new();
+        repo.expect_get_by_id()
+            .returning(move |_| Ok(Some(task.clone())));
+        repo.expect_update().never();
+        let (queue, sent) = recording_queue();
+        let processor = TaskProcessor::new(TaskService::new(repo), queue);
+
+        processor
+            .process(&TaskCommand::complete(task_id))
+            .await
+            .unwrap();
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(matches!(
+            sent[0].action,
+            TaskAction::Create { task_id: next_id, .. } if next_id == follow_up_id(task_id, 2)
+        ));
+    }
+
+    /// Repository backed by `tasks`, so state carries across deliveries
+    fn in_memory_repo(tasks: Arc<Mutex<HashMap<Uuid, Task>>>) -> MockTaskRepository {
+        let mut repo = MockTaskRepository::new();
+
+        let store = tasks.clone();
+        repo.expect_get_by_id()
+            .returning(move |id| Ok(store.lock().unwrap().get(&id).cloned()));
+
+        let store = tasks.clone();
+        repo.expect_update().returning(move |id, update| {
+            let mut tasks = store.lock().unwrap();
+            let task = tasks.get_mut(&id).unwrap();
+            task.apply_update(update);
+            task.updated_at = completed_at();
+            Ok(task.clone())
+        });
+
+        repo.expect_create_with_id().returning(move |id, input| {
+            let task = Task {
+                id,
+                title: input.title,
+                description: input.description,
+                project_id: input.project_id,
+                parent_id: input.parent_id,
+                due_date: input.due_date,
+                recurrence: input.recurrence,
+                completed: false,
+                ..recurring_task(None)
+            };
+            tasks.lock().unwrap().insert(id, task.clone());
+            Ok(task)
+        });
+
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_failed_enqueue_is_retried_into_one_follow_up() {
+        let task = recurring_task(Some(daily()));
+        let task_id = task.id;
+        let tasks = Arc::new(Mutex::new(HashMap::from([(task_id, task)])));
+        let service = TaskService::new(in_memory_repo(tasks.clone()));
+
+        // The first enqueue fails after the task was already completed
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let recorder = sent.clone();
+        let mut queue = MockTaskCommandQueue::new();
+        let mut seq = mockall::Sequence::new();
+        queue
+            .expect_enqueue()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Err(ProcessingError::transient("queue unavailable")));
+        queue
+            .expect_enqueue()
+            .in_sequence(&mut seq)
+            .returning(move |command| {
+                recorder.lock().unwrap().push(command);
+                Ok(())
+            });
+        let processor = TaskProcessor::new(service, queue);
+
+        let command = TaskCommand::complete(task_id);
+        assert!(processor.process(&command).await.is_err());
+        assert!(tasks.lock().unwrap()[&task_id].completed);
+
+        // The redelivered command still schedules the follow-up
+        processor.process(&command).await.unwrap();
+        let creates = sent.lock().unwrap().clone();
+        assert_eq!(creates.len(), 1);
+
+        // Deliver the create twice, as a redelivery would
+        processor.process(&creates[0]).await.unwrap();
+        processor.process(&creates[0]).await.unwrap();
+
+        let tasks = tasks.lock().unwrap();
+        let follow_ups: Vec<&Task> = tasks.values().filter(|t| t.id != task_id).collect();
+        assert_eq!(follow_ups.len(), 1);
+        assert_eq!(follow_ups[0].id, follow_up_id(task_id, 2));
+        assert_eq!(follow_ups[0].recurrence.as_ref().unwrap().occurrence, 2);
+    }
+
+    #[tokio::test]
+    async fn test_missing_task_is_a_permanent_failure() {
+        let mut repo = MockTaskRepository::new();
+        repo.expect_get_by_id().returning(|_| Ok(None));
+        let mut queue = MockTaskCommandQueue::new();
+        queue.expect_enqueue().never();
+        let processor = TaskProcessor::new(TaskService::new(repo), queue);
+
+        let result = processor
+            .process(&TaskCommand::complete(Uuid::now_v7()))
+            .await;
+
+        assert!(matches!(result, Err(ProcessingError::Permanent { .. })));
+    }
+}
@@ -14,21 +14,46 @@ pub trait TaskRepository: Send + Sync {
     /// Create a new task
     async fn create(&self, input: CreateTask) -> TaskResult<Task>;
 
-    /// Get a task by ID
+    /// Create a task with a caller-chosen ID, e.g. one derived from the
+    /// command that asked for it so a retried command can't create it twice
+    async fn create_with_id(&self, id: Uuid, input: CreateTask) -> TaskResult<Task>;
+
+    /// Create several tasks atomically; if any insert fails, none are kept
+    /// and the error is a `TaskError::BatchItem` naming the failing index
+    async fn create_batch(&self, inputs: Vec<CreateTask>) -> TaskResult<Vec<Task>>;
+
+    /// Get a task by ID, unless it is soft-deleted
     async fn get_by_id(&self, id: Uuid) -> TaskResult<Option<Task>>;
 
-    /// List tasks with optional filters
+    /// List tasks with optional filters; soft-deleted tasks are skipped
+    /// unless `filter.include_deleted` is set
     async fn list(&self, filter: TaskFilter) -> TaskResult<Vec<Task>>;
 
     /// Update an existing task
     async fn update(&self, id: Uuid, input: UpdateTask) -> TaskResult<Task>;
 
-    /// Delete a task by ID
+    /// Soft-delete a task by ID; false if it is missing or already deleted
     async fn delete(&self, id: Uuid) -> TaskResult<bool>;
 
-    /// Count all tasks
+    /// Soft-delete a task together with all of its live descendants
+    ///
+    /// Runs as a single statement, so the subtree is removed atomically.
+    /// Returns the number of tasks deleted; 0 if the task is missing or
+    /// already deleted.
+    async fn delete_subtree(&self, id: Uuid) -> TaskResult<u64>;
+
+    /// Undo a soft delete; false if the task is missing or not deleted
+    async fn restore(&self, id: Uuid) -> TaskResult<bool>;
+
+    /// List soft-deleted tasks matching the filter, most recently deleted first
+    async fn list_deleted(&self, filter: TaskFilter) -> TaskResult<Vec<Task>>;
+
+    /// Count all live tasks
     async fn count(&self) -> TaskResult<usize>;
 
+    /// List direct subtasks of a task, oldest first
+    async fn list_children(&self, parent_id: Uuid) -> TaskResult<Vec<Task>>;
+
     /// Count tasks by project
     async fn count_by_project(&self, project_id: Uuid) -> TaskResult<usize>;
 }
@@ -1,25 +1,35 @@
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use tracing::instrument;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::error::{TaskError, TaskResult};
-use crate::models::{CreateTask, Task, TaskFilter, TaskStatus, UpdateTask};
+use crate::models::{CreateTask, Task, TaskFilter, TaskStatus, TaskTree, UpdateTask};
 use crate::repository::TaskRepository;
 
 /// Service layer for Task business logic
 #[derive(Clone)]
 pub struct TaskService<R: TaskRepository> {
     repository: Arc<R>,
+    cascade_delete: bool,
 }
 
 impl<R: TaskRepository> TaskService<R> {
     pub fn new(repository: R) -> Self {
         Self {
             repository: Arc::new(repository),
+            cascade_delete: false,
         }
     }
 
+    /// Delete subtasks along with their parent instead of refusing
+    /// (the default) to delete a task that still has subtasks
+    pub fn with_cascade_delete(mut self, cascade_delete: bool) -> Self {
+        self.cascade_delete = cascade_delete;
+        self
+    }
+
     /// Create a new task with validation
     #[instrument(skip(self, input), fields(task_title = %input.title))]
     pub async fn create_task(&self, input: CreateTask) -> TaskResult<Task> {
@@ -28,9 +38,52 @@ impl<R: TaskRepository> TaskService<R> {
             .validate()
             .map_err(|e| TaskError::Validation(e.to_string()))?;
 
+        if let Some(parent_id) = input.parent_id {
+            self.ensure_valid_parent(None, parent_id).await?;
+        }
+
         self.repository.create(input).await
     }
 
+    /// Create a task with a caller-chosen ID, validated like [`Self::create_task`]
+    pub async fn create_task_with_id(&self, id: Uuid, input: CreateTask) -> TaskResult<Task> {
+        input
+            .validate()
+            .map_err(|e| TaskError::Validation(e.to_string()))?;
+
+        if let Some(parent_id) = input.parent_id {
+            self.ensure_valid_parent(None, parent_id).await?;
+        }
+
+        self.repository.create_with_id(id, input).await
+    }
+
+    /// Create several tasks in one transaction
+    ///
+    /// Every item is validated before anything is written. Any failure
+    /// rejects the whole batch with a `TaskError::BatchItem` naming the
+    /// offending index.
+    #[instrument(skip(self, inputs), fields(batch_size = inputs.len()))]
+    pub async fn create_tasks_batch(&self, inputs: Vec<CreateTask>) -> TaskResult<Vec<Task>> {
+        for (index, input) in inputs.iter().enumerate() {
+            input
+                .validate()
+                .map_err(|e| TaskError::Validation(e.to_string()).at_batch_index(index))?;
+
+            if let Some(parent_id) = input.parent_id {
+                self.ensure_valid_parent(None, parent_id)
+                    .await
+                    .map_err(|e| e.at_batch_index(index))?;
+            }
+        }
+
+        if inputs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.repository.create_batch(inputs).await
+    }
+
     /// Get a task by ID
     #[instrument(skip(self), fields(task_id = %id))]
     pub async fn get_task(&self, id: Uuid) -> TaskResult<Task> {
@@ -53,13 +106,31 @@ impl<R: TaskRepository> TaskService<R> {
             .validate()
             .map_err(|e| TaskError::Validation(e.to_string()))?;
 
+        if let Some(Some(parent_id)) = input.parent_id {
+            self.ensure_valid_parent(Some(id), parent_id).await?;
+        }
+
         self.repository.update(id, input).await
     }
 
-    /// Delete a task
+    /// Soft-delete a task; it can be brought back with `restore_task`
+    ///
+    /// A task with subtasks is only deleted when cascade delete is enabled,
+    /// in which case its whole subtree is deleted with it in one statement.
     #[instrument(skip(self), fields(task_id = %id))]
     pub async fn delete_task(&self, id: Uuid) -> TaskResult<()> {
-        let deleted = self.repository.delete(id).await?;
+        let children = self.repository.list_children(id).await?;
+        let deleted = if children.is_empty() {
+            self.repository.delete(id).await?
+        } else if self.cascade_delete {
+            self.repository.delete_subtree(id).await? > 0
+        } else {
+            return Err(TaskError::Conflict(format!(
+                "Task {} has {} subtask(s)",
+                id,
+                children.len()
+            )));
+        };
 
         if !deleted {
             return Err(TaskError::NotFound(id));
@@ -68,6 +139,94 @@ impl<R: TaskRepository> TaskService<R> {
         Ok(())
     }
 
+    /// Undo a soft delete
+    ///
+    /// Only the task itself comes back; subtasks removed by a cascade
+    /// delete have to be restored individually.
+    #[instrument(skip(self), fields(task_id = %id))]
+    pub async fn restore_task(&self, id: Uuid) -> TaskResult<Task> {
+        if !self.repository.restore(id).await? {
+            return Err(TaskError::NotFound(id));
+        }
+
+        self.get_task(id).await
+    }
+
+    /// List soft-deleted tasks, most recently deleted first
+    pub async fn list_deleted(&self, filter: TaskFilter) -> TaskResult<Vec<Task>> {
+        self.repository.list_deleted(filter).await
+    }
+
+    /// List direct subtasks of a task
+    pub async fn list_children(&self, parent_id: Uuid) -> TaskResult<Vec<Task>> {
+        self.get_task(parent_id).await?;
+        self.repository.list_children(parent_id).await
+    }
+
+    /// Load a task and all of its subtasks as a nested tree
+    #[instrument(skip(self), fields(task_id = %root_id))]
+    pub async fn get_task_tree(&self, root_id: Uuid) -> TaskResult<TaskTree> {
+        let root = self.get_task(root_id).await?;
+
+        // Breadth-first load; `seen` guards against cycles in existing data
+        let mut children_of: HashMap<Uuid, Vec<Task>> = HashMap::new();
+        let mut seen = HashSet::from([root_id]);
+        let mut queue = VecDeque::from([root_id]);
+        while let Some(id) = queue.pop_front() {
+            let children: Vec<Task> = self
+                .repository
+                .list_children(id)
+                .await?
+                .into_iter()
+                .filter(|child| seen.insert(child.id))
+                .collect();
+            queue.extend(children.iter().map(|child| child.id));
+            children_of.insert(id, children);
+        }
+
+        Ok(assemble_tree(root, &mut children_of))
+    }
+
+    /// Check `parent_id` exists and is neither `task_id` nor one of its
+    /// descendants, which would create a cycle
+    async fn ensure_valid_parent(&self, task_id: Option<Uuid>, parent_id: Uuid) -> TaskResult<()> {
+        if task_id == Some(parent_id) {
+            return Err(TaskError::Validation(
+                "A task cannot be its own parent".to_string(),
+            ));
+        }
+
+        let parent =
+            self.repository.get_by_id(parent_id).await?.ok_or_else(|| {
+                TaskError::Validation(format!("Parent task {} not found", parent_id))
+            })?;
+
+        let Some(task_id) = task_id else {
+            return Ok(());
+        };
+
+        // Walk up from the new parent; reaching the task means a cycle
+        let mut seen = HashSet::from([parent_id]);
+        let mut ancestor_id = parent.parent_id;
+        while let Some(id) = ancestor_id {
+            if id == task_id {
+                return Err(TaskError::Validation(format!(
+                    "Task {} is a subtask of {}; moving it there would create a cycle",
+                    parent_id, task_id
+                )));
+            }
+            if !seen.insert(id) {
+                break;
+            }
+            ancestor_id = match self.repository.get_by_id(id).await? {
+                Some(ancestor) => ancestor.parent_id,
+                None => None,
+            };
+        }
+
+        Ok(())
+    }
+
     /// Mark a task as completed
     pub async fn complete_task(&self, id: Uuid) -> TaskResult<Task> {
         self.repository
@@ -106,3 +265,268 @@ impl<R: TaskRepository> TaskService<R> {
         self.repository.count_by_project(project_id).await
     }
 }
+
+fn assemble_tree(task: Task, children_of: &mut HashMap<Uuid, Vec<Task>>) -> TaskTree {
+    let children = children_of
+        .remove(&task.id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|child| assemble_tree(child, children_of))
+        .collect();
+    TaskTree { task, children }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::MockTaskRepository;
+
+    fn task(parent_id: Option<Uuid>) -> Task {
+        let now = chrono::Utc::now();
+        Task {
+            id: Uuid::now_v7(),
+            title: "Task".to_string(),
+            description: String::new(),
+            completed: false,
+            project_id: None,
+            parent_id,
+            priority: Default::default(),
+            status: Default::default(),
+            due_date: None,
+            recurrence: None,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+        }
+    }
+
+    /// Mock whose reads are served from a fixed set of tasks
+    fn repo_with(tasks: Vec<Task>) -> MockTaskRepository {
+        let tasks = Arc::new(tasks);
+        let mut repo = MockTaskRepository::new();
+
+        let by_id = tasks.clone();
+        repo.expect_get_by_id()
+            .returning(move |id| Ok(by_id.iter().find(|t| t.id == id).cloned()));
+
+        let children = tasks.clone();
+        repo.expect_list_children().returning(move |parent_id| {
+            Ok(children
+                .iter()
+                .filter(|t| t.parent_id == Some(parent_id))
+                .cloned()
+                .collect())
+        });
+
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_create_child_task() {
+        let parent = task(None);
+        let parent_id = parent.id;
+        let mut repo = repo_with(vec![parent]);
+        repo.expect_create().returning(|input| {
+            Ok(Task {
+                title: input.title,
+                ..task(input.parent_id)
+            })
+        });
+
+        let service = TaskService::new(repo);
+        let child = service
+            .create_task(CreateTask {
+                title: "Child".to_string(),
+                description: String::new(),
+                project_id: None,
+                parent_id: Some(parent_id),
+                priority: Default::default(),
+                status: Default::default(),
+                due_date: None,
+                recurrence: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(child.parent_id, Some(parent_id));
+    }
+
+    #[tokio::test]
+    async fn test_create_with_missing_parent_fails() {
+        let mut repo = repo_with(vec![]);
+        repo.expect_create().never();
+
+        let service = TaskService::new(repo);
+        let result = service
+            .create_task(CreateTask {
+                title: "Orphan".to_string(),
+                description: String::new(),
+                project_id: None,
+                parent_id: Some(Uuid::now_v7()),
+                priority: Default::default(),
+                status: Default::default(),
+                due_date: None,
+                recurrence: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(TaskError::Validation(_))));
+    }
+
+    fn new_task(title: &str) -> CreateTask {
+        CreateTask {
+            title: title.to_string(),
+            description: String::new(),
+            project_id: None,
+            parent_id: None,
+            priority: Default::default(),
+            status: Default::default(),
+            due_date: None,
+            recurrence: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_tasks_batch() {
+        let mut repo = repo_with(vec![]);
+        repo.expect_create_batch().times(1).returning(|inputs| {
+            Ok(inputs
+                .into_iter()
+                .map(|input| Task {
+                    title: input.title,
+                    ..task(None)
+                })
+                .collect())
+        });
+
+        let service = TaskService::new(repo);
+        let tasks = service
+            .create_tasks_batch(vec![new_task("One"), new_task("Two"), new_task("Three")])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            tasks.iter().map(|t| t.title.as_str()).collect::<Vec<_>>(),
+            vec!["One", "Two", "Three"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_tasks_batch_rejects_invalid_item() {
+        let mut repo = repo_with(vec![]);
+        repo.expect_create_batch().never();
+        repo.expect_create().never();
+
+        let service = TaskService::new(repo);
+        let result = service
+            .create_tasks_batch(vec![new_task("One"), new_task(""), new_task("Three")])
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(TaskError::BatchItem { index: 1, ref source })
+                if matches!(**source, TaskError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_self_parent() {
+        let existing = task(None);
+        let id = existing.id;
+        let mut repo = repo_with(vec![existing]);
+        repo.expect_update().never();
+
+        let service = TaskService::new(repo);
+        let result = service
+            .update_task(
+                id,
+                UpdateTask {
+                    parent_id: Some(Some(id)),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(TaskError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_descendant_as_parent() {
+        let root = task(None);
+        let child = task(Some(root.id));
+        let grandchild = task(Some(child.id));
+        let (root_id, grandchild_id) = (root.id, grandchild.id);
+        let mut repo = repo_with(vec![root, child, grandchild]);
+        repo.expect_update().never();
+
+        let service = TaskService::new(repo);
+        let result = service
+            .update_task(
+                root_id,
+                UpdateTask {
+                    parent_id: Some(Some(grandchild_id)),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(TaskError::Validation(msg)) if msg.contains("cycle")));
+    }
+
+    #[tokio::test]
+    async fn test_list_children_and_tree() {
+        let root = task(None);
+        let child_a = task(Some(root.id));
+        let child_b = task(Some(root.id));
+        let grandchild = task(Some(child_a.id));
+        let unrelated = task(None);
+        let ids = (root.id, child_a.id, child_b.id, grandchild.id);
+        let service = TaskService::new(repo_with(vec![
+            root, child_a, child_b, grandchild, unrelated,
+        ]));
+
+        let children = service.list_children(ids.0).await.unwrap();
+        assert_eq!(
+            children.iter().map(|t| t.id).collect::<Vec<_>>(),
+            vec![ids.1, ids.2]
+        );
+
+        let tree = service.get_task_tree(ids.0).await.unwrap();
+        assert_eq!(tree.task.id, ids.0);
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[0].children[0].task.id, ids.3);
+        assert!(tree.children[1].children.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_parent_blocked_by_default() {
+        let root = task(None);
+        let child = task(Some(root.id));
+        let root_id = root.id;
+        let mut repo = repo_with(vec![root, child]);
+        repo.expect_delete().never();
+
+        let service = TaskService::new(repo);
+        let result = service.delete_task(root_id).await;
+
+        assert!(matches!(result, Err(TaskError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_parent_cascades_when_enabled() {
+        let root = task(None);
+        let child = task(Some(root.id));
+        let grandchild = task(Some(child.id));
+        let root_id = root.id;
+
+        let mut repo = repo_with(vec![root, child, grandchild]);
+        repo.expect_delete().never();
+        repo.expect_delete_subtree()
+            .withf(move |id| *id == root_id)
+            .times(1)
+            .returning(|_| Ok(3));
+
+        let service = TaskService::new(repo).with_cascade_delete(true);
+        service.delete_task(root_id).await.unwrap();
+    }
+}
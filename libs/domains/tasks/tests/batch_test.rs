@@ -0,0 +1,73 @@
+//! Integration tests for batch task creation
+//!
+//! These tests use real PostgreSQL via testcontainers to ensure a failing
+//! item rolls back the whole batch transaction.
+
+use domain_tasks::*;
+use test_utils::TestDatabase;
+use uuid::Uuid;
+
+fn create(title: &str) -> CreateTask {
+    CreateTask {
+        title: title.to_string(),
+        description: String::new(),
+        project_id: None,
+        parent_id: None,
+        priority: TaskPriority::Medium,
+        status: TaskStatus::Todo,
+        due_date: None,
+        recurrence: None,
+    }
+}
+
+#[tokio::test]
+async fn test_create_batch_persists_all() {
+    let db = TestDatabase::new().await;
+    let service = TaskService::new(PgTaskRepository::new(db.connection()));
+
+    let tasks = service
+        .create_tasks_batch(vec![create("One"), create("Two"), create("Three")])
+        .await
+        .unwrap();
+
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(service.count_tasks().await.unwrap(), 3);
+    for task in tasks {
+        assert!(service.get_task(task.id).await.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn test_create_batch_with_invalid_item_persists_nothing() {
+    let db = TestDatabase::new().await;
+    let service = TaskService::new(PgTaskRepository::new(db.connection()));
+
+    let result = service
+        .create_tasks_batch(vec![create("One"), create(""), create("Three")])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(TaskError::BatchItem { index: 1, ref source })
+            if matches!(**source, TaskError::Validation(_))
+    ));
+    assert_eq!(service.count_tasks().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_create_batch_rolls_back_on_database_error() {
+    let db = TestDatabase::new().await;
+    let repo = PgTaskRepository::new(db.connection());
+
+    // Skipping the service's parent check lets the foreign key fail mid-batch
+    let orphan = CreateTask {
+        parent_id: Some(Uuid::now_v7()),
+        ..create("Orphan")
+    };
+    let result = repo
+        .create_batch(vec![create("One"), orphan, create("Three")])
+        .await;
+
+    assert!(matches!(result, Err(TaskError::BatchItem { index: 1, .. })));
+    assert_eq!(repo.count().await.unwrap(), 0);
+}
@@ -0,0 +1,79 @@
+//! Integration tests for full-text task search
+//!
+//! These tests use real PostgreSQL via testcontainers so the tsvector
+//! expression, GIN index migration and ranking are exercised for real.
+
+use domain_tasks::*;
+use test_utils::TestDatabase;
+
+fn create(title: &str, description: &str) -> CreateTask {
+    CreateTask {
+        title: title.to_string(),
+        description: description.to_string(),
+        project_id: None,
+        parent_id: None,
+        priority: TaskPriority::Medium,
+        status: TaskStatus::Todo,
+        due_date: None,
+        recurrence: None,
+    }
+}
+
+fn search(query: &str) -> TaskFilter {
+    TaskFilter {
+        query: Some(query.to_string()),
+        limit: 50,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_multi_word_query_matches_relevant_tasks() {
+    let db = TestDatabase::new().await;
+    let repo = PgTaskRepository::new(db.connection());
+
+    let both = repo
+        .create(create("Deploy billing service", "Roll out to staging"))
+        .await
+        .unwrap();
+    let split = repo
+        .create(create("Billing cleanup", "Finish the deployment checklist"))
+        .await
+        .unwrap();
+    repo.create(create("Billing report", "Monthly numbers"))
+        .await
+        .unwrap();
+    repo.create(create("Deploy docs", "Publish the handbook"))
+        .await
+        .unwrap();
+
+    // plainto_tsquery ANDs the words and stems "deploy"/"deployment"
+    let results = repo.list(search("deploy billing")).await.unwrap();
+    let mut ids: Vec<_> = results.iter().map(|t| t.id).collect();
+    ids.sort();
+    let mut expected = vec![both.id, split.id];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    assert!(repo.list(search("kubernetes")).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_title_matches_rank_above_description_matches() {
+    let db = TestDatabase::new().await;
+    let repo = PgTaskRepository::new(db.connection());
+
+    // Created first so that newest-first ordering alone would put it last
+    let in_title = repo
+        .create(create("Migrate invoices", "Move old records"))
+        .await
+        .unwrap();
+    let in_description = repo
+        .create(create("Data cleanup", "Migrate the legacy invoices table"))
+        .await
+        .unwrap();
+
+    let results = repo.list(search("migrate invoices")).await.unwrap();
+    let ids: Vec<_> = results.iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec![in_title.id, in_description.id]);
+}
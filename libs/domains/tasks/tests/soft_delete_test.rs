@@ -0,0 +1,164 @@
+//! Integration tests for task soft delete and restore
+//!
+//! These tests use real PostgreSQL via testcontainers to ensure deleted
+//! tasks stay in the table but drop out of the default queries.
+
+use domain_tasks::*;
+use test_utils::TestDatabase;
+
+fn create(title: &str) -> CreateTask {
+    CreateTask {
+        title: title.to_string(),
+        description: String::new(),
+        project_id: None,
+        parent_id: None,
+        priority: TaskPriority::Medium,
+        status: TaskStatus::Todo,
+        due_date: None,
+        recurrence: None,
+    }
+}
+
+fn all() -> TaskFilter {
+    TaskFilter {
+        limit: 50,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_deleted_task_is_hidden_and_restorable() {
+    let db = TestDatabase::new().await;
+    let service = TaskService::new(PgTaskRepository::new(db.connection()));
+
+    let kept = service.create_task(create("Kept")).await.unwrap();
+    let deleted = service.create_task(create("Deleted")).await.unwrap();
+    service.delete_task(deleted.id).await.unwrap();
+
+    // Hidden from the default queries
+    let ids: Vec<_> = service
+        .list_tasks(all())
+        .await
+        .unwrap()
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![kept.id]);
+    assert!(matches!(
+        service.get_task(deleted.id).await,
+        Err(TaskError::NotFound(_))
+    ));
+    assert_eq!(service.count_tasks().await.unwrap(), 1);
+
+    // Visible with the flag and in the deleted listing
+    let with_deleted = service
+        .list_tasks(TaskFilter {
+            include_deleted: true,
+            ..all()
+        })
+        .await
+        .unwrap();
+    assert_eq!(with_deleted.len(), 2);
+
+    let trash = service.list_deleted(all()).await.unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].id, deleted.id);
+    assert!(trash[0].deleted_at.is_some());
+
+    // Deleting twice is a not-found, like deleting a missing task
+    assert!(matches!(
+        service.delete_task(deleted.id).await,
+        Err(TaskError::NotFound(_))
+    ));
+
+    let restored = service.restore_task(deleted.id).await.unwrap();
+    assert_eq!(restored.id, deleted.id);
+    assert!(restored.deleted_at.is_none());
+    assert_eq!(service.list_tasks(all()).await.unwrap().len(), 2);
+    assert!(service.list_deleted(all()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_restore_live_task_is_not_found() {
+    let db = TestDatabase::new().await;
+    let service = TaskService::new(PgTaskRepository::new(db.connection()));
+
+    let task = service.create_task(create("Live")).await.unwrap();
+
+    assert!(matches!(
+        service.restore_task(task.id).await,
+        Err(TaskError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_deleted_task_cannot_be_updated_or_used_as_parent() {
+    let db = TestDatabase::new().await;
+    let service = TaskService::new(PgTaskRepository::new(db.connection()));
+
+    let task = service.create_task(create("Gone")).await.unwrap();
+    service.delete_task(task.id).await.unwrap();
+
+    let result = service
+        .update_task(
+            task.id,
+            UpdateTask {
+                title: Some("Renamed".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(TaskError::NotFound(_))));
+
+    let result = service
+        .create_task(CreateTask {
+            parent_id: Some(task.id),
+            ..create("Child")
+        })
+        .await;
+    assert!(matches!(result, Err(TaskError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_cascade_delete_removes_whole_subtree() {
+    let db = TestDatabase::new().await;
+    let service =
+        TaskService::new(PgTaskRepository::new(db.connection())).with_cascade_delete(true);
+
+    let root = service.create_task(create("Root")).await.unwrap();
+    let child = service
+        .create_task(CreateTask {
+            parent_id: Some(root.id),
+            ..create("Child")
+        })
+        .await
+        .unwrap();
+    let grandchild = service
+        .create_task(CreateTask {
+            parent_id: Some(child.id),
+            ..create("Grandchild")
+        })
+        .await
+        .unwrap();
+    let other = service.create_task(create("Other")).await.unwrap();
+
+    service.delete_task(root.id).await.unwrap();
+
+    let trash: Vec<_> = service
+        .list_deleted(all())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|task| task.id)
+        .collect();
+    assert_eq!(trash.len(), 3);
+    for id in [root.id, child.id, grandchild.id] {
+        assert!(trash.contains(&id));
+    }
+    assert!(service.get_task(other.id).await.is_ok());
+
+    assert!(matches!(
+        service.delete_task(root.id).await,
+        Err(TaskError::NotFound(_))
+    ));
+}
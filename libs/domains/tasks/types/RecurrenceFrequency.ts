@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unit of a recurrence interval
+ */
+export type RecurrenceFrequency = "hourly" | "daily" | "weekly" | "monthly";
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecurrenceFrequency } from "./RecurrenceFrequency";
+
+/**
+ * Schedule for a task that regenerates once it is completed
+ *
+ * The next occurrence is due `interval` units after the completion time,
+ * so a late completion pushes the whole series back.
+ */
+export type RecurrenceRule = { 
+/**
+ * Unit of the repeat interval
+ */
+frequency: RecurrenceFrequency, 
+/**
+ * Repeat every `interval` units
+ */
+interval: number, 
+/**
+ * Stop the series after this many occurrences
+ */
+max_occurrences: number | null, 
+/**
+ * Position of this task in its series, starting at 1
+ */
+occurrence: number, };
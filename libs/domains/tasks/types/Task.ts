@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecurrenceRule } from "./RecurrenceRule";
 import type { TaskPriority } from "./TaskPriority";
 import type { TaskStatus } from "./TaskStatus";
 
@@ -26,6 +27,10 @@ completed: boolean,
  * Optional project association
  */
 project_id: string | null, 
+/**
+ * Parent task when this is a subtask
+ */
+parent_id: string | null, 
 /**
  * Task priority
  */
@@ -38,6 +43,10 @@ status: TaskStatus,
  * Optional due date
  */
 due_date: string | null, 
+/**
+ * Schedule for regenerating the task once completed
+ */
+recurrence: RecurrenceRule | null, 
 /**
  * Creation timestamp
  */
@@ -45,4 +54,8 @@ created_at: string,
 /**
  * Last update timestamp
  */
-updated_at: string, };
+updated_at: string, 
+/**
+ * When the task was soft-deleted, if it has been
+ */
+deleted_at: string | null, };
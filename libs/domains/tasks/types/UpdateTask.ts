@@ -1,8 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecurrenceRule } from "./RecurrenceRule";
 import type { TaskPriority } from "./TaskPriority";
 import type { TaskStatus } from "./TaskStatus";
 
 /**
  * DTO for updating an existing task
  */
-export type UpdateTask = { title: string | null, description: string | null, completed: boolean | null, project_id: string | null | null, priority: TaskPriority | null, status: TaskStatus | null, due_date: string | null | null, };
+export type UpdateTask = { title: string | null, description: string | null, completed: boolean | null, project_id: string | null | null, parent_id: string | null | null, priority: TaskPriority | null, status: TaskStatus | null, due_date: string | null | null, recurrence: RecurrenceRule | null | null, };
@@ -6,3 +6,5 @@ export * from './CreateTask';
 export * from './UpdateTask';
 export * from './TaskPriority';
 export * from './TaskStatus';
+export * from './RecurrenceRule';
+export * from './RecurrenceFrequency';
@@ -217,6 +217,7 @@ async fn register<R: UserRepository, O: OAuthAccountRepository>(
         (status = 200, description = "Login successful", body = LoginResponse),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Invalid credentials"),
+        (status = 403, description = "Account locked after repeated failed logins"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -227,7 +228,7 @@ async fn login<R: UserRepository, O: OAuthAccountRepository>(
     // Verify credentials
     let user = state
         .service
-        .verify_credentials(&input.email, &input.password)
+        .verify_credentials(&input.email, &input.password, input.totp_code.as_deref())
         .await?;
 
     let user_id = user.id.to_string();
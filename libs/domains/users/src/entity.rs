@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use sea_orm::ActiveValue::Set;
+use sea_orm::entity::prelude::*;
+
+use crate::models::{Role, User};
+
+/// Sea-ORM Entity for Users table
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "users")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(unique)]
+    pub email: String,
+    pub name: String,
+    /// Argon2 PHC string
+    pub password_hash: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub avatar_url: Option<String>,
+    /// PostgreSQL `TEXT[]` of lowercase role names
+    pub roles: Vec<String>,
+    pub email_verified: bool,
+    pub is_active: bool,
+    pub is_locked: bool,
+    pub failed_login_attempts: i32,
+    pub locked_until: Option<DateTimeWithTimeZone>,
+    pub last_login_at: Option<DateTimeWithTimeZone>,
+    pub google_id: Option<String>,
+    pub github_id: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub totp_secret: Option<String>,
+    pub totp_enabled: bool,
+    pub totp_last_step: Option<i64>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// Conversion from Sea-ORM Model to domain User
+impl From<Model> for User {
+    fn from(model: Model) -> Self {
+        // Unknown role names are dropped rather than failing the whole row
+        let roles = model
+            .roles
+            .iter()
+            .filter_map(|s| Role::from_str(s).ok())
+            .collect();
+
+        Self {
+            id: model.id,
+            email: model.email,
+            name: model.name,
+            password_hash: model.password_hash,
+            roles,
+            email_verified: model.email_verified,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+            avatar_url: model.avatar_url,
+            google_id: model.google_id,
+            github_id: model.github_id,
+            last_login_at: model.last_login_at.map(Into::into),
+            is_active: model.is_active,
+            is_locked: model.is_locked,
+            failed_login_attempts: model.failed_login_attempts,
+            locked_until: model.locked_until.map(Into::into),
+            totp_secret: model.totp_secret,
+            totp_enabled: model.totp_enabled,
+            totp_last_step: model.totp_last_step,
+        }
+    }
+}
+
+// Conversion from domain User to Sea-ORM ActiveModel (all columns set)
+impl From<User> for ActiveModel {
+    fn from(user: User) -> Self {
+        ActiveModel {
+            id: Set(user.id),
+            email: Set(user.email),
+            name: Set(user.name),
+            password_hash: Set(user.password_hash),
+            avatar_url: Set(user.avatar_url),
+            roles: Set(user.roles.iter().map(|r| r.to_string()).collect()),
+            email_verified: Set(user.email_verified),
+            is_active: Set(user.is_active),
+            is_locked: Set(user.is_locked),
+            failed_login_attempts: Set(user.failed_login_attempts),
+            locked_until: Set(user.locked_until.map(Into::into)),
+            last_login_at: Set(user.last_login_at.map(Into::into)),
+            google_id: Set(user.google_id),
+            github_id: Set(user.github_id),
+            totp_secret: Set(user.totp_secret),
+            totp_enabled: Set(user.totp_enabled),
+            totp_last_step: Set(user.totp_last_step),
+            created_at: Set(user.created_at.into()),
+            updated_at: Set(user.updated_at.into()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use axum_helpers::{AppError, impl_into_response_via_app_error};
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -13,6 +14,14 @@ pub enum UserError {
     #[error("Invalid credentials")]
     InvalidCredentials,
 
+    /// Too many failed logins; `None` means locked without an expiry
+    #[error("Account is locked")]
+    AccountLocked(Option<DateTime<Utc>>),
+
+    /// Password accepted but a TOTP code is still needed
+    #[error("Two-factor code required")]
+    TotpRequired,
+
     #[error("Invalid input: {0}")]
     Validation(String),
 
@@ -41,6 +50,13 @@ impl From<UserError> for AppError {
             UserError::InvalidCredentials => {
                 AppError::Unauthorized("Invalid email or password".to_string())
             }
+            UserError::AccountLocked(locked_until) => AppError::Forbidden(match locked_until {
+                Some(until) => format!("Account is locked until {}", until.to_rfc3339()),
+                None => "Account is locked".to_string(),
+            }),
+            UserError::TotpRequired => {
+                AppError::Unauthorized("Two-factor code required".to_string())
+            }
             UserError::Validation(msg) => AppError::BadRequest(msg),
             UserError::Unauthorized => AppError::Unauthorized("Unauthorized".to_string()),
             UserError::PasswordHash(msg) => {
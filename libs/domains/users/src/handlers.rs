@@ -275,6 +275,7 @@ async fn change_password<R: UserRepository>(
         (status = 200, description = "Login successful", body = UserResponse),
         (status = 400, response = BadRequestValidationResponse),
         (status = 401, description = "Invalid credentials"),
+        (status = 403, description = "Account locked after repeated failed logins"),
         (status = 500, response = InternalServerErrorResponse)
     )
 )]
@@ -283,7 +284,7 @@ async fn login<R: UserRepository>(
     ValidatedJson(input): ValidatedJson<LoginRequest>,
 ) -> UserResult<Json<UserResponse>> {
     let user = service
-        .verify_credentials(&input.email, &input.password)
+        .verify_credentials(&input.email, &input.password, input.totp_code.as_deref())
         .await?;
     Ok(Json(user))
 }
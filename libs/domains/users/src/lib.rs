@@ -9,6 +9,7 @@
 //! - Email verification
 //! - Role-based access control
 //! - Login/authentication
+//! - Account lockout and TOTP two-factor auth
 //!
 //! # Architecture
 //!
@@ -22,7 +23,7 @@
 //! └──────┬──────┘
 //!        │
 //! ┌──────▼──────┐
-//! │ Repository  │  ← Data access (trait + implementations)
+//! │ Repository  │  ← Data access (trait + in-memory / PostgreSQL)
 //! └──────┬──────┘
 //!        │
 //! ┌──────▼──────┐
@@ -48,13 +49,15 @@
 //! ```
 
 pub mod auth_handlers;
+pub mod entity;
 pub mod error;
 pub mod handlers;
 pub mod models;
 pub mod oauth;
-pub mod postgres_repository_impl;
+pub mod postgres;
 pub mod repository;
 pub mod service;
+pub mod totp;
 
 // Re-export commonly used types
 pub use auth_handlers::AuthApiDoc;
@@ -62,6 +65,7 @@ pub use error::{UserError, UserResult};
 pub use handlers::ApiDoc;
 pub use models::{CreateUser, LoginRequest, Role, UpdateUser, User, UserFilter, UserResponse};
 pub use oauth::{AccountLinkingService, OAuthStateManager, PostgresOAuthAccountRepository};
-pub use postgres_repository_impl::PostgresUserRepository;
+pub use postgres::{PgUserRepository, PostgresUserRepository};
 pub use repository::{InMemoryUserRepository, UserRepository};
-pub use service::UserService;
+pub use service::{UserService, UserServiceConfig};
+pub use totp::TotpSecret;
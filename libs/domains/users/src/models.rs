@@ -73,6 +73,14 @@ pub struct User {
     pub failed_login_attempts: i32,
     /// Locked until timestamp
     pub locked_until: Option<DateTime<Utc>>,
+    /// Base32 TOTP secret (set on enrollment, never exposed in API responses)
+    #[serde(skip_serializing)]
+    pub totp_secret: Option<String>,
+    /// Whether login requires a TOTP code (set once enrollment is verified)
+    pub totp_enabled: bool,
+    /// Time step of the last accepted TOTP code; older or equal steps are rejected
+    #[serde(skip_serializing)]
+    pub totp_last_step: Option<i64>,
 }
 
 /// User response DTO (without password_hash)
@@ -87,6 +95,7 @@ pub struct UserResponse {
     pub updated_at: DateTime<Utc>,
     pub avatar_url: Option<String>,
     pub last_login_at: Option<DateTime<Utc>>,
+    pub totp_enabled: bool,
 }
 
 impl From<User> for UserResponse {
@@ -101,6 +110,7 @@ impl From<User> for UserResponse {
             updated_at: user.updated_at,
             avatar_url: user.avatar_url,
             last_login_at: user.last_login_at,
+            totp_enabled: user.totp_enabled,
         }
     }
 }
@@ -151,6 +161,9 @@ pub struct LoginRequest {
     #[validate(email, length(max = 255))]
     pub email: String,
     pub password: String,
+    /// Current TOTP code, required when two-factor auth is enabled
+    #[serde(default)]
+    pub totp_code: Option<String>,
 }
 
 /// DTO for user registration
@@ -194,6 +207,9 @@ impl User {
             is_locked: false,
             failed_login_attempts: 0,
             locked_until: None,
+            totp_secret: None,
+            totp_enabled: false,
+            totp_last_step: None,
         }
     }
 
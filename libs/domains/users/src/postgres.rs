@@ -0,0 +1,366 @@
+use async_trait::async_trait;
+use database::BaseRepository;
+use sea_orm::sea_query::{Expr, ExprTrait, Func, LikeExpr};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Select, SqlErr,
+};
+use uuid::Uuid;
+
+use crate::{
+    entity,
+    error::{UserError, UserResult},
+    models::{User, UserFilter},
+    oauth::Provider,
+    repository::UserRepository,
+};
+
+/// PostgreSQL implementation of UserRepository using SeaORM
+pub struct PgUserRepository {
+    base: BaseRepository<entity::Entity>,
+}
+
+/// Previous name of [`PgUserRepository`], kept for existing callers
+pub type PostgresUserRepository = PgUserRepository;
+
+/// Escape LIKE wildcards so user input only ever matches literally
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// BaseRepository isn't Clone; the connection itself is a cheap pool handle
+impl Clone for PgUserRepository {
+    fn clone(&self) -> Self {
+        Self::new(self.base.db().clone())
+    }
+}
+
+impl PgUserRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            base: BaseRepository::new(db),
+        }
+    }
+
+    /// Case-insensitive email match, consistent with InMemoryUserRepository
+    fn by_email(email: &str) -> Select<entity::Entity> {
+        entity::Entity::find().filter(
+            Expr::expr(Func::lower(Expr::col(entity::Column::Email))).eq(email.to_lowercase()),
+        )
+    }
+
+    fn filtered(filter: &UserFilter) -> Select<entity::Entity> {
+        let mut query = entity::Entity::find();
+
+        if let Some(ref email) = filter.email {
+            query = query.filter(
+                Expr::expr(Func::lower(Expr::col(entity::Column::Email))).like(
+                    LikeExpr::new(format!("%{}%", escape_like(&email.to_lowercase()))).escape('\\'),
+                ),
+            );
+        }
+
+        if let Some(ref role) = filter.role {
+            query = query.filter(Expr::cust_with_values(
+                "$1 = ANY(roles)",
+                [role.to_lowercase()],
+            ));
+        }
+
+        if let Some(verified) = filter.email_verified {
+            query = query.filter(entity::Column::EmailVerified.eq(verified));
+        }
+
+        query
+    }
+
+    /// Reject an email already used by another user (case-insensitively).
+    ///
+    /// The unique index on `email` is case-sensitive, so this check is what
+    /// keeps `Alice@x.com` and `alice@x.com` apart; the index still catches
+    /// concurrent inserts of the exact same address.
+    async fn ensure_email_available(&self, email: &str, exclude: Option<Uuid>) -> UserResult<()> {
+        let mut query = Self::by_email(email);
+        if let Some(id) = exclude {
+            query = query.filter(entity::Column::Id.ne(id));
+        }
+
+        let taken = query.count(self.base.db()).await.map_err(db_error)? > 0;
+        if taken {
+            return Err(UserError::DuplicateEmail(email.to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn db_error(e: DbErr) -> UserError {
+    UserError::Internal(format!("Database error: {}", e))
+}
+
+/// Map unique violations on write to DuplicateEmail
+fn write_error(e: DbErr, email: &str) -> UserError {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => UserError::DuplicateEmail(email.to_string()),
+        _ => db_error(e),
+    }
+}
+
+#[async_trait]
+impl UserRepository for PgUserRepository {
+    async fn create(&self, user: User) -> UserResult<User> {
+        self.ensure_email_available(&user.email, None).await?;
+
+        let email = user.email.clone();
+        let active_model: entity::ActiveModel = user.into();
+
+        let model = self
+            .base
+            .insert(active_model)
+            .await
+            .map_err(|e| write_error(e, &email))?;
+
+        tracing::info!(user_id = %model.id, email = %model.email, "Created user");
+        Ok(model.into())
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> UserResult<Option<User>> {
+        let model = self.base.find_by_id(id).await.map_err(db_error)?;
+
+        Ok(model.map(|m| m.into()))
+    }
+
+    async fn get_by_email(&self, email: &str) -> UserResult<Option<User>> {
+        let model = Self::by_email(email)
+            .one(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(model.map(|m| m.into()))
+    }
+
+    async fn list(&self, filter: UserFilter) -> UserResult<Vec<User>> {
+        let models = Self::filtered(&filter)
+            .order_by_desc(entity::Column::CreatedAt)
+            .limit(filter.limit as u64)
+            .offset(filter.offset as u64)
+            .all(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(models.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn update(&self, user: User) -> UserResult<User> {
+        let id = user.id;
+        self.ensure_email_available(&user.email, Some(id)).await?;
+
+        let email = user.email.clone();
+        let active_model: entity::ActiveModel = user.into();
+
+        let model = self.base.update(active_model).await.map_err(|e| match e {
+            DbErr::RecordNotUpdated => UserError::NotFound(id),
+            e => write_error(e, &email),
+        })?;
+
+        tracing::info!(user_id = %id, "Updated user");
+        Ok(model.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> UserResult<bool> {
+        let rows_affected = self.base.delete_by_id(id).await.map_err(db_error)?;
+
+        if rows_affected > 0 {
+            tracing::info!(user_id = %id, "Deleted user");
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    async fn email_exists(&self, email: &str) -> UserResult<bool> {
+        let count = Self::by_email(email)
+            .count(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(count > 0)
+    }
+
+    async fn count(&self, filter: UserFilter) -> UserResult<usize> {
+        let count = Self::filtered(&filter)
+            .count(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(count as usize)
+    }
+
+    async fn get_by_oauth_id(
+        &self,
+        provider: Provider,
+        provider_id: &str,
+    ) -> UserResult<Option<User>> {
+        let column = match provider {
+            Provider::Google => entity::Column::GoogleId,
+            Provider::Github => entity::Column::GithubId,
+        };
+
+        let model = entity::Entity::find()
+            .filter(column.eq(provider_id))
+            .one(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(model.map(|m| m.into()))
+    }
+
+    async fn link_oauth_account(
+        &self,
+        user_id: Uuid,
+        provider: Provider,
+        provider_id: &str,
+        avatar_url: Option<String>,
+    ) -> UserResult<()> {
+        let column = match provider {
+            Provider::Google => entity::Column::GoogleId,
+            Provider::Github => entity::Column::GithubId,
+        };
+
+        let mut update = entity::Entity::update_many()
+            .col_expr(column, Expr::value(provider_id))
+            .col_expr(entity::Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+            .filter(entity::Column::Id.eq(user_id));
+
+        if let Some(avatar_url) = avatar_url {
+            update = update.col_expr(entity::Column::AvatarUrl, Expr::value(avatar_url));
+        }
+
+        let result = update.exec(self.base.db()).await.map_err(db_error)?;
+        if result.rows_affected == 0 {
+            return Err(UserError::NotFound(user_id));
+        }
+
+        Ok(())
+    }
+
+    async fn record_login_success(&self, user_id: Uuid) -> UserResult<()> {
+        let now = chrono::Utc::now();
+        let result = entity::Entity::update_many()
+            .col_expr(entity::Column::FailedLoginAttempts, Expr::value(0))
+            .col_expr(entity::Column::IsLocked, Expr::value(false))
+            .col_expr(
+                entity::Column::LockedUntil,
+                Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None),
+            )
+            .col_expr(entity::Column::LastLoginAt, Expr::value(now))
+            .col_expr(entity::Column::UpdatedAt, Expr::value(now))
+            .filter(entity::Column::Id.eq(user_id))
+            .exec(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        if result.rows_affected == 0 {
+            return Err(UserError::NotFound(user_id));
+        }
+        Ok(())
+    }
+
+    async fn record_login_failure(
+        &self,
+        user_id: Uuid,
+        max_attempts: i32,
+        lockout_duration: chrono::Duration,
+    ) -> UserResult<()> {
+        let now = chrono::Utc::now();
+
+        // Start counting afresh once a previous lock has expired
+        entity::Entity::update_many()
+            .col_expr(entity::Column::FailedLoginAttempts, Expr::value(0))
+            .col_expr(entity::Column::IsLocked, Expr::value(false))
+            .col_expr(
+                entity::Column::LockedUntil,
+                Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None),
+            )
+            .filter(entity::Column::Id.eq(user_id))
+            .filter(entity::Column::IsLocked.eq(true))
+            .filter(entity::Column::LockedUntil.lte(now))
+            .exec(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        // Increment in SQL so concurrent failures are all counted
+        let updated = entity::Entity::update_many()
+            .col_expr(
+                entity::Column::FailedLoginAttempts,
+                Expr::col(entity::Column::FailedLoginAttempts).add(1),
+            )
+            .col_expr(entity::Column::UpdatedAt, Expr::value(now))
+            .filter(entity::Column::Id.eq(user_id))
+            .exec_with_returning(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        let model = updated
+            .into_iter()
+            .next()
+            .ok_or(UserError::NotFound(user_id))?;
+
+        if max_attempts > 0 && model.failed_login_attempts >= max_attempts {
+            entity::Entity::update_many()
+                .col_expr(entity::Column::IsLocked, Expr::value(true))
+                .col_expr(
+                    entity::Column::LockedUntil,
+                    Expr::value(now + lockout_duration),
+                )
+                .filter(entity::Column::Id.eq(user_id))
+                .exec(self.base.db())
+                .await
+                .map_err(db_error)?;
+        }
+
+        Ok(())
+    }
+
+    async fn check_account_locked(&self, user_id: Uuid) -> UserResult<bool> {
+        let model = self
+            .base
+            .find_by_id(user_id)
+            .await
+            .map_err(db_error)?
+            .ok_or(UserError::NotFound(user_id))?;
+
+        if !model.is_locked {
+            return Ok(false);
+        }
+
+        // Check if lock has expired
+        match model.locked_until {
+            Some(locked_until) => Ok(locked_until > chrono::Utc::now()),
+            None => Ok(true),
+        }
+    }
+
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> UserResult<bool> {
+        // Conditional update so two concurrent logins can't both use one code
+        let result = entity::Entity::update_many()
+            .col_expr(entity::Column::TotpLastStep, Expr::value(step))
+            .filter(entity::Column::Id.eq(user_id))
+            .filter(
+                Condition::any()
+                    .add(entity::Column::TotpLastStep.is_null())
+                    .add(entity::Column::TotpLastStep.lt(step)),
+            )
+            .exec(self.base.db())
+            .await
+            .map_err(db_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -51,11 +51,25 @@ pub trait UserRepository: Send + Sync {
         avatar_url: Option<String>,
     ) -> UserResult<()>;
 
-    /// Update login attempt (increment or reset)
-    async fn update_login_attempt(&self, user_id: Uuid, success: bool) -> UserResult<()>;
+    /// Reset failed attempts and any lock, and stamp `last_login_at`
+    async fn record_login_success(&self, user_id: Uuid) -> UserResult<()>;
+
+    /// Count a failed login, locking the account for `lockout_duration` once
+    /// `max_attempts` consecutive failures are reached (0 disables locking).
+    /// An expired lock is cleared first so the user gets a fresh set of attempts.
+    async fn record_login_failure(
+        &self,
+        user_id: Uuid,
+        max_attempts: i32,
+        lockout_duration: chrono::Duration,
+    ) -> UserResult<()>;
 
     /// Check if account is currently locked
     async fn check_account_locked(&self, user_id: Uuid) -> UserResult<bool>;
+
+    /// Record `step` as the last accepted TOTP step if it is newer than the
+    /// stored one. Returns `false` when it is not, i.e. the code was replayed.
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> UserResult<bool>;
 }
 
 /// In-memory implementation of UserRepository (for development/testing)
@@ -266,26 +280,45 @@ impl UserRepository for InMemoryUserRepository {
         }
     }
 
-    async fn update_login_attempt(&self, user_id: Uuid, success: bool) -> UserResult<()> {
+    async fn record_login_success(&self, user_id: Uuid) -> UserResult<()> {
         let mut users = self.users.write().await;
         if let Some(user) = users.get_mut(&user_id) {
-            if success {
-                // Reset on successful login
-                user.failed_login_attempts = 0;
+            let now = chrono::Utc::now();
+            user.failed_login_attempts = 0;
+            user.is_locked = false;
+            user.locked_until = None;
+            user.last_login_at = Some(now);
+            user.updated_at = now;
+            Ok(())
+        } else {
+            Err(UserError::NotFound(user_id))
+        }
+    }
+
+    async fn record_login_failure(
+        &self,
+        user_id: Uuid,
+        max_attempts: i32,
+        lockout_duration: chrono::Duration,
+    ) -> UserResult<()> {
+        let mut users = self.users.write().await;
+        if let Some(user) = users.get_mut(&user_id) {
+            let now = chrono::Utc::now();
+
+            // Start counting afresh once a previous lock has expired
+            if user.is_locked && user.locked_until.is_some_and(|until| until <= now) {
                 user.is_locked = false;
                 user.locked_until = None;
-                user.last_login_at = Some(chrono::Utc::now());
-            } else {
-                // Increment failed attempts
-                user.failed_login_attempts += 1;
+                user.failed_login_attempts = 0;
+            }
 
-                // Lock account after 5 failed attempts
-                if user.failed_login_attempts >= 5 {
-                    user.is_locked = true;
-                    user.locked_until = Some(chrono::Utc::now() + chrono::Duration::minutes(15));
-                }
+            user.failed_login_attempts += 1;
+
+            if max_attempts > 0 && user.failed_login_attempts >= max_attempts {
+                user.is_locked = true;
+                user.locked_until = Some(now + lockout_duration);
             }
-            user.updated_at = chrono::Utc::now();
+            user.updated_at = now;
             Ok(())
         } else {
             Err(UserError::NotFound(user_id))
@@ -309,6 +342,19 @@ impl UserRepository for InMemoryUserRepository {
             Err(UserError::NotFound(user_id))
         }
     }
+
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> UserResult<bool> {
+        let mut users = self.users.write().await;
+        let user = users
+            .get_mut(&user_id)
+            .ok_or(UserError::NotFound(user_id))?;
+
+        if user.totp_last_step.is_some_and(|last| last >= step) {
+            return Ok(false);
+        }
+        user.totp_last_step = Some(step);
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
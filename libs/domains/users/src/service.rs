@@ -2,6 +2,7 @@ use argon2::{
     Argon2,
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
 };
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -9,20 +10,84 @@ use crate::error::{UserError, UserResult};
 use crate::models::{CreateUser, Role, UpdateUser, User, UserFilter, UserResponse};
 use crate::oauth::{OAuthUserInfo, Provider};
 use crate::repository::UserRepository;
+use crate::totp::{self, TotpSecret};
+
+/// Source of the current time; swapped out in tests
+pub type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
+/// Tunables for UserService
+#[derive(Debug, Clone)]
+pub struct UserServiceConfig {
+    /// Consecutive failed logins before the account is locked (0 disables lockout)
+    pub max_failed_login_attempts: i32,
+    /// How long a locked account stays locked
+    pub lockout_duration: chrono::Duration,
+    /// Issuer shown by authenticator apps
+    pub totp_issuer: String,
+    /// TOTP steps accepted either side of the current one, for clock drift
+    pub totp_skew_steps: u64,
+}
+
+impl Default for UserServiceConfig {
+    fn default() -> Self {
+        Self {
+            max_failed_login_attempts: 5,
+            lockout_duration: chrono::Duration::minutes(15),
+            totp_issuer: "nx-playground".to_string(),
+            totp_skew_steps: 1,
+        }
+    }
+}
+
+impl UserServiceConfig {
+    pub fn with_max_failed_login_attempts(mut self, max_failed_login_attempts: i32) -> Self {
+        self.max_failed_login_attempts = max_failed_login_attempts;
+        self
+    }
+
+    pub fn with_lockout_duration(mut self, lockout_duration: chrono::Duration) -> Self {
+        self.lockout_duration = lockout_duration;
+        self
+    }
+
+    pub fn with_totp_issuer(mut self, totp_issuer: impl Into<String>) -> Self {
+        self.totp_issuer = totp_issuer.into();
+        self
+    }
+
+    pub fn with_totp_skew_steps(mut self, totp_skew_steps: u64) -> Self {
+        self.totp_skew_steps = totp_skew_steps;
+        self
+    }
+}
 
 /// Service layer for User business logic
 #[derive(Clone)]
 pub struct UserService<R: UserRepository> {
     repository: Arc<R>,
+    config: UserServiceConfig,
+    clock: Clock,
 }
 
 impl<R: UserRepository> UserService<R> {
     pub fn new(repository: R) -> Self {
+        Self::with_config(repository, UserServiceConfig::default())
+    }
+
+    pub fn with_config(repository: R, config: UserServiceConfig) -> Self {
         Self {
             repository: Arc::new(repository),
+            config,
+            clock: Arc::new(Utc::now),
         }
     }
 
+    /// Replace the clock used for TOTP checks
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Create a new user with password hashing
     pub async fn create_user(&self, input: CreateUser) -> UserResult<UserResponse> {
         // Validate input
@@ -113,12 +178,20 @@ impl<R: UserRepository> UserService<R> {
     }
 
     /// Verify user credentials (for login)
+    ///
+    /// Failed attempts are counted per account; once
+    /// `max_failed_login_attempts` is reached the account is rejected with
+    /// `AccountLocked` until `lockout_duration` has passed.
+    ///
+    /// When TOTP is enabled a correct password without `totp_code` returns
+    /// `TotpRequired`; a wrong code counts as a failed attempt.
     pub async fn verify_credentials(
         &self,
         email: &str,
         password: &str,
+        totp_code: Option<&str>,
     ) -> UserResult<UserResponse> {
-        let user = self
+        let mut user = self
             .repository
             .get_by_email(email)
             .await?
@@ -131,29 +204,127 @@ impl<R: UserRepository> UserService<R> {
 
         // Check if account is locked
         if self.repository.check_account_locked(user.id).await? {
-            let locked_until = user
-                .locked_until
-                .map(|dt| dt.to_rfc3339())
-                .unwrap_or_else(|| "unknown".to_string());
-            return Err(UserError::Validation(format!(
-                "Account is locked until {}",
-                locked_until
-            )));
+            return Err(UserError::AccountLocked(user.locked_until));
         }
 
         // Verify password
         if !self.verify_password(password, &user.password_hash)? {
-            // Increment failed login attempts
-            self.repository.update_login_attempt(user.id, false).await?;
+            self.record_login_failure(user.id).await?;
             return Err(UserError::InvalidCredentials);
         }
 
+        // Second factor
+        if user.totp_enabled {
+            let Some(code) = totp_code else {
+                return Err(UserError::TotpRequired);
+            };
+            if !self.accept_totp(&mut user, code).await? {
+                self.record_login_failure(user.id).await?;
+                return Err(UserError::InvalidCredentials);
+            }
+        }
+
         // Successful login - reset failed attempts and update last login
-        self.repository.update_login_attempt(user.id, true).await?;
+        self.repository.record_login_success(user.id).await?;
 
         Ok(user.into())
     }
 
+    async fn record_login_failure(&self, user_id: Uuid) -> UserResult<()> {
+        self.repository
+            .record_login_failure(
+                user_id,
+                self.config.max_failed_login_attempts,
+                self.config.lockout_duration,
+            )
+            .await
+    }
+
+    // Two-factor auth
+
+    /// Start TOTP enrollment with a fresh secret.
+    ///
+    /// Login is not gated until the first code is confirmed with
+    /// `verify_totp`, so a half-finished enrollment can't lock the user out.
+    pub async fn enroll_totp(&self, user_id: Uuid) -> UserResult<TotpSecret> {
+        let mut user = self
+            .repository
+            .get_by_id(user_id)
+            .await?
+            .ok_or(UserError::NotFound(user_id))?;
+
+        if user.totp_enabled {
+            return Err(UserError::Validation(
+                "Two-factor auth is already enabled".to_string(),
+            ));
+        }
+
+        let secret = totp::generate_secret();
+        let otpauth_uri = totp::otpauth_uri(&self.config.totp_issuer, &user.email, &secret);
+
+        user.totp_secret = Some(secret.clone());
+        user.updated_at = (self.clock)();
+        self.repository.update(user).await?;
+
+        Ok(TotpSecret {
+            secret,
+            otpauth_uri,
+        })
+    }
+
+    /// Check a TOTP code, enabling two-factor auth on the first success
+    ///
+    /// Each code is accepted once; see [`accept_totp`](Self::accept_totp).
+    pub async fn verify_totp(&self, user_id: Uuid, code: &str) -> UserResult<()> {
+        let mut user = self
+            .repository
+            .get_by_id(user_id)
+            .await?
+            .ok_or(UserError::NotFound(user_id))?;
+
+        if user.totp_secret.is_none() {
+            return Err(UserError::Validation(
+                "Two-factor auth is not enrolled".to_string(),
+            ));
+        }
+
+        if !self.accept_totp(&mut user, code).await? {
+            return Err(UserError::InvalidCredentials);
+        }
+
+        if !user.totp_enabled {
+            user.totp_enabled = true;
+            user.updated_at = (self.clock)();
+            self.repository.update(user).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Check a TOTP code and consume its time step.
+    ///
+    /// A code is rejected unless its step is later than the last accepted
+    /// one, so an intercepted code can't be reused within the skew window.
+    async fn accept_totp(&self, user: &mut User, code: &str) -> UserResult<bool> {
+        let Some(step) = user.totp_secret.as_deref().and_then(|secret| {
+            totp::matching_step(
+                secret,
+                code.trim(),
+                (self.clock)().timestamp(),
+                self.config.totp_skew_steps,
+            )
+        }) else {
+            return Ok(false);
+        };
+
+        let step = step as i64;
+        if !self.repository.record_totp_step(user.id, step).await? {
+            return Ok(false);
+        }
+        user.totp_last_step = Some(step);
+        Ok(true)
+    }
+
     /// Verify email (mark as verified)
     pub async fn verify_email(&self, id: Uuid) -> UserResult<UserResponse> {
         let mut user = self
@@ -353,3 +524,283 @@ impl<R: UserRepository> UserService<R> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::InMemoryUserRepository;
+
+    const PASSWORD: &str = "Correct-h0rse";
+
+    async fn setup(
+        max_attempts: i32,
+    ) -> (UserService<InMemoryUserRepository>, InMemoryUserRepository) {
+        let repository = InMemoryUserRepository::new();
+        let config = UserServiceConfig::default()
+            .with_max_failed_login_attempts(max_attempts)
+            .with_lockout_duration(chrono::Duration::minutes(15));
+        let service = UserService::with_config(repository.clone(), config);
+
+        service
+            .create_user(CreateUser {
+                email: "lock@example.com".to_string(),
+                name: "Lock Test".to_string(),
+                password: PASSWORD.to_string(),
+                roles: vec![],
+            })
+            .await
+            .unwrap();
+
+        (service, repository)
+    }
+
+    async fn fail_login(service: &UserService<InMemoryUserRepository>) -> UserError {
+        service
+            .verify_credentials("lock@example.com", "Wrong-passw0rd", None)
+            .await
+            .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn test_locks_after_threshold() {
+        let (service, repository) = setup(3).await;
+
+        for _ in 0..3 {
+            assert!(matches!(
+                fail_login(&service).await,
+                UserError::InvalidCredentials
+            ));
+        }
+
+        let user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(user.is_locked);
+        assert_eq!(user.failed_login_attempts, 3);
+        assert!(user.locked_until.unwrap() > chrono::Utc::now());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_correct_password_while_locked() {
+        let (service, repository) = setup(2).await;
+        fail_login(&service).await;
+        fail_login(&service).await;
+
+        let err = service
+            .verify_credentials("lock@example.com", PASSWORD, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, UserError::AccountLocked(Some(_))));
+
+        // Attempts while locked are not counted
+        assert!(matches!(
+            fail_login(&service).await,
+            UserError::AccountLocked(_)
+        ));
+        let user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.failed_login_attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_auto_unlocks_after_window() {
+        let (service, repository) = setup(2).await;
+        fail_login(&service).await;
+        fail_login(&service).await;
+
+        // Move the lock window into the past
+        let mut user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        user.locked_until = Some(chrono::Utc::now() - chrono::Duration::seconds(1));
+        repository.update(user).await.unwrap();
+
+        let response = service
+            .verify_credentials("lock@example.com", PASSWORD, None)
+            .await
+            .unwrap();
+        assert_eq!(response.email, "lock@example.com");
+
+        let user = repository.get_by_id(response.id).await.unwrap().unwrap();
+        assert!(!user.is_locked);
+        assert_eq!(user.failed_login_attempts, 0);
+        assert!(user.last_login_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_failure_count() {
+        let (service, repository) = setup(3).await;
+        fail_login(&service).await;
+        fail_login(&service).await;
+
+        service
+            .verify_credentials("lock@example.com", PASSWORD, None)
+            .await
+            .unwrap();
+
+        fail_login(&service).await;
+        fail_login(&service).await;
+
+        let user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!user.is_locked);
+        assert_eq!(user.failed_login_attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_zero_threshold_disables_lockout() {
+        let (service, _) = setup(0).await;
+
+        for _ in 0..10 {
+            assert!(matches!(
+                fail_login(&service).await,
+                UserError::InvalidCredentials
+            ));
+        }
+    }
+
+    fn fixed_clock(unix_time: i64) -> Clock {
+        Arc::new(move || DateTime::from_timestamp(unix_time, 0).unwrap())
+    }
+
+    /// RFC 6238 Appendix B SHA1 seed; the code at t=59 is 287082
+    const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[tokio::test]
+    async fn test_enroll_and_verify_totp() {
+        let (service, repository) = setup(5).await;
+        let service = service.with_clock(fixed_clock(1_700_000_000));
+        let user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+
+        let enrolled = service.enroll_totp(user.id).await.unwrap();
+        assert!(
+            enrolled
+                .otpauth_uri
+                .starts_with("otpauth://totp/nx-playground:lock%40example.com?secret=")
+        );
+
+        // Enrollment alone doesn't gate login
+        service
+            .verify_credentials("lock@example.com", PASSWORD, None)
+            .await
+            .unwrap();
+
+        let code = totp::code_at(&enrolled.secret, 1_700_000_000).unwrap();
+        service.verify_totp(user.id, &code).await.unwrap();
+
+        let user = repository.get_by_id(user.id).await.unwrap().unwrap();
+        assert!(user.totp_enabled);
+        assert!(matches!(
+            service.enroll_totp(user.id).await,
+            Err(UserError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_login_requires_totp_when_enabled() {
+        let (service, repository) = setup(5).await;
+        let service = service.with_clock(fixed_clock(59));
+
+        let mut user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        user.totp_secret = Some(RFC_SECRET.to_string());
+        user.totp_enabled = true;
+        repository.update(user).await.unwrap();
+
+        let err = service
+            .verify_credentials("lock@example.com", PASSWORD, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, UserError::TotpRequired));
+
+        service
+            .verify_credentials("lock@example.com", PASSWORD, Some("287082"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_wrong_or_old_totp_code() {
+        let (service, repository) = setup(5).await;
+        // Two steps after t=59, outside the default one-step skew
+        let service = service.with_clock(fixed_clock(119));
+
+        let mut user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        user.totp_secret = Some(RFC_SECRET.to_string());
+        user.totp_enabled = true;
+        let user = repository.update(user).await.unwrap();
+
+        for code in ["287082", "123456"] {
+            let err = service
+                .verify_credentials("lock@example.com", PASSWORD, Some(code))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, UserError::InvalidCredentials));
+        }
+
+        // Bad codes count towards lockout
+        let user = repository.get_by_id(user.id).await.unwrap().unwrap();
+        assert_eq!(user.failed_login_attempts, 2);
+
+        assert!(matches!(
+            service.verify_totp(user.id, "287082").await,
+            Err(UserError::InvalidCredentials)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_totp_code_cannot_be_replayed() {
+        let (service, repository) = setup(5).await;
+        let service = service.with_clock(fixed_clock(59));
+
+        let mut user = repository
+            .get_by_email("lock@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        user.totp_secret = Some(RFC_SECRET.to_string());
+        let user = repository.update(user).await.unwrap();
+
+        service.verify_totp(user.id, "287082").await.unwrap();
+        assert!(matches!(
+            service.verify_totp(user.id, "287082").await,
+            Err(UserError::InvalidCredentials)
+        ));
+
+        // Still inside the skew window, but the step was already used
+        let service = service.with_clock(fixed_clock(89));
+        let err = service
+            .verify_credentials("lock@example.com", PASSWORD, Some("287082"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, UserError::InvalidCredentials));
+
+        // The next step's code is accepted
+        let next = totp::code_at(RFC_SECRET, 89).unwrap();
+        service
+            .verify_credentials("lock@example.com", PASSWORD, Some(&next))
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+//! Integration tests for PgUserRepository
+//!
+//! These tests use real PostgreSQL via testcontainers to ensure the SeaORM
+//! entity matches the `users` table and that email uniqueness is enforced.
+
+use domain_users::*;
+use test_utils::TestDatabase;
+
+const PASSWORD_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo";
+
+fn new_user(email: &str, roles: Vec<Role>) -> User {
+    User::new(
+        email.to_string(),
+        "Test User".to_string(),
+        PASSWORD_HASH.to_string(),
+        roles,
+    )
+}
+
+#[tokio::test]
+async fn test_create_and_get_user() {
+    let db = TestDatabase::new().await;
+    let repo = PgUserRepository::new(db.connection());
+
+    let created = repo
+        .create(new_user(
+            "create@example.com",
+            vec![Role::Admin, Role::User],
+        ))
+        .await
+        .unwrap();
+
+    let fetched = repo.get_by_id(created.id).await.unwrap().unwrap();
+    assert_eq!(fetched.email, "create@example.com");
+    assert_eq!(fetched.password_hash, PASSWORD_HASH);
+    assert_eq!(fetched.roles, vec![Role::Admin, Role::User]);
+    assert!(fetched.is_active);
+    assert!(!fetched.is_locked);
+}
+
+#[tokio::test]
+async fn test_get_by_email_is_case_insensitive() {
+    let db = TestDatabase::new().await;
+    let repo = PgUserRepository::new(db.connection());
+
+    let created = repo
+        .create(new_user("Find.Me@example.com", vec![]))
+        .await
+        .unwrap();
+
+    let fetched = repo
+        .get_by_email("find.me@EXAMPLE.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(fetched.id, created.id);
+
+    assert!(repo.email_exists("FIND.ME@example.com").await.unwrap());
+    assert!(
+        repo.get_by_email("missing@example.com")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_duplicate_email_error() {
+    let db = TestDatabase::new().await;
+    let repo = PgUserRepository::new(db.connection());
+
+    repo.create(new_user("dup@example.com", vec![]))
+        .await
+        .unwrap();
+
+    let result = repo.create(new_user("dup@example.com", vec![])).await;
+    assert!(matches!(result, Err(UserError::DuplicateEmail(_))));
+
+    let result = repo.create(new_user("DUP@example.com", vec![])).await;
+    assert!(matches!(result, Err(UserError::DuplicateEmail(_))));
+
+    // Renaming another user onto a taken email is rejected too
+    let mut other = repo
+        .create(new_user("other@example.com", vec![]))
+        .await
+        .unwrap();
+    other.email = "Dup@Example.com".to_string();
+    let result = repo.update(other).await;
+    assert!(matches!(result, Err(UserError::DuplicateEmail(_))));
+}
+
+#[tokio::test]
+async fn test_list_and_count_with_filter() {
+    let db = TestDatabase::new().await;
+    let repo = PgUserRepository::new(db.connection());
+
+    repo.create(new_user("alice@acme.com", vec![Role::Admin]))
+        .await
+        .unwrap();
+    repo.create(new_user("bob@acme.com", vec![Role::User]))
+        .await
+        .unwrap();
+    repo.create(new_user("carol@other.com", vec![Role::User]))
+        .await
+        .unwrap();
+
+    let filter = UserFilter {
+        email: Some("ACME".to_string()),
+        role: None,
+        email_verified: None,
+        limit: 50,
+        offset: 0,
+    };
+    assert_eq!(repo.list(filter.clone()).await.unwrap().len(), 2);
+    assert_eq!(repo.count(filter).await.unwrap(), 2);
+
+    let filter = UserFilter {
+        email: None,
+        role: Some("admin".to_string()),
+        email_verified: None,
+        limit: 50,
+        offset: 0,
+    };
+    let admins = repo.list(filter).await.unwrap();
+    assert_eq!(admins.len(), 1);
+    assert_eq!(admins[0].email, "alice@acme.com");
+
+    // LIKE wildcards in the filter match only themselves
+    repo.create(new_user("dave_100%@acme.com", vec![Role::User]))
+        .await
+        .unwrap();
+    for (pattern, expected) in [("_", 1), ("%", 1), ("e_1", 1), ("b_b", 0)] {
+        let filter = UserFilter {
+            email: Some(pattern.to_string()),
+            role: None,
+            email_verified: None,
+            limit: 50,
+            offset: 0,
+        };
+        assert_eq!(
+            repo.count(filter).await.unwrap(),
+            expected,
+            "pattern {pattern:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_record_totp_step_rejects_used_steps() {
+    let db = TestDatabase::new().await;
+    let repo = PgUserRepository::new(db.connection());
+
+    let user = repo
+        .create(new_user("totp@example.com", vec![]))
+        .await
+        .unwrap();
+
+    assert!(repo.record_totp_step(user.id, 100).await.unwrap());
+    assert!(!repo.record_totp_step(user.id, 100).await.unwrap());
+    assert!(!repo.record_totp_step(user.id, 99).await.unwrap());
+    assert!(repo.record_totp_step(user.id, 101).await.unwrap());
+
+    let fetched = repo.get_by_id(user.id).await.unwrap().unwrap();
+    assert_eq!(fetched.totp_last_step, Some(101));
+}
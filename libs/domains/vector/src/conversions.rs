@@ -1,9 +1,11 @@
+use qdrant_client::qdrant::r#match::MatchValue;
+use qdrant_client::qdrant::{Condition, Filter, PointId, Range, RepeatedIntegers, RepeatedStrings};
 use uuid::Uuid;
 
 use crate::error::{VectorError, VectorResult};
 use crate::models::{
-    CollectionInfo, DistanceMetric, EmbeddingModel, EmbeddingProviderType, HnswConfig,
-    SearchResult, TenantContext, Vector, VectorConfig,
+    CollectionInfo, DistanceMetric, EmbeddingModel, EmbeddingProviderType, FilterValue, HnswConfig,
+    PayloadCondition, SearchFilter, SearchResult, TenantContext, Vector, VectorConfig,
 };
 
 // Import generated proto types
@@ -103,6 +105,7 @@ pub fn vector_config_from_proto(proto: Option<ProtoVectorConfig>) -> VectorConfi
             dimension: c.dimension,
             distance: distance_from_proto(c.distance),
             hnsw: hnsw_from_proto(c.hnsw),
+            sparse: false,
         },
         None => VectorConfig::new(1536),
     }
@@ -303,6 +306,151 @@ pub fn embedding_model_to_proto(model: EmbeddingModel) -> i32 {
     }
 }
 
+// ===== Search Filter =====
+
+impl TryFrom<&SearchFilter> for Filter {
+    type Error = VectorError;
+
+    fn try_from(filter: &SearchFilter) -> Result<Self, Self::Error> {
+        let mut must = Vec::new();
+
+        if let Some(id) = filter.must_have_id {
+            must.push(Condition::has_id([PointId::from(id.to_string())]));
+        }
+        if let Some(fields) = &filter.must_match {
+            must.extend(must_match_to_conditions(fields)?);
+        }
+        if let Some(namespace) = &filter.namespace_filter {
+            must.push(Condition::matches("namespace", namespace.clone()));
+        }
+        for condition in &filter.must {
+            must.push(payload_condition_to_qdrant(condition)?);
+        }
+
+        let should = filter
+            .should
+            .iter()
+            .map(payload_condition_to_qdrant)
+            .collect::<VectorResult<Vec<_>>>()?;
+
+        Ok(Filter {
+            must,
+            should,
+            ..Default::default()
+        })
+    }
+}
+
+pub fn payload_condition_to_qdrant(condition: &PayloadCondition) -> VectorResult<Condition> {
+    match condition {
+        PayloadCondition::Eq { key, value } => Ok(Condition::matches(
+            key.clone(),
+            filter_value_to_match(value),
+        )),
+        PayloadCondition::Range { key, range } => Ok(Condition::range(
+            key.clone(),
+            Range {
+                gt: range.gt,
+                gte: range.gte,
+                lt: range.lt,
+                lte: range.lte,
+            },
+        )),
+        PayloadCondition::In { key, values } => {
+            let value = match filter_values_to_repeated(key, values)? {
+                Repeated::Keywords(strings) => MatchValue::Keywords(RepeatedStrings { strings }),
+                Repeated::Integers(integers) => MatchValue::Integers(RepeatedIntegers { integers }),
+            };
+            Ok(Condition::matches(key.clone(), value))
+        }
+        PayloadCondition::NotIn { key, values } => {
+            let value = match filter_values_to_repeated(key, values)? {
+                Repeated::Keywords(strings) => {
+                    MatchValue::ExceptKeywords(RepeatedStrings { strings })
+                }
+                Repeated::Integers(integers) => {
+                    MatchValue::ExceptIntegers(RepeatedIntegers { integers })
+                }
+            };
+            Ok(Condition::matches(key.clone(), value))
+        }
+    }
+}
+
+fn filter_value_to_match(value: &FilterValue) -> MatchValue {
+    match value {
+        FilterValue::Bool(b) => MatchValue::Boolean(*b),
+        FilterValue::Integer(i) => MatchValue::Integer(*i),
+        FilterValue::Keyword(s) => MatchValue::Keyword(s.clone()),
+    }
+}
+
+/// Values of an `in`/`not-in` condition, which Qdrant only supports for
+/// keywords or integers
+enum Repeated {
+    Keywords(Vec<String>),
+    Integers(Vec<i64>),
+}
+
+fn filter_values_to_repeated(key: &str, values: &[FilterValue]) -> VectorResult<Repeated> {
+    let invalid = || {
+        VectorError::Validation(format!(
+            "Filter on '{}' needs a non-empty list of only keywords or only integers",
+            key
+        ))
+    };
+
+    match values.first() {
+        Some(FilterValue::Keyword(_)) => values
+            .iter()
+            .map(|v| match v {
+                FilterValue::Keyword(s) => Ok(s.clone()),
+                _ => Err(invalid()),
+            })
+            .collect::<VectorResult<_>>()
+            .map(Repeated::Keywords),
+        Some(FilterValue::Integer(_)) => values
+            .iter()
+            .map(|v| match v {
+                FilterValue::Integer(i) => Ok(*i),
+                _ => Err(invalid()),
+            })
+            .collect::<VectorResult<_>>()
+            .map(Repeated::Integers),
+        _ => Err(invalid()),
+    }
+}
+
+fn must_match_to_conditions(fields: &serde_json::Value) -> VectorResult<Vec<Condition>> {
+    let serde_json::Value::Object(map) = fields else {
+        return Err(VectorError::Validation(
+            "must_match must be a JSON object".to_string(),
+        ));
+    };
+
+    map.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::Bool(b) => FilterValue::Bool(*b),
+                serde_json::Value::String(s) => FilterValue::Keyword(s.clone()),
+                serde_json::Value::Number(n) if n.is_i64() => {
+                    FilterValue::Integer(n.as_i64().unwrap_or_default())
+                }
+                _ => {
+                    return Err(VectorError::Validation(format!(
+                        "must_match.{} must be a string, integer or boolean",
+                        key
+                    )));
+                }
+            };
+            Ok(Condition::matches(
+                key.clone(),
+                filter_value_to_match(&value),
+            ))
+        })
+        .collect()
+}
+
 // ===== Helper Functions =====
 
 pub fn bytes_to_uuid(bytes: &[u8]) -> VectorResult<Uuid> {
@@ -323,3 +471,135 @@ pub fn bytes_to_uuid(bytes: &[u8]) -> VectorResult<Uuid> {
 pub fn uuid_to_bytes(id: Uuid) -> Vec<u8> {
     id.as_bytes().to_vec()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FilterRange;
+    use qdrant_client::qdrant::condition::ConditionOneOf;
+
+    fn field_condition(condition: &Condition) -> &qdrant_client::qdrant::FieldCondition {
+        match &condition.condition_one_of {
+            Some(ConditionOneOf::Field(field)) => field,
+            other => panic!("expected field condition, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_range_and_equality_filter() {
+        let filter = SearchFilter::default()
+            .and(PayloadCondition::eq("status", "open"))
+            .and(PayloadCondition::range(
+                "priority",
+                FilterRange::default().gte(2.0).lt(5.0),
+            ));
+
+        let qdrant_filter = Filter::try_from(&filter).unwrap();
+
+        assert_eq!(qdrant_filter.must.len(), 2);
+        assert!(qdrant_filter.should.is_empty());
+        assert!(qdrant_filter.must_not.is_empty());
+
+        let status = field_condition(&qdrant_filter.must[0]);
+        assert_eq!(status.key, "status");
+        assert_eq!(
+            status.r#match.as_ref().and_then(|m| m.match_value.clone()),
+            Some(MatchValue::Keyword("open".to_string()))
+        );
+
+        let priority = field_condition(&qdrant_filter.must[1]);
+        assert_eq!(priority.key, "priority");
+        assert_eq!(
+            priority.range,
+            Some(Range {
+                gt: None,
+                gte: Some(2.0),
+                lt: Some(5.0),
+                lte: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_or_group_goes_to_should() {
+        let filter = SearchFilter::default()
+            .or(PayloadCondition::eq("kind", "task"))
+            .or(PayloadCondition::eq("kind", "note"));
+
+        let qdrant_filter = Filter::try_from(&filter).unwrap();
+
+        assert!(qdrant_filter.must.is_empty());
+        assert_eq!(qdrant_filter.should.len(), 2);
+    }
+
+    #[test]
+    fn test_in_and_not_in() {
+        let filter = SearchFilter::default()
+            .and(PayloadCondition::is_in("tag", ["a", "b"]))
+            .and(PayloadCondition::not_in("year", [2020_i64, 2021]));
+
+        let qdrant_filter = Filter::try_from(&filter).unwrap();
+
+        let tags = field_condition(&qdrant_filter.must[0]);
+        assert_eq!(
+            tags.r#match.as_ref().and_then(|m| m.match_value.clone()),
+            Some(MatchValue::Keywords(RepeatedStrings {
+                strings: vec!["a".to_string(), "b".to_string()],
+            }))
+        );
+
+        let years = field_condition(&qdrant_filter.must[1]);
+        assert_eq!(
+            years.r#match.as_ref().and_then(|m| m.match_value.clone()),
+            Some(MatchValue::ExceptIntegers(RepeatedIntegers {
+                integers: vec![2020, 2021],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_mixed_in_values_are_rejected() {
+        let filter = SearchFilter::default().and(PayloadCondition::In {
+            key: "tag".to_string(),
+            values: vec![FilterValue::from("a"), FilterValue::from(1_i64)],
+        });
+
+        assert!(matches!(
+            Filter::try_from(&filter),
+            Err(VectorError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_legacy_fields_are_combined() {
+        let filter = SearchFilter {
+            must_have_id: Some(Uuid::new_v4()),
+            must_match: Some(serde_json::json!({"done": false, "owner": "ana"})),
+            namespace_filter: Some("docs".to_string()),
+            ..Default::default()
+        }
+        .and(PayloadCondition::eq("priority", 1_i64));
+
+        let qdrant_filter = Filter::try_from(&filter).unwrap();
+
+        // has_id + two must_match keys + namespace + explicit condition
+        assert_eq!(qdrant_filter.must.len(), 5);
+        assert!(matches!(
+            qdrant_filter.must[0].condition_one_of,
+            Some(ConditionOneOf::HasId(_))
+        ));
+    }
+
+    #[test]
+    fn test_must_match_rejects_floats() {
+        let filter = SearchFilter {
+            must_match: Some(serde_json::json!({"score": 0.5})),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            Filter::try_from(&filter),
+            Err(VectorError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,388 @@
+//! Cohere embedding provider implementation
+//!
+//! Uses the Cohere v2 embed API with float embeddings.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::provider::{check_dimensions, embed_in_chunks};
+use super::EmbeddingProvider;
+use crate::error::{VectorError, VectorResult};
+use crate::models::{EmbeddingModel, EmbeddingProviderType, EmbeddingResult};
+
+/// Maximum texts per Cohere embed request
+const DEFAULT_MAX_BATCH_SIZE: usize = 96;
+
+/// Cohere embedding provider configuration
+#[derive(Debug, Clone)]
+pub struct CohereConfig {
+    pub api_key: String,
+    pub base_url: String,
+    /// Cohere `input_type` for documents, e.g. `search_document`
+    pub input_type: String,
+    /// Cohere `input_type` for search queries, e.g. `search_query`
+    pub query_input_type: String,
+    /// Texts per embed request; larger batches are split
+    pub max_batch_size: usize,
+}
+
+impl CohereConfig {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            base_url: "https://api.cohere.com/v2".to_string(),
+            input_type: "search_document".to_string(),
+            query_input_type: "search_query".to_string(),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn with_input_type(mut self, input_type: String) -> Self {
+        self.input_type = input_type;
+        self
+    }
+
+    pub fn with_query_input_type(mut self, query_input_type: String) -> Self {
+        self.query_input_type = query_input_type;
+        self
+    }
+
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    pub fn from_env() -> VectorResult<Self> {
+        let api_key = std::env::var("COHERE_API_KEY")
+            .map_err(|_| VectorError::Config("COHERE_API_KEY not set".to_string()))?;
+
+        let mut config = Self::new(api_key);
+        if let Ok(base_url) = std::env::var("COHERE_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+}
+
+/// Cohere embeddings provider
+pub struct CohereProvider {
+    client: Client,
+    config: CohereConfig,
+}
+
+impl CohereProvider {
+    pub fn new(config: CohereConfig) -> Self {
+        Self {
+            client: Client::new(),
+            config,
+        }
+    }
+
+    pub fn from_env() -> VectorResult<Self> {
+        Ok(Self::new(CohereConfig::from_env()?))
+    }
+
+    /// Map EmbeddingModel to Cohere model name
+    fn model_name(model: EmbeddingModel) -> VectorResult<&'static str> {
+        match model {
+            EmbeddingModel::CohereEmbedV3 => Ok("embed-english-v3.0"),
+            EmbeddingModel::CohereEmbedMultilingualV3 => Ok("embed-multilingual-v3.0"),
+            other => Err(VectorError::Validation(format!(
+                "Model {} is not supported by Cohere",
+                other.model_name()
+            ))),
+        }
+    }
+
+    fn build_request(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+        input_type: &str,
+    ) -> VectorResult<CohereRequest> {
+        Ok(CohereRequest {
+            model: Self::model_name(model)?.to_string(),
+            texts: texts.to_vec(),
+            input_type: input_type.to_string(),
+            embedding_types: vec!["float".to_string()],
+        })
+    }
+
+    /// Embed up to `max_batch_size` texts in a single request
+    async fn embed_chunk(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+        input_type: &str,
+    ) -> VectorResult<Vec<EmbeddingResult>> {
+        let request = self.build_request(model, texts, input_type)?;
+
+        let response = self
+            .client
+            .post(format!("{}/embed", self.config.base_url))
+            .header("Authorization", format!("Bearer {}", self.config.api_key))
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(VectorError::Embedding(format!(
+                "Cohere API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let embedding_response: CohereResponse = response.json().await?;
+
+        let input_tokens = embedding_response
+            .meta
+            .and_then(|m| m.billed_units)
+            .map(|b| b.input_tokens)
+            .unwrap_or(0);
+        let tokens_per_embedding = input_tokens / texts.len() as u32;
+
+        let results: Vec<EmbeddingResult> = embedding_response
+            .embeddings
+            .float
+            .into_iter()
+            .map(|values| EmbeddingResult {
+                dimension: values.len() as u32,
+                values,
+                tokens_used: tokens_per_embedding,
+            })
+            .collect();
+
+        check_dimensions(model, &results)?;
+        Ok(results)
+    }
+}
+
+// Cohere request/response types
+
+#[derive(Debug, Serialize)]
+struct CohereRequest {
+    model: String,
+    texts: Vec<String>,
+    input_type: String,
+    embedding_types: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CohereResponse {
+    embeddings: CohereEmbeddings,
+    #[serde(default)]
+    meta: Option<CohereMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CohereEmbeddings {
+    float: Vec<Vec<f32>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CohereMeta {
+    #[serde(default)]
+    billed_units: Option<CohereBilledUnits>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CohereBilledUnits {
+    #[serde(default)]
+    input_tokens: u32,
+}
+
+#[async_trait]
+impl EmbeddingProvider for CohereProvider {
+    fn provider_type(&self) -> EmbeddingProviderType {
+        EmbeddingProviderType::Cohere
+    }
+
+    async fn embed(&self, model: EmbeddingModel, text: &str) -> VectorResult<EmbeddingResult> {
+        let results = self.embed_batch(model, &[text.to_string()]).await?;
+        results
+            .into_iter()
+            .next()
+            .ok_or_else(|| VectorError::Embedding("No embedding returned".to_string()))
+    }
+
+    async fn embed_batch(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+    ) -> VectorResult<Vec<EmbeddingResult>> {
+        embed_in_chunks(texts, self.config.max_batch_size, |chunk| {
+            self.embed_chunk(model, chunk, &self.config.input_type)
+        })
+        .await
+    }
+
+    async fn embed_query(
+        &self,
+        model: EmbeddingModel,
+        text: &str,
+    ) -> VectorResult<EmbeddingResult> {
+        let results = self
+            .embed_chunk(model, &[text.to_string()], &self.config.query_input_type)
+            .await?;
+        results
+            .into_iter()
+            .next()
+            .ok_or_else(|| VectorError::Embedding("No embedding returned".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedding::mock_api::MockApi;
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    fn provider(base_url: String) -> CohereProvider {
+        CohereProvider::new(CohereConfig::new("test-key".to_string()).with_base_url(base_url))
+    }
+
+    #[test]
+    fn test_model_names() {
+        assert_eq!(
+            CohereProvider::model_name(EmbeddingModel::CohereEmbedV3).unwrap(),
+            "embed-english-v3.0"
+        );
+        assert_eq!(
+            CohereProvider::model_name(EmbeddingModel::CohereEmbedMultilingualV3).unwrap(),
+            "embed-multilingual-v3.0"
+        );
+        assert!(matches!(
+            CohereProvider::model_name(EmbeddingModel::TextEmbedding3Small),
+            Err(VectorError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_request_body() {
+        let provider = provider("http://localhost".to_string());
+        let request = provider
+            .build_request(
+                EmbeddingModel::CohereEmbedV3,
+                &["hello".to_string()],
+                "search_document",
+            )
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({
+                "model": "embed-english-v3.0",
+                "texts": ["hello"],
+                "input_type": "search_document",
+                "embedding_types": ["float"],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_embed_batch() {
+        let api = MockApi::start(
+            "/embed",
+            StatusCode::OK,
+            json!({
+                "id": "abc",
+                "embeddings": {"float": [vec![0.1; 1024], vec![0.2; 1024]]},
+                "meta": {"billed_units": {"input_tokens": 8}},
+            }),
+        )
+        .await;
+
+        let texts = vec!["first".to_string(), "second".to_string()];
+        let results = provider(api.base_url.clone())
+            .embed_batch(EmbeddingModel::CohereEmbedV3, &texts)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].dimension, 1024);
+        assert_eq!(results[1].tokens_used, 4);
+
+        let requests = api.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].authorization.as_deref(),
+            Some("Bearer test-key")
+        );
+        assert_eq!(requests[0].body["texts"], json!(["first", "second"]));
+    }
+
+    #[tokio::test]
+    async fn test_embed_query_uses_query_input_type() {
+        let api = MockApi::start(
+            "/embed",
+            StatusCode::OK,
+            json!({"embeddings": {"float": [vec![0.1; 1024]]}}),
+        )
+        .await;
+        let provider = provider(api.base_url.clone());
+
+        provider
+            .embed(EmbeddingModel::CohereEmbedV3, "document")
+            .await
+            .unwrap();
+        provider
+            .embed_query(EmbeddingModel::CohereEmbedV3, "query")
+            .await
+            .unwrap();
+
+        let requests = api.requests();
+        assert_eq!(requests[0].body["input_type"], "search_document");
+        assert_eq!(requests[1].body["input_type"], "search_query");
+    }
+
+    #[tokio::test]
+    async fn test_api_error_is_mapped() {
+        let api = MockApi::start(
+            "/embed",
+            StatusCode::TOO_MANY_REQUESTS,
+            json!({"message": "rate limited"}),
+        )
+        .await;
+
+        let err = provider(api.base_url.clone())
+            .embed(EmbeddingModel::CohereEmbedV3, "hello")
+            .await
+            .unwrap_err();
+
+        match err {
+            VectorError::Embedding(msg) => {
+                assert!(msg.contains("429"), "unexpected message: {msg}");
+                assert!(msg.contains("rate limited"), "unexpected message: {msg}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dimension_mismatch_is_rejected() {
+        let api = MockApi::start(
+            "/embed",
+            StatusCode::OK,
+            json!({"embeddings": {"float": [[0.1, 0.2]]}}),
+        )
+        .await;
+
+        let err = provider(api.base_url.clone())
+            .embed(EmbeddingModel::CohereEmbedV3, "hello")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VectorError::Embedding(_)));
+    }
+}
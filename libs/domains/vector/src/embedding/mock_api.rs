@@ -0,0 +1,58 @@
+//! Local HTTP server standing in for embedding APIs in tests
+
+use std::sync::{Arc, Mutex};
+
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::Value;
+
+/// A request received by [`MockApi`]
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedRequest {
+    pub authorization: Option<String>,
+    pub body: Value,
+}
+
+/// Mocked embeddings endpoint answering every request with a fixed response
+pub(crate) struct MockApi {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockApi {
+    /// Serve `response` with `status` on `path`, recording incoming requests
+    pub async fn start(path: &str, status: StatusCode, response: Value) -> Self {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        let handler = move |headers: HeaderMap, Json(body): Json<Value>| {
+            let recorded = recorded.clone();
+            let response = response.clone();
+            async move {
+                let authorization = headers
+                    .get(AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok())
+                    .map(String::from);
+                recorded.lock().unwrap().push(RecordedRequest {
+                    authorization,
+                    body,
+                });
+                (status, Json(response))
+            }
+        };
+
+        let app = Router::new().route(path, post(handler));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        Self { base_url, requests }
+    }
+
+    /// Requests received so far
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
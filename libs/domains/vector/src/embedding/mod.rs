@@ -1,7 +1,15 @@
+mod cohere;
+#[cfg(test)]
+mod mock_api;
 mod openai;
 mod provider;
 mod vertexai;
+mod voyage;
 
-pub use openai::OpenAIProvider;
+pub use cohere::{CohereConfig, CohereProvider};
+pub use openai::{OpenAIConfig, OpenAIProvider};
 pub use provider::EmbeddingProvider;
+#[cfg(test)]
+pub use provider::MockEmbeddingProvider;
 pub use vertexai::VertexAIProvider;
+pub use voyage::{VoyageConfig, VoyageProvider};
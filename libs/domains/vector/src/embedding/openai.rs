@@ -2,15 +2,21 @@ use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
+use super::provider::{check_dimensions, embed_in_chunks};
 use super::EmbeddingProvider;
 use crate::error::{VectorError, VectorResult};
 use crate::models::{EmbeddingModel, EmbeddingProviderType, EmbeddingResult};
 
+/// Maximum inputs per OpenAI embeddings request
+const DEFAULT_MAX_BATCH_SIZE: usize = 2048;
+
 /// OpenAI embedding provider configuration
 #[derive(Debug, Clone)]
 pub struct OpenAIConfig {
     pub api_key: String,
     pub base_url: String,
+    /// Texts per embeddings request; larger batches are split
+    pub max_batch_size: usize,
 }
 
 impl OpenAIConfig {
@@ -18,6 +24,7 @@ impl OpenAIConfig {
         Self {
             api_key,
             base_url: "https://api.openai.com/v1".to_string(),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
         }
     }
 
@@ -26,6 +33,11 @@ impl OpenAIConfig {
         self
     }
 
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
     pub fn from_env() -> VectorResult<Self> {
         let api_key = std::env::var("OPENAI_API_KEY")
             .map_err(|_| VectorError::Config("OPENAI_API_KEY not set".to_string()))?;
@@ -33,7 +45,11 @@ impl OpenAIConfig {
         let base_url = std::env::var("OPENAI_BASE_URL")
             .unwrap_or_else(|_| "https://api.openai.com/v1".to_string());
 
-        Ok(Self { api_key, base_url })
+        Ok(Self {
+            api_key,
+            base_url,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+        })
     }
 }
 
@@ -54,58 +70,13 @@ impl OpenAIProvider {
     pub fn from_env() -> VectorResult<Self> {
         Ok(Self::new(OpenAIConfig::from_env()?))
     }
-}
-
-#[derive(Debug, Serialize)]
-struct EmbeddingRequest {
-    model: String,
-    input: Vec<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    dimensions: Option<u32>,
-}
-
-#[derive(Debug, Deserialize)]
-struct EmbeddingResponse {
-    data: Vec<EmbeddingData>,
-    usage: EmbeddingUsage,
-}
-
-#[derive(Debug, Deserialize)]
-struct EmbeddingData {
-    embedding: Vec<f32>,
-    index: usize,
-}
-
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)]
-struct EmbeddingUsage {
-    prompt_tokens: u32,
-    total_tokens: u32,
-}
-
-#[async_trait]
-impl EmbeddingProvider for OpenAIProvider {
-    fn provider_type(&self) -> EmbeddingProviderType {
-        EmbeddingProviderType::OpenAI
-    }
 
-    async fn embed(&self, model: EmbeddingModel, text: &str) -> VectorResult<EmbeddingResult> {
-        let results = self.embed_batch(model, &[text.to_string()]).await?;
-        results
-            .into_iter()
-            .next()
-            .ok_or_else(|| VectorError::Embedding("No embedding returned".to_string()))
-    }
-
-    async fn embed_batch(
+    /// Embed up to `max_batch_size` texts in a single request
+    async fn embed_chunk(
         &self,
         model: EmbeddingModel,
         texts: &[String],
     ) -> VectorResult<Vec<EmbeddingResult>> {
-        if texts.is_empty() {
-            return Ok(vec![]);
-        }
-
         let dimensions = match model {
             EmbeddingModel::Custom(dim) => Some(dim),
             _ => None,
@@ -143,14 +114,70 @@ impl EmbeddingProvider for OpenAIProvider {
 
         let tokens_per_embedding = embedding_response.usage.total_tokens / texts.len() as u32;
 
-        Ok(data
+        let results: Vec<EmbeddingResult> = data
             .into_iter()
             .map(|d| EmbeddingResult {
                 dimension: d.embedding.len() as u32,
                 values: d.embedding,
                 tokens_used: tokens_per_embedding,
             })
-            .collect())
+            .collect();
+
+        check_dimensions(model, &results)?;
+        Ok(results)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct EmbeddingRequest {
+    model: String,
+    input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimensions: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+    usage: EmbeddingUsage,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    embedding: Vec<f32>,
+    index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct EmbeddingUsage {
+    prompt_tokens: u32,
+    total_tokens: u32,
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAIProvider {
+    fn provider_type(&self) -> EmbeddingProviderType {
+        EmbeddingProviderType::OpenAI
+    }
+
+    async fn embed(&self, model: EmbeddingModel, text: &str) -> VectorResult<EmbeddingResult> {
+        let results = self.embed_batch(model, &[text.to_string()]).await?;
+        results
+            .into_iter()
+            .next()
+            .ok_or_else(|| VectorError::Embedding("No embedding returned".to_string()))
+    }
+
+    async fn embed_batch(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+    ) -> VectorResult<Vec<EmbeddingResult>> {
+        embed_in_chunks(texts, self.config.max_batch_size, |chunk| {
+            self.embed_chunk(model, chunk)
+        })
+        .await
     }
 }
 
@@ -174,6 +201,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_max_batch_size() {
+        let config = OpenAIConfig::new("key".to_string());
+        assert_eq!(config.max_batch_size, 2048);
+
+        let config = config.with_max_batch_size(100);
+        assert_eq!(config.max_batch_size, 100);
+    }
+
     #[test]
     fn test_model_dimensions() {
         assert_eq!(EmbeddingModel::TextEmbedding3Small.dimension(), 1536);
@@ -1,6 +1,8 @@
+use std::future::Future;
+
 use async_trait::async_trait;
 
-use crate::error::VectorResult;
+use crate::error::{VectorError, VectorResult};
 use crate::models::{EmbeddingModel, EmbeddingProviderType, EmbeddingResult};
 
 /// Trait for embedding generation providers
@@ -16,9 +18,179 @@ pub trait EmbeddingProvider: Send + Sync {
     async fn embed(&self, model: EmbeddingModel, text: &str) -> VectorResult<EmbeddingResult>;
 
     /// Generate embeddings for multiple texts in batch
+    ///
+    /// Results are in input order. The default embeds one text at a time;
+    /// providers with a native batch API should override it.
     async fn embed_batch(
         &self,
         model: EmbeddingModel,
         texts: &[String],
-    ) -> VectorResult<Vec<EmbeddingResult>>;
+    ) -> VectorResult<Vec<EmbeddingResult>> {
+        let mut results = Vec::with_capacity(texts.len());
+        for text in texts {
+            results.push(self.embed(model, text).await?);
+        }
+        Ok(results)
+    }
+
+    /// Generate embedding for a search query
+    ///
+    /// Defaults to `embed`; providers that embed queries and documents
+    /// differently should override it.
+    async fn embed_query(
+        &self,
+        model: EmbeddingModel,
+        text: &str,
+    ) -> VectorResult<EmbeddingResult> {
+        self.embed(model, text).await
+    }
+}
+
+/// Embed `texts` in chunks of at most `max_batch_size`, preserving order
+///
+/// Used by providers whose batch endpoint caps the number of inputs.
+pub(crate) async fn embed_in_chunks<'a, F, Fut>(
+    texts: &'a [String],
+    max_batch_size: usize,
+    mut embed_chunk: F,
+) -> VectorResult<Vec<EmbeddingResult>>
+where
+    F: FnMut(&'a [String]) -> Fut,
+    Fut: Future<Output = VectorResult<Vec<EmbeddingResult>>>,
+{
+    let mut results = Vec::with_capacity(texts.len());
+    for chunk in texts.chunks(max_batch_size.max(1)) {
+        let embedded = embed_chunk(chunk).await?;
+        if embedded.len() != chunk.len() {
+            return Err(VectorError::Embedding(format!(
+                "Expected {} embeddings, got {}",
+                chunk.len(),
+                embedded.len()
+            )));
+        }
+        results.extend(embedded);
+    }
+    Ok(results)
+}
+
+/// Check that every embedding has the dimension the model produces
+pub(crate) fn check_dimensions(
+    model: EmbeddingModel,
+    results: &[EmbeddingResult],
+) -> VectorResult<()> {
+    let expected = model.dimension();
+    match results.iter().find(|r| r.dimension != expected) {
+        Some(result) => Err(VectorError::Embedding(format!(
+            "Expected {}-dimensional embeddings from {}, got {}",
+            expected,
+            model.model_name(),
+            result.dimension
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn texts(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("text {}", i)).collect()
+    }
+
+    fn fake_embeddings(chunk: &[String]) -> Vec<EmbeddingResult> {
+        chunk
+            .iter()
+            .map(|text| EmbeddingResult {
+                values: vec![text.len() as f32],
+                dimension: 1,
+                tokens_used: 1,
+            })
+            .collect()
+    }
+
+    async fn chunk_sizes(count: usize, max_batch_size: usize) -> Vec<usize> {
+        let input = texts(count);
+        let sizes = Mutex::new(Vec::new());
+        let results = embed_in_chunks(&input, max_batch_size, |chunk| {
+            sizes.lock().unwrap().push(chunk.len());
+            async move { Ok(fake_embeddings(chunk)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(results.len(), count);
+        sizes.into_inner().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_chunking_at_batch_boundary() {
+        assert_eq!(chunk_sizes(4, 4).await, vec![4]);
+        assert_eq!(chunk_sizes(5, 4).await, vec![4, 1]);
+        assert_eq!(chunk_sizes(8, 4).await, vec![4, 4]);
+        assert_eq!(chunk_sizes(0, 4).await, Vec::<usize>::new());
+    }
+
+    #[tokio::test]
+    async fn test_chunking_rejects_missing_embeddings() {
+        let input = texts(3);
+        let result = embed_in_chunks(&input, 2, |chunk| async move {
+            Ok(fake_embeddings(&chunk[..1]))
+        })
+        .await;
+        assert!(matches!(result, Err(VectorError::Embedding(_))));
+    }
+
+    #[test]
+    fn test_check_dimensions() {
+        let result = |dimension| EmbeddingResult {
+            values: vec![0.0; dimension as usize],
+            dimension,
+            tokens_used: 0,
+        };
+
+        assert!(check_dimensions(EmbeddingModel::Voyage3Lite, &[result(512)]).is_ok());
+        assert!(check_dimensions(EmbeddingModel::Custom(8), &[result(8), result(8)]).is_ok());
+        assert!(matches!(
+            check_dimensions(EmbeddingModel::Voyage3Lite, &[result(512), result(1024)]),
+            Err(VectorError::Embedding(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_default_embed_batch_loops_over_embed() {
+        let mut provider = MockEmbeddingProvider::new();
+        provider.expect_embed().times(3).returning(|_, text| {
+            Ok(EmbeddingResult {
+                values: vec![text.len() as f32],
+                dimension: 1,
+                tokens_used: 1,
+            })
+        });
+
+        struct Looping(MockEmbeddingProvider);
+
+        #[async_trait]
+        impl EmbeddingProvider for Looping {
+            fn provider_type(&self) -> EmbeddingProviderType {
+                EmbeddingProviderType::Local
+            }
+
+            async fn embed(
+                &self,
+                model: EmbeddingModel,
+                text: &str,
+            ) -> VectorResult<EmbeddingResult> {
+                self.0.embed(model, text).await
+            }
+        }
+
+        let input = vec!["a".to_string(), "bb".to_string(), "ccc".to_string()];
+        let results = Looping(provider)
+            .embed_batch(EmbeddingModel::Custom(1), &input)
+            .await
+            .unwrap();
+        let values: Vec<f32> = results.iter().map(|r| r.values[0]).collect();
+        assert_eq!(values, vec![1.0, 2.0, 3.0]);
+    }
 }
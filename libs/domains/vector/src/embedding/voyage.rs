@@ -0,0 +1,387 @@
+//! Voyage AI embedding provider implementation
+//!
+//! Uses the Voyage embeddings API (OpenAI-compatible response shape).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::provider::{check_dimensions, embed_in_chunks};
+use super::EmbeddingProvider;
+use crate::error::{VectorError, VectorResult};
+use crate::models::{EmbeddingModel, EmbeddingProviderType, EmbeddingResult};
+
+/// Maximum inputs per Voyage embeddings request
+const DEFAULT_MAX_BATCH_SIZE: usize = 128;
+
+/// Voyage AI embedding provider configuration
+#[derive(Debug, Clone)]
+pub struct VoyageConfig {
+    pub api_key: String,
+    pub base_url: String,
+    /// Voyage `input_type` for documents (`document`); omitted when `None`
+    pub input_type: Option<String>,
+    /// Voyage `input_type` for search queries (`query`); omitted when `None`
+    pub query_input_type: Option<String>,
+    /// Texts per embeddings request; larger batches are split
+    pub max_batch_size: usize,
+}
+
+impl VoyageConfig {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            base_url: "https://api.voyageai.com/v1".to_string(),
+            input_type: None,
+            query_input_type: Some("query".to_string()),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn with_input_type(mut self, input_type: String) -> Self {
+        self.input_type = Some(input_type);
+        self
+    }
+
+    pub fn with_query_input_type(mut self, query_input_type: String) -> Self {
+        self.query_input_type = Some(query_input_type);
+        self
+    }
+
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    pub fn from_env() -> VectorResult<Self> {
+        let api_key = std::env::var("VOYAGE_API_KEY")
+            .map_err(|_| VectorError::Config("VOYAGE_API_KEY not set".to_string()))?;
+
+        let mut config = Self::new(api_key);
+        if let Ok(base_url) = std::env::var("VOYAGE_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+}
+
+/// Voyage AI embeddings provider
+pub struct VoyageProvider {
+    client: Client,
+    config: VoyageConfig,
+}
+
+impl VoyageProvider {
+    pub fn new(config: VoyageConfig) -> Self {
+        Self {
+            client: Client::new(),
+            config,
+        }
+    }
+
+    pub fn from_env() -> VectorResult<Self> {
+        Ok(Self::new(VoyageConfig::from_env()?))
+    }
+
+    /// Map EmbeddingModel to Voyage model name
+    fn model_name(model: EmbeddingModel) -> VectorResult<&'static str> {
+        match model {
+            EmbeddingModel::Voyage3 => Ok("voyage-3"),
+            EmbeddingModel::Voyage3Lite => Ok("voyage-3-lite"),
+            EmbeddingModel::VoyageCode3 => Ok("voyage-code-3"),
+            other => Err(VectorError::Validation(format!(
+                "Model {} is not supported by Voyage",
+                other.model_name()
+            ))),
+        }
+    }
+
+    fn build_request(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+        input_type: Option<&str>,
+    ) -> VectorResult<VoyageRequest> {
+        Ok(VoyageRequest {
+            model: Self::model_name(model)?.to_string(),
+            input: texts.to_vec(),
+            input_type: input_type.map(str::to_string),
+        })
+    }
+
+    /// Embed up to `max_batch_size` texts in a single request
+    async fn embed_chunk(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+        input_type: Option<&str>,
+    ) -> VectorResult<Vec<EmbeddingResult>> {
+        let request = self.build_request(model, texts, input_type)?;
+
+        let response = self
+            .client
+            .post(format!("{}/embeddings", self.config.base_url))
+            .header("Authorization", format!("Bearer {}", self.config.api_key))
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(VectorError::Embedding(format!(
+                "Voyage API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let embedding_response: VoyageResponse = response.json().await?;
+
+        // Sort by index to maintain order
+        let mut data = embedding_response.data;
+        data.sort_by_key(|d| d.index);
+
+        let tokens_per_embedding = embedding_response.usage.total_tokens / texts.len() as u32;
+
+        let results: Vec<EmbeddingResult> = data
+            .into_iter()
+            .map(|d| EmbeddingResult {
+                dimension: d.embedding.len() as u32,
+                values: d.embedding,
+                tokens_used: tokens_per_embedding,
+            })
+            .collect();
+
+        check_dimensions(model, &results)?;
+        Ok(results)
+    }
+}
+
+// Voyage request/response types
+
+#[derive(Debug, Serialize)]
+struct VoyageRequest {
+    model: String,
+    input: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VoyageResponse {
+    data: Vec<VoyageEmbedding>,
+    usage: VoyageUsage,
+}
+
+#[derive(Debug, Deserialize)]
+struct VoyageEmbedding {
+    embedding: Vec<f32>,
+    index: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct VoyageUsage {
+    total_tokens: u32,
+}
+
+#[async_trait]
+impl EmbeddingProvider for VoyageProvider {
+    fn provider_type(&self) -> EmbeddingProviderType {
+        EmbeddingProviderType::Voyage
+    }
+
+    async fn embed(&self, model: EmbeddingModel, text: &str) -> VectorResult<EmbeddingResult> {
+        let results = self.embed_batch(model, &[text.to_string()]).await?;
+        results
+            .into_iter()
+            .next()
+            .ok_or_else(|| VectorError::Embedding("No embedding returned".to_string()))
+    }
+
+    async fn embed_batch(
+        &self,
+        model: EmbeddingModel,
+        texts: &[String],
+    ) -> VectorResult<Vec<EmbeddingResult>> {
+        embed_in_chunks(texts, self.config.max_batch_size, |chunk| {
+            self.embed_chunk(model, chunk, self.config.input_type.as_deref())
+        })
+        .await
+    }
+
+    async fn embed_query(
+        &self,
+        model: EmbeddingModel,
+        text: &str,
+    ) -> VectorResult<EmbeddingResult> {
+        let results = self
+            .embed_chunk(
+                model,
+                &[text.to_string()],
+                self.config.query_input_type.as_deref(),
+            )
+            .await?;
+        results
+            .into_iter()
+            .next()
+            .ok_or_else(|| VectorError::Embedding("No embedding returned".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedding::mock_api::MockApi;
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    fn provider(base_url: String) -> VoyageProvider {
+        VoyageProvider::new(VoyageConfig::new("test-key".to_string()).with_base_url(base_url))
+    }
+
+    #[test]
+    fn test_model_names() {
+        assert_eq!(
+            VoyageProvider::model_name(EmbeddingModel::Voyage3).unwrap(),
+            "voyage-3"
+        );
+        assert_eq!(
+            VoyageProvider::model_name(EmbeddingModel::VoyageCode3).unwrap(),
+            "voyage-code-3"
+        );
+        assert!(matches!(
+            VoyageProvider::model_name(EmbeddingModel::CohereEmbedV3),
+            Err(VectorError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_request_body() {
+        let provider = provider("http://localhost".to_string());
+        let request = provider
+            .build_request(EmbeddingModel::Voyage3Lite, &["hello".to_string()], None)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            json!({"model": "voyage-3-lite", "input": ["hello"]})
+        );
+
+        let request = provider
+            .build_request(
+                EmbeddingModel::Voyage3,
+                &["hello".to_string()],
+                Some("query"),
+            )
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(request).unwrap()["input_type"],
+            "query"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_embed_batch_keeps_input_order() {
+        let api = MockApi::start(
+            "/embeddings",
+            StatusCode::OK,
+            json!({
+                "object": "list",
+                "data": [
+                    {"object": "embedding", "embedding": vec![0.2; 512], "index": 1},
+                    {"object": "embedding", "embedding": vec![0.1; 512], "index": 0},
+                ],
+                "model": "voyage-3-lite",
+                "usage": {"total_tokens": 6},
+            }),
+        )
+        .await;
+
+        let texts = vec!["first".to_string(), "second".to_string()];
+        let results = provider(api.base_url.clone())
+            .embed_batch(EmbeddingModel::Voyage3Lite, &texts)
+            .await
+            .unwrap();
+
+        assert_eq!(results[0].values[0], 0.1);
+        assert_eq!(results[1].values[0], 0.2);
+        assert_eq!(results[0].tokens_used, 3);
+
+        let requests = api.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].authorization.as_deref(),
+            Some("Bearer test-key")
+        );
+        assert_eq!(requests[0].body["model"], "voyage-3-lite");
+    }
+
+    #[tokio::test]
+    async fn test_embed_query_uses_query_input_type() {
+        let api = MockApi::start(
+            "/embeddings",
+            StatusCode::OK,
+            json!({
+                "data": [{"embedding": vec![0.1; 512], "index": 0}],
+                "usage": {"total_tokens": 2},
+            }),
+        )
+        .await;
+        let provider = provider(api.base_url.clone());
+
+        provider
+            .embed(EmbeddingModel::Voyage3Lite, "document")
+            .await
+            .unwrap();
+        provider
+            .embed_query(EmbeddingModel::Voyage3Lite, "query")
+            .await
+            .unwrap();
+
+        let requests = api.requests();
+        assert!(requests[0].body.get("input_type").is_none());
+        assert_eq!(requests[1].body["input_type"], "query");
+    }
+
+    #[tokio::test]
+    async fn test_api_error_is_mapped() {
+        let api = MockApi::start(
+            "/embeddings",
+            StatusCode::UNAUTHORIZED,
+            json!({"detail": "invalid api key"}),
+        )
+        .await;
+
+        let err = provider(api.base_url.clone())
+            .embed(EmbeddingModel::Voyage3, "hello")
+            .await
+            .unwrap_err();
+
+        match err {
+            VectorError::Embedding(msg) => {
+                assert!(msg.contains("401"), "unexpected message: {msg}");
+                assert!(msg.contains("invalid api key"), "unexpected message: {msg}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_model_skips_request() {
+        let api = MockApi::start("/embeddings", StatusCode::OK, json!({})).await;
+
+        let err = provider(api.base_url.clone())
+            .embed(EmbeddingModel::TextEmbedding3Small, "hello")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VectorError::Validation(_)));
+        assert!(api.requests().is_empty());
+    }
+}
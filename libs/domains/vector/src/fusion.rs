@@ -0,0 +1,124 @@
+//! Result fusion for hybrid (dense + sparse) search
+//!
+//! Dense and sparse scores live on different scales: dense scores depend on
+//! the collection's distance metric (cosine in `[-1, 1]`, unbounded dot
+//! product or negated euclidean/manhattan distances), while sparse scores are
+//! unbounded dot products over term weights. Rather than normalizing the raw
+//! scores, reciprocal rank fusion (RRF) only looks at each result's rank:
+//!
+//! ```text
+//! score(d) = Σ 1 / (k + rank_i(d))      rank_i is 1-based
+//! ```
+//!
+//! Fused scores are therefore comparable across metrics but are not
+//! similarities: they lie in `(0, n / (k + 1)]` for `n` result lists. A larger
+//! `k` flattens the contribution of top ranks.
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::models::SearchResult;
+
+/// Fuse ranked result lists with reciprocal rank fusion
+///
+/// Each list must be ordered best-first. Results appearing in several lists
+/// accumulate their scores; payloads and vectors are taken from the first list
+/// that returned them. Ties keep the order in which results were first seen.
+/// Returns at most `limit` results with their fused score.
+pub fn rrf_fuse(result_lists: Vec<Vec<SearchResult>>, k: u32, limit: usize) -> Vec<SearchResult> {
+    let mut fused: Vec<SearchResult> = Vec::new();
+    let mut positions: HashMap<Uuid, usize> = HashMap::new();
+
+    for results in result_lists {
+        for (rank, result) in results.into_iter().enumerate() {
+            let score = 1.0 / (k as f32 + rank as f32 + 1.0);
+            match positions.get(&result.id) {
+                Some(&index) => {
+                    let existing = &mut fused[index];
+                    existing.score += score;
+                    if existing.payload.is_none() {
+                        existing.payload = result.payload;
+                    }
+                    if existing.vector.is_none() {
+                        existing.vector = result.vector;
+                    }
+                }
+                None => {
+                    positions.insert(result.id, fused.len());
+                    fused.push(SearchResult { score, ..result });
+                }
+            }
+        }
+    }
+
+    fused.sort_by(|a, b| b.score.total_cmp(&a.score));
+    fused.truncate(limit);
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(id: Uuid, score: f32) -> SearchResult {
+        SearchResult {
+            id,
+            score,
+            payload: None,
+            vector: None,
+        }
+    }
+
+    #[test]
+    fn test_rrf_scores_by_rank() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+
+        let fused = rrf_fuse(vec![vec![result(a, 0.9), result(b, 0.8)]], 60, 10);
+
+        assert_eq!(fused[0].id, a);
+        assert_eq!(fused[0].score, 1.0 / 61.0);
+        assert_eq!(fused[1].score, 1.0 / 62.0);
+    }
+
+    #[test]
+    fn test_rrf_accumulates_across_lists() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+
+        // b is second in both lists and beats the two single-list winners
+        let fused = rrf_fuse(
+            vec![
+                vec![result(a, 0.9), result(b, 0.5)],
+                vec![result(c, 40.0), result(b, 10.0)],
+            ],
+            60,
+            10,
+        );
+
+        let ids: Vec<Uuid> = fused.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![b, a, c]);
+        assert_eq!(fused[0].score, 2.0 / 62.0);
+    }
+
+    #[test]
+    fn test_rrf_respects_limit() {
+        let lists = vec![(0..5).map(|_| result(Uuid::new_v4(), 1.0)).collect()];
+        assert_eq!(rrf_fuse(lists, 60, 3).len(), 3);
+    }
+
+    #[test]
+    fn test_rrf_keeps_first_payload() {
+        let a = Uuid::new_v4();
+        let mut sparse = result(a, 1.0);
+        sparse.payload = Some(serde_json::json!({"source": "sparse"}));
+
+        let fused = rrf_fuse(vec![vec![result(a, 1.0)], vec![sparse]], 60, 10);
+        assert_eq!(
+            fused[0].payload,
+            Some(serde_json::json!({"source": "sparse"}))
+        );
+    }
+}
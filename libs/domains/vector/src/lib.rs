@@ -18,7 +18,8 @@
 //! ┌────────▼────────┐     ┌────────▼────────┐
 //! │ QdrantRepository│     │  OpenAIProvider  │
 //! │ (implementation)│     │  VertexAIProvider│
-//! └─────────────────┘     │  (+ more...)     │
+//! └─────────────────┘     │  CohereProvider  │
+//!                         │  VoyageProvider  │
 //!                         └──────────────────┘
 //! ```
 //!
@@ -67,6 +68,7 @@
 pub mod conversions;
 pub mod embedding;
 pub mod error;
+pub mod fusion;
 pub mod handlers;
 pub mod models;
 pub mod qdrant;
@@ -74,13 +76,17 @@ pub mod repository;
 pub mod service;
 
 // Re-export commonly used types
-pub use embedding::{EmbeddingProvider, OpenAIProvider, VertexAIProvider};
+pub use embedding::{
+    CohereConfig, CohereProvider, EmbeddingProvider, OpenAIConfig, OpenAIProvider,
+    VertexAIProvider, VoyageConfig, VoyageProvider,
+};
 pub use error::{VectorError, VectorResult};
 pub use handlers::VectorApiDoc;
 pub use models::{
     CollectionInfo, CollectionStatus, CreateCollection, DistanceMetric, EmbeddingModel,
-    EmbeddingProviderType, EmbeddingResult, HnswConfig, RecommendQuery, SearchFilter, SearchQuery,
-    SearchResult, SparseVector, TenantContext, Vector, VectorConfig,
+    EmbeddingProviderType, EmbeddingResult, FilterRange, FilterValue, HnswConfig, PayloadCondition,
+    RecommendQuery, SearchFilter, SearchQuery, SearchResult, SnapshotInfo, SparseVector,
+    TenantContext, TextDocument, Vector, VectorConfig,
 };
 pub use qdrant::{QdrantConfig, QdrantRepository};
 pub use repository::VectorRepository;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -72,6 +73,9 @@ pub struct VectorConfig {
     pub dimension: u32,
     pub distance: DistanceMetric,
     pub hnsw: Option<HnswConfig>,
+    /// Store a sparse vector alongside the dense one (needed for hybrid search)
+    #[serde(default)]
+    pub sparse: bool,
 }
 
 impl VectorConfig {
@@ -80,6 +84,7 @@ impl VectorConfig {
             dimension,
             distance: DistanceMetric::default(),
             hnsw: None,
+            sparse: false,
         }
     }
 
@@ -92,6 +97,11 @@ impl VectorConfig {
         self.hnsw = Some(hnsw);
         self
     }
+
+    pub fn with_sparse(mut self) -> Self {
+        self.sparse = true;
+        self
+    }
 }
 
 /// Input for creating a collection
@@ -154,6 +164,29 @@ impl Vector {
     }
 }
 
+/// A text document to embed and upsert as a vector point
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TextDocument {
+    pub id: Uuid,
+    pub text: String,
+    pub payload: Option<serde_json::Value>,
+}
+
+impl TextDocument {
+    pub fn new(id: Uuid, text: impl Into<String>) -> Self {
+        Self {
+            id,
+            text: text.into(),
+            payload: None,
+        }
+    }
+
+    pub fn with_payload(mut self, payload: serde_json::Value) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+}
+
 /// Sparse vector for hybrid search
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SparseVector {
@@ -170,8 +203,17 @@ pub struct SearchQuery {
     pub filter: Option<SearchFilter>,
     pub with_vectors: bool,
     pub with_payloads: bool,
+    /// Sparse component; when set, dense and sparse results are fused (RRF)
+    #[serde(default)]
+    pub sparse: Option<SparseVector>,
+    /// RRF rank constant for hybrid search, defaults to [`DEFAULT_RRF_K`]
+    #[serde(default)]
+    pub rrf_k: Option<u32>,
 }
 
+/// Default reciprocal rank fusion constant
+pub const DEFAULT_RRF_K: u32 = 60;
+
 impl SearchQuery {
     pub fn new(vector: Vec<f32>, limit: u32) -> Self {
         Self {
@@ -181,16 +223,182 @@ impl SearchQuery {
             filter: None,
             with_vectors: false,
             with_payloads: true,
+            sparse: None,
+            rrf_k: None,
         }
     }
+
+    pub fn with_sparse(mut self, sparse: SparseVector) -> Self {
+        self.sparse = Some(sparse);
+        self
+    }
+
+    pub fn with_rrf_k(mut self, k: u32) -> Self {
+        self.rrf_k = Some(k);
+        self
+    }
+
+    /// Whether the query runs as a hybrid (dense + sparse) search
+    pub fn is_hybrid(&self) -> bool {
+        self.sparse.is_some()
+    }
 }
 
 /// Search filter conditions
+///
+/// All set fields and every condition in `must` have to match (AND). When
+/// `should` is non-empty, at least one of its conditions has to match as well
+/// (OR group). `must_match` is shorthand for equality conditions on each of its
+/// top-level keys, and `namespace_filter` matches the `namespace` payload field,
+/// which upserts set from the tenant's namespace.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
 pub struct SearchFilter {
     pub must_have_id: Option<Uuid>,
     pub must_match: Option<serde_json::Value>,
     pub namespace_filter: Option<String>,
+    /// Payload conditions that must all match
+    #[serde(default)]
+    pub must: Vec<PayloadCondition>,
+    /// Payload conditions of which at least one must match
+    #[serde(default)]
+    pub should: Vec<PayloadCondition>,
+}
+
+impl SearchFilter {
+    /// Add a condition that must match
+    pub fn and(mut self, condition: PayloadCondition) -> Self {
+        self.must.push(condition);
+        self
+    }
+
+    /// Add a condition to the OR group
+    pub fn or(mut self, condition: PayloadCondition) -> Self {
+        self.should.push(condition);
+        self
+    }
+}
+
+/// Predicate on a payload field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PayloadCondition {
+    /// Field equals the value
+    Eq { key: String, value: FilterValue },
+    /// Numeric field lies within the range
+    Range { key: String, range: FilterRange },
+    /// Field equals any of the values
+    In {
+        key: String,
+        values: Vec<FilterValue>,
+    },
+    /// Field equals none of the values
+    NotIn {
+        key: String,
+        values: Vec<FilterValue>,
+    },
+}
+
+impl PayloadCondition {
+    pub fn eq(key: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self::Eq {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn range(key: impl Into<String>, range: FilterRange) -> Self {
+        Self::Range {
+            key: key.into(),
+            range,
+        }
+    }
+
+    pub fn is_in<V: Into<FilterValue>>(
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        Self::In {
+            key: key.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn not_in<V: Into<FilterValue>>(
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        Self::NotIn {
+            key: key.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Value matched by payload conditions
+///
+/// Qdrant matches keywords, integers and booleans exactly; use a
+/// [`FilterRange`] for floating point fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum FilterValue {
+    Bool(bool),
+    Integer(i64),
+    Keyword(String),
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        Self::Keyword(value)
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        Self::Keyword(value.to_string())
+    }
+}
+
+/// Numeric range; unset bounds are open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FilterRange {
+    pub gt: Option<f64>,
+    pub gte: Option<f64>,
+    pub lt: Option<f64>,
+    pub lte: Option<f64>,
+}
+
+impl FilterRange {
+    pub fn gt(mut self, value: f64) -> Self {
+        self.gt = Some(value);
+        self
+    }
+
+    pub fn gte(mut self, value: f64) -> Self {
+        self.gte = Some(value);
+        self
+    }
+
+    pub fn lt(mut self, value: f64) -> Self {
+        self.lt = Some(value);
+        self
+    }
+
+    pub fn lte(mut self, value: f64) -> Self {
+        self.lte = Some(value);
+        self
+    }
 }
 
 /// Search result
@@ -351,6 +559,17 @@ pub struct EmbeddingResult {
     pub tokens_used: u32,
 }
 
+/// Collection snapshot metadata
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SnapshotInfo {
+    pub name: String,
+    /// Tenant-prefixed name of the snapshotted collection
+    pub collection_name: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub size_bytes: u64,
+    pub checksum: Option<String>,
+}
+
 /// Recommendation request
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RecommendQuery {
@@ -3,9 +3,10 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use qdrant_client::qdrant::{
-    self, CreateCollectionBuilder, DeletePointsBuilder, Distance, GetPointsBuilder, PointId,
-    PointStruct, RecommendPointsBuilder, SearchPointsBuilder, UpsertPointsBuilder,
-    Value as QdrantValue, VectorParamsBuilder,
+    self, CreateCollectionBuilder, DeletePointsBuilder, DeleteSnapshotRequestBuilder, Distance,
+    Filter, GetPointsBuilder, NamedVectors, PointId, PointStruct, RecommendPointsBuilder,
+    SearchPointsBuilder, SparseVectorParamsBuilder, SparseVectorsConfigBuilder,
+    UpsertPointsBuilder, Value as QdrantValue, VectorParamsBuilder,
 };
 use qdrant_client::Qdrant;
 use uuid::Uuid;
@@ -14,19 +15,41 @@ use super::QdrantConfig;
 use crate::error::{VectorError, VectorResult};
 use crate::models::{
     CollectionInfo, CollectionStatus, CreateCollection, DistanceMetric, RecommendQuery,
-    SearchQuery, SearchResult, TenantContext, Vector, VectorConfig,
+    SearchQuery, SearchResult, SnapshotInfo, TenantContext, Vector, VectorConfig,
 };
 use crate::repository::VectorRepository;
 
+/// Name of the sparse vector in collections created with sparse support
+///
+/// The dense vector stays unnamed, so dense-only collections are unaffected.
+const SPARSE_VECTOR_NAME: &str = "sparse";
+
 /// Qdrant-backed implementation of VectorRepository
 pub struct QdrantRepository {
     client: Qdrant,
+    /// REST access for operations the gRPC API lacks
+    rest: Option<RestEndpoint>,
+}
+
+struct RestEndpoint {
+    http: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+    /// Snapshots directory as seen by the Qdrant server
+    snapshots_path: String,
 }
 
 impl QdrantRepository {
     pub async fn new(config: QdrantConfig) -> VectorResult<Self> {
         let mut builder = Qdrant::from_url(&config.url);
 
+        let rest = config.resolved_rest_url().map(|url| RestEndpoint {
+            http: reqwest::Client::new(),
+            url: url.trim_end_matches('/').to_string(),
+            api_key: config.api_key.clone(),
+            snapshots_path: config.snapshots_path.trim_end_matches('/').to_string(),
+        });
+
         if let Some(api_key) = config.api_key {
             builder = builder.api_key(api_key);
         }
@@ -37,11 +60,12 @@ impl QdrantRepository {
             .build()
             .map_err(|e| VectorError::Qdrant(format!("Failed to build client: {}", e)))?;
 
-        Ok(Self { client })
+        Ok(Self { client, rest })
     }
 
+    /// Wrap an existing client; snapshot restore is unavailable without a REST URL
     pub fn from_client(client: Qdrant) -> Self {
-        Self { client }
+        Self { client, rest: None }
     }
 
     fn to_qdrant_distance(metric: DistanceMetric) -> Distance {
@@ -79,6 +103,34 @@ impl QdrantRepository {
         }
     }
 
+    /// Convert a vector into a point, tagging it with the tenant's namespace
+    ///
+    /// The `namespace` payload field is what `SearchFilter::namespace_filter`
+    /// matches on, so it always reflects the tenant rather than the caller's payload.
+    fn to_point(tenant: &TenantContext, vector: Vector) -> PointStruct {
+        let id = Self::uuid_to_point_id(vector.id);
+        let mut payload = Self::payload_to_qdrant(vector.payload);
+        if let Some(namespace) = &tenant.namespace {
+            payload.insert(
+                "namespace".to_string(),
+                QdrantValue::from(namespace.clone()),
+            );
+        }
+
+        match vector.sparse {
+            Some(sparse) => {
+                let vectors = NamedVectors::default()
+                    .add_vector("", vector.values)
+                    .add_vector(
+                        SPARSE_VECTOR_NAME,
+                        qdrant::Vector::new_sparse(sparse.indices, sparse.values),
+                    );
+                PointStruct::new(id, vectors, payload)
+            }
+            None => PointStruct::new(id, vector.values, payload),
+        }
+    }
+
     fn payload_to_qdrant(payload: Option<serde_json::Value>) -> HashMap<String, QdrantValue> {
         let Some(value) = payload else {
             return HashMap::new();
@@ -176,6 +228,13 @@ impl VectorRepository for QdrantRepository {
             builder = builder.hnsw_config(hnsw_config);
         }
 
+        if input.config.sparse {
+            let mut sparse_config = SparseVectorsConfigBuilder::default();
+            sparse_config
+                .add_named_vector_params(SPARSE_VECTOR_NAME, SparseVectorParamsBuilder::default());
+            builder = builder.sparse_vectors_config(sparse_config);
+        }
+
         self.client.create_collection(builder).await?;
 
         Ok(CollectionInfo {
@@ -232,11 +291,8 @@ impl VectorRepository for QdrantRepository {
     ) -> VectorResult<Uuid> {
         let full_name = tenant.collection_name(collection_name);
 
-        let point = PointStruct::new(
-            Self::uuid_to_point_id(vector.id),
-            vector.values,
-            Self::payload_to_qdrant(vector.payload),
-        );
+        let id = vector.id;
+        let point = Self::to_point(tenant, vector);
 
         let mut builder = UpsertPointsBuilder::new(&full_name, vec![point]);
         if wait {
@@ -245,7 +301,7 @@ impl VectorRepository for QdrantRepository {
 
         self.client.upsert_points(builder).await?;
 
-        Ok(vector.id)
+        Ok(id)
     }
 
     async fn upsert_batch(
@@ -261,13 +317,7 @@ impl VectorRepository for QdrantRepository {
 
         let points: Vec<PointStruct> = vectors
             .into_iter()
-            .map(|v| {
-                PointStruct::new(
-                    Self::uuid_to_point_id(v.id),
-                    v.values,
-                    Self::payload_to_qdrant(v.payload),
-                )
-            })
+            .map(|vector| Self::to_point(tenant, vector))
             .collect();
 
         let mut builder = UpsertPointsBuilder::new(&full_name, points);
@@ -294,6 +344,10 @@ impl VectorRepository for QdrantRepository {
             builder = builder.score_threshold(threshold);
         }
 
+        if let Some(filter) = &query.filter {
+            builder = builder.filter(Filter::try_from(filter)?);
+        }
+
         builder = builder.with_vectors(query.with_vectors);
         builder = builder.with_payload(query.with_payloads);
 
@@ -302,23 +356,43 @@ impl VectorRepository for QdrantRepository {
         results
             .result
             .into_iter()
-            .map(|point| {
-                let id = point
-                    .id
-                    .as_ref()
-                    .map(Self::point_id_to_uuid)
-                    .transpose()?
-                    .ok_or_else(|| VectorError::Internal("Missing point ID".to_string()))?;
+            .map(Self::scored_point_to_result)
+            .collect()
+    }
 
-                let vector = Self::extract_vector_from_output(&point.vectors);
+    async fn search_sparse(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+        query: SearchQuery,
+    ) -> VectorResult<Vec<SearchResult>> {
+        let full_name = tenant.collection_name(collection_name);
 
-                Ok(SearchResult {
-                    id,
-                    score: point.score,
-                    payload: Self::qdrant_to_payload(point.payload),
-                    vector,
-                })
-            })
+        let sparse = query.sparse.ok_or_else(|| {
+            VectorError::Validation("Sparse search requires a sparse vector".to_string())
+        })?;
+
+        let mut builder = SearchPointsBuilder::new(&full_name, sparse.values, query.limit as u64)
+            .vector_name(SPARSE_VECTOR_NAME)
+            .sparse_indices(sparse.indices);
+
+        if let Some(threshold) = query.score_threshold {
+            builder = builder.score_threshold(threshold);
+        }
+
+        if let Some(filter) = &query.filter {
+            builder = builder.filter(Filter::try_from(filter)?);
+        }
+
+        builder = builder.with_vectors(query.with_vectors);
+        builder = builder.with_payload(query.with_payloads);
+
+        let results = self.client.search_points(builder).await?;
+
+        results
+            .result
+            .into_iter()
+            .map(Self::scored_point_to_result)
             .collect()
     }
 
@@ -426,6 +500,10 @@ impl VectorRepository for QdrantRepository {
             builder = builder.score_threshold(threshold);
         }
 
+        if let Some(filter) = &query.filter {
+            builder = builder.filter(Filter::try_from(filter)?);
+        }
+
         builder = builder.with_vectors(query.with_vectors);
         builder = builder.with_payload(query.with_payloads);
 
@@ -434,28 +512,175 @@ impl VectorRepository for QdrantRepository {
         results
             .result
             .into_iter()
-            .map(|point| {
-                let id = point
-                    .id
-                    .as_ref()
-                    .map(Self::point_id_to_uuid)
-                    .transpose()?
-                    .ok_or_else(|| VectorError::Internal("Missing point ID".to_string()))?;
+            .map(Self::scored_point_to_result)
+            .collect()
+    }
+}
 
-                let vector = Self::extract_vector_from_output(&point.vectors);
+// ===== Snapshots =====
 
-                Ok(SearchResult {
-                    id,
-                    score: point.score,
-                    payload: Self::qdrant_to_payload(point.payload),
-                    vector,
-                })
-            })
-            .collect()
+impl QdrantRepository {
+    /// Snapshot a tenant's collection
+    pub async fn create_snapshot(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+    ) -> VectorResult<SnapshotInfo> {
+        let full_name = tenant.collection_name(collection_name);
+
+        let response = self.client.create_snapshot(full_name.as_str()).await?;
+
+        let description = response.snapshot_description.ok_or_else(|| {
+            VectorError::Internal("Snapshot response missing description".to_string())
+        })?;
+
+        Ok(Self::snapshot_info(&full_name, description))
+    }
+
+    /// List snapshots of a tenant's collection
+    pub async fn list_snapshots(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+    ) -> VectorResult<Vec<SnapshotInfo>> {
+        let full_name = tenant.collection_name(collection_name);
+
+        let response = self.client.list_snapshots(full_name.as_str()).await?;
+
+        Ok(response
+            .snapshot_descriptions
+            .into_iter()
+            .map(|description| Self::snapshot_info(&full_name, description))
+            .collect())
+    }
+
+    /// Delete a snapshot of a tenant's collection
+    pub async fn delete_snapshot(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+        snapshot_name: &str,
+    ) -> VectorResult<()> {
+        let full_name = tenant.collection_name(collection_name);
+        Self::validate_snapshot_name(snapshot_name)?;
+
+        self.client
+            .delete_snapshot(DeleteSnapshotRequestBuilder::new(&full_name, snapshot_name))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Restore a tenant's collection from one of its snapshots
+    ///
+    /// Uses the REST API, since recovery is not exposed over gRPC. The server
+    /// reads the snapshot straight from its snapshots directory via a
+    /// `file://` location, so `snapshots_path` must match the server's
+    /// `storage.snapshots_path`. Snapshot data takes priority over any points
+    /// currently in the collection.
+    pub async fn restore_snapshot(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+        snapshot_name: &str,
+    ) -> VectorResult<()> {
+        let full_name = tenant.collection_name(collection_name);
+        Self::validate_snapshot_name(snapshot_name)?;
+
+        let rest = self.rest.as_ref().ok_or_else(|| {
+            VectorError::Config(
+                "Qdrant REST URL not configured; set QDRANT_REST_URL to restore snapshots"
+                    .to_string(),
+            )
+        })?;
+
+        let location = format!(
+            "file://{}/{}/{}",
+            rest.snapshots_path, full_name, snapshot_name
+        );
+
+        let mut request = rest
+            .http
+            .put(format!(
+                "{}/collections/{}/snapshots/recover?wait=true",
+                rest.url, full_name
+            ))
+            .json(&serde_json::json!({
+                "location": location,
+                "priority": "snapshot",
+            }));
+
+        if let Some(api_key) = &rest.api_key {
+            request = request.header("api-key", api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| VectorError::Qdrant(format!("Snapshot restore request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(VectorError::Qdrant(format!(
+                "Snapshot restore failed ({}): {}",
+                status, error_text
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Reject names that would escape the collection's snapshot path
+    fn validate_snapshot_name(snapshot_name: &str) -> VectorResult<()> {
+        if snapshot_name.is_empty()
+            || snapshot_name.contains('/')
+            || snapshot_name.contains('\\')
+            || snapshot_name.contains("..")
+        {
+            return Err(VectorError::Validation(format!(
+                "Invalid snapshot name: {}",
+                snapshot_name
+            )));
+        }
+        Ok(())
+    }
+
+    fn snapshot_info(
+        collection_name: &str,
+        description: qdrant::SnapshotDescription,
+    ) -> SnapshotInfo {
+        SnapshotInfo {
+            name: description.name,
+            collection_name: collection_name.to_string(),
+            created_at: description
+                .creation_time
+                .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
+            size_bytes: description.size.max(0) as u64,
+            checksum: description.checksum,
+        }
     }
 }
 
 impl QdrantRepository {
+    fn scored_point_to_result(point: qdrant::ScoredPoint) -> VectorResult<SearchResult> {
+        let id = point
+            .id
+            .as_ref()
+            .map(Self::point_id_to_uuid)
+            .transpose()?
+            .ok_or_else(|| VectorError::Internal("Missing point ID".to_string()))?;
+
+        let vector = Self::extract_vector_from_output(&point.vectors);
+
+        Ok(SearchResult {
+            id,
+            score: point.score,
+            payload: Self::qdrant_to_payload(point.payload),
+            vector,
+        })
+    }
+
     /// Extract vector values from VectorsOutput
     /// Note: Uses deprecated data field for now until migration to 1.18+
     #[allow(deprecated)]
@@ -467,8 +692,11 @@ impl QdrantRepository {
                 match opts {
                     qdrant::vectors_output::VectorsOptions::Vector(v) => Some(v.data.clone()),
                     qdrant::vectors_output::VectorsOptions::Vectors(map) => {
-                        // For multi-vector, return the first one
-                        map.vectors.values().next().map(|v| v.data.clone())
+                        // Prefer the unnamed dense vector, else return the first one
+                        map.vectors
+                            .get("")
+                            .or_else(|| map.vectors.values().next())
+                            .map(|v| v.data.clone())
                     }
                 }
             }
@@ -510,6 +738,7 @@ impl QdrantRepository {
                 dimension,
                 distance,
                 hnsw: None,
+                sparse: Self::has_sparse_vectors(&result.config),
             },
             status,
         }))
@@ -550,6 +779,14 @@ impl QdrantRepository {
         }
     }
 
+    fn has_sparse_vectors(config: &Option<qdrant::CollectionConfig>) -> bool {
+        config
+            .as_ref()
+            .and_then(|c| c.params.as_ref())
+            .and_then(|p| p.sparse_vectors_config.as_ref())
+            .is_some_and(|s| s.map.contains_key(SPARSE_VECTOR_NAME))
+    }
+
     fn extract_counts(result: &qdrant::CollectionInfo) -> (u64, u64) {
         // Try to get counts from segments_count as a proxy
         let segments = result.segments_count;
@@ -6,14 +6,25 @@ pub struct QdrantConfig {
     pub url: String,
     pub api_key: Option<String>,
     pub timeout_secs: u64,
+    /// REST endpoint, needed for operations missing from the gRPC API
+    /// (snapshot restore). Derived from `url` when it uses the default gRPC port.
+    pub rest_url: Option<String>,
+    /// Server-side snapshots directory (`storage.snapshots_path`), used to
+    /// locate snapshots on restore
+    pub snapshots_path: String,
 }
 
+/// Snapshots directory of the official Qdrant image
+const DEFAULT_SNAPSHOTS_PATH: &str = "/qdrant/snapshots";
+
 impl QdrantConfig {
     pub fn new(url: String) -> Self {
         Self {
             url,
             api_key: None,
             timeout_secs: 30,
+            rest_url: None,
+            snapshots_path: DEFAULT_SNAPSHOTS_PATH.to_string(),
         }
     }
 
@@ -27,6 +38,27 @@ impl QdrantConfig {
         self
     }
 
+    pub fn with_rest_url(mut self, rest_url: String) -> Self {
+        self.rest_url = Some(rest_url);
+        self
+    }
+
+    pub fn with_snapshots_path(mut self, snapshots_path: String) -> Self {
+        self.snapshots_path = snapshots_path;
+        self
+    }
+
+    /// REST endpoint: the configured one, or `url` with the gRPC port 6334
+    /// swapped for the REST port 6333
+    pub fn resolved_rest_url(&self) -> Option<String> {
+        self.rest_url.clone().or_else(|| {
+            self.url
+                .trim_end_matches('/')
+                .strip_suffix(":6334")
+                .map(|base| format!("{}:6333", base))
+        })
+    }
+
     pub fn from_env() -> VectorResult<Self> {
         let url =
             std::env::var("QDRANT_URL").unwrap_or_else(|_| "http://localhost:6334".to_string());
@@ -38,10 +70,17 @@ impl QdrantConfig {
             .and_then(|s| s.parse().ok())
             .unwrap_or(30);
 
+        let rest_url = std::env::var("QDRANT_REST_URL").ok();
+
+        let snapshots_path = std::env::var("QDRANT_SNAPSHOTS_PATH")
+            .unwrap_or_else(|_| DEFAULT_SNAPSHOTS_PATH.to_string());
+
         Ok(Self {
             url,
             api_key,
             timeout_secs,
+            rest_url,
+            snapshots_path,
         })
     }
 }
@@ -52,6 +91,38 @@ impl Default for QdrantConfig {
             url: "http://localhost:6334".to_string(),
             api_key: None,
             timeout_secs: 30,
+            rest_url: None,
+            snapshots_path: DEFAULT_SNAPSHOTS_PATH.to_string(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rest_url_derived_from_grpc_port() {
+        let config = QdrantConfig::new("http://qdrant:6334".to_string());
+        assert_eq!(
+            config.resolved_rest_url().as_deref(),
+            Some("http://qdrant:6333")
+        );
+    }
+
+    #[test]
+    fn test_explicit_rest_url_wins() {
+        let config = QdrantConfig::new("http://qdrant:6334".to_string())
+            .with_rest_url("https://qdrant.example.com".to_string());
+        assert_eq!(
+            config.resolved_rest_url().as_deref(),
+            Some("https://qdrant.example.com")
+        );
+    }
+
+    #[test]
+    fn test_no_rest_url_for_custom_port() {
+        let config = QdrantConfig::new("https://qdrant.example.com".to_string());
+        assert_eq!(config.resolved_rest_url(), None);
+    }
+}
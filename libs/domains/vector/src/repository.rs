@@ -68,6 +68,16 @@ pub trait VectorRepository: Send + Sync {
         query: SearchQuery,
     ) -> VectorResult<Vec<SearchResult>>;
 
+    /// Search the sparse vectors using `query.sparse`
+    ///
+    /// Only valid for collections created with sparse vectors enabled.
+    async fn search_sparse(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+        query: SearchQuery,
+    ) -> VectorResult<Vec<SearchResult>>;
+
     /// Get vectors by IDs
     async fn get(
         &self,
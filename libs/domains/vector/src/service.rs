@@ -4,9 +4,10 @@ use uuid::Uuid;
 
 use crate::embedding::EmbeddingProvider;
 use crate::error::{VectorError, VectorResult};
+use crate::fusion::rrf_fuse;
 use crate::models::{
     CollectionInfo, CreateCollection, EmbeddingModel, EmbeddingProviderType, EmbeddingResult,
-    RecommendQuery, SearchQuery, SearchResult, TenantContext, Vector,
+    RecommendQuery, SearchQuery, SearchResult, TenantContext, TextDocument, Vector, DEFAULT_RRF_K,
 };
 use crate::repository::VectorRepository;
 
@@ -94,13 +95,42 @@ impl<R: VectorRepository> VectorService<R> {
             .await
     }
 
+    /// Search for similar vectors
+    ///
+    /// Queries carrying a sparse vector run as a hybrid search: the dense and
+    /// sparse legs are searched separately and fused with reciprocal rank
+    /// fusion, so returned scores are RRF scores rather than similarities (see
+    /// [`fusion`](crate::fusion)). `score_threshold` only applies to the dense
+    /// leg, since sparse scores are unbounded.
     pub async fn search(
         &self,
         tenant: &TenantContext,
         collection_name: &str,
         query: SearchQuery,
     ) -> VectorResult<Vec<SearchResult>> {
-        self.repository.search(tenant, collection_name, query).await
+        if !query.is_hybrid() {
+            return self.repository.search(tenant, collection_name, query).await;
+        }
+
+        let limit = query.limit as usize;
+        let k = query.rrf_k.unwrap_or(DEFAULT_RRF_K);
+
+        let dense_query = SearchQuery {
+            sparse: None,
+            ..query.clone()
+        };
+        let sparse_query = SearchQuery {
+            score_threshold: None,
+            ..query
+        };
+
+        let (dense, sparse) = tokio::try_join!(
+            self.repository.search(tenant, collection_name, dense_query),
+            self.repository
+                .search_sparse(tenant, collection_name, sparse_query),
+        )?;
+
+        Ok(rrf_fuse(vec![dense, sparse], k, limit))
     }
 
     pub async fn get(
@@ -193,6 +223,57 @@ impl<R: VectorRepository> VectorService<R> {
             .await
     }
 
+    /// Upsert many documents, embedding them with a single batch call
+    ///
+    /// The provider splits the batch into API-sized requests where needed.
+    pub async fn upsert_texts_batch(
+        &self,
+        tenant: &TenantContext,
+        collection_name: &str,
+        documents: Vec<TextDocument>,
+        _provider_type: EmbeddingProviderType,
+        model: EmbeddingModel,
+        wait: bool,
+    ) -> VectorResult<Vec<Uuid>> {
+        let provider = self
+            .embedding_provider
+            .as_ref()
+            .ok_or_else(|| VectorError::Config("No embedding provider configured".to_string()))?;
+
+        if documents.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Generate embeddings
+        let texts: Vec<String> = documents.iter().map(|d| d.text.clone()).collect();
+        let embeddings = provider.embed_batch(model, &texts).await?;
+        if embeddings.len() != documents.len() {
+            return Err(VectorError::Embedding(format!(
+                "Expected {} embeddings, got {}",
+                documents.len(),
+                embeddings.len()
+            )));
+        }
+
+        // Create vectors with embeddings
+        let vectors = documents
+            .into_iter()
+            .zip(embeddings)
+            .map(|(document, embedding)| {
+                let vector = Vector::new(document.id, embedding.values);
+                match document.payload {
+                    Some(p) => vector.with_payload(p),
+                    None => vector,
+                }
+            })
+            .collect();
+
+        // Upsert to repository
+        self.repository
+            .upsert_batch(tenant, collection_name, vectors, wait)
+            .await
+    }
+
     /// Search with automatic query embedding generation
     #[allow(clippy::too_many_arguments)]
     pub async fn search_with_embedding(
@@ -213,7 +294,7 @@ impl<R: VectorRepository> VectorService<R> {
             .ok_or_else(|| VectorError::Config("No embedding provider configured".to_string()))?;
 
         // Generate query embedding
-        let embedding = provider.embed(model, text).await?;
+        let embedding = provider.embed_query(model, text).await?;
 
         // Create search query
         let mut query = SearchQuery::new(embedding.values, limit);
@@ -238,3 +319,160 @@ impl<R: VectorRepository> VectorService<R> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedding::MockEmbeddingProvider;
+    use crate::models::SparseVector;
+    use crate::repository::MockVectorRepository;
+
+    fn ranked(ids: &[Uuid]) -> Vec<SearchResult> {
+        ids.iter()
+            .enumerate()
+            .map(|(rank, id)| SearchResult {
+                id: *id,
+                score: 1.0 - rank as f32 * 0.1,
+                payload: None,
+                vector: None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_dense_search_skips_fusion() {
+        let a = Uuid::new_v4();
+        let mut repository = MockVectorRepository::new();
+        repository
+            .expect_search()
+            .times(1)
+            .returning(move |_, _, _| Ok(ranked(&[a])));
+        repository.expect_search_sparse().never();
+
+        let service = VectorService::new(repository);
+        let results = service
+            .search(
+                &TenantContext::new(Uuid::new_v4()),
+                "docs",
+                SearchQuery::new(vec![0.1], 10),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_hybrid_search_fuses_rankings() {
+        let (a, b, c, d) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+
+        let mut repository = MockVectorRepository::new();
+        repository
+            .expect_search()
+            .times(1)
+            .withf(|_, _, query| query.sparse.is_none() && query.score_threshold == Some(0.5))
+            .returning(move |_, _, _| Ok(ranked(&[a, b, c])));
+        repository
+            .expect_search_sparse()
+            .times(1)
+            .withf(|_, _, query| query.sparse.is_some() && query.score_threshold.is_none())
+            .returning(move |_, _, _| Ok(ranked(&[c, d, b])));
+
+        let mut query = SearchQuery::new(vec![0.1], 3)
+            .with_sparse(SparseVector {
+                indices: vec![1, 7],
+                values: vec![0.5, 0.5],
+            })
+            .with_rrf_k(1);
+        query.score_threshold = Some(0.5);
+
+        let service = VectorService::new(repository);
+        let results = service
+            .search(&TenantContext::new(Uuid::new_v4()), "docs", query)
+            .await
+            .unwrap();
+
+        // k = 1: c = 1/4 + 1/2, b = 1/3 + 1/4, a = 1/2, d = 1/3
+        let ids: Vec<Uuid> = results.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![c, b, a]);
+        assert_eq!(results[0].score, 0.75);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_texts_batch_embeds_once() {
+        let mut provider = MockEmbeddingProvider::new();
+        provider
+            .expect_embed_batch()
+            .times(1)
+            .withf(|_, texts| texts.len() == 2 && texts[0] == "first" && texts[1] == "second")
+            .returning(|_, texts| {
+                Ok(texts
+                    .iter()
+                    .map(|text| EmbeddingResult {
+                        values: vec![text.len() as f32],
+                        dimension: 1,
+                        tokens_used: 1,
+                    })
+                    .collect())
+            });
+
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let mut repository = MockVectorRepository::new();
+        repository
+            .expect_upsert_batch()
+            .times(1)
+            .withf(move |_, collection, vectors, _| {
+                collection == "docs"
+                    && vectors.len() == 2
+                    && vectors[0].id == first
+                    && vectors[0].values == vec![5.0]
+                    && vectors[0].payload.is_some()
+                    && vectors[1].id == second
+                    && vectors[1].payload.is_none()
+            })
+            .returning(|_, _, vectors, _| Ok(vectors.iter().map(|v| v.id).collect()));
+
+        let service = VectorService::new(repository).with_embedding_provider(Arc::new(provider));
+        let documents = vec![
+            TextDocument::new(first, "first").with_payload(serde_json::json!({"n": 1})),
+            TextDocument::new(second, "second"),
+        ];
+        let ids = service
+            .upsert_texts_batch(
+                &TenantContext::new(Uuid::new_v4()),
+                "docs",
+                documents,
+                EmbeddingProviderType::OpenAI,
+                EmbeddingModel::Custom(1),
+                true,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec![first, second]);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_texts_batch_requires_provider() {
+        let service = VectorService::new(MockVectorRepository::new());
+        let result = service
+            .upsert_texts_batch(
+                &TenantContext::new(Uuid::new_v4()),
+                "docs",
+                vec![TextDocument::new(Uuid::new_v4(), "text")],
+                EmbeddingProviderType::OpenAI,
+                EmbeddingModel::Custom(1),
+                true,
+            )
+            .await;
+
+        assert!(matches!(result, Err(VectorError::Config(_))));
+    }
+}
@@ -0,0 +1,68 @@
+//! Search filters against a running Qdrant
+//!
+//! Point `QDRANT_URL` at a Qdrant instance and run with
+//! `cargo test -p domain_vector -- --ignored`.
+
+use domain_vector::{
+    CreateCollection, QdrantConfig, QdrantRepository, SearchFilter, SearchQuery, TenantContext,
+    Vector, VectorConfig, VectorRepository,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+#[ignore] // Requires a running Qdrant
+async fn test_namespace_filter_matches_upserted_points() {
+    let repository = QdrantRepository::new(QdrantConfig::from_env().unwrap())
+        .await
+        .unwrap();
+    let tenant = TenantContext::new(Uuid::new_v4()).with_namespace("docs".to_string());
+    let collection = "filters";
+
+    repository
+        .create_collection(
+            &tenant,
+            CreateCollection {
+                name: collection.to_string(),
+                config: VectorConfig::new(4),
+            },
+        )
+        .await
+        .unwrap();
+    repository
+        .upsert_batch(
+            &tenant,
+            collection,
+            vec![
+                Vector::new(Uuid::new_v4(), vec![0.1, 0.2, 0.3, 0.4]),
+                // A caller-supplied namespace doesn't override the tenant's
+                Vector::new(Uuid::new_v4(), vec![0.4, 0.3, 0.2, 0.1])
+                    .with_payload(json!({ "namespace": "other" })),
+            ],
+            true,
+        )
+        .await
+        .unwrap();
+
+    let search = |namespace: &str| {
+        let mut query = SearchQuery::new(vec![0.1, 0.2, 0.3, 0.4], 10);
+        query.filter = Some(SearchFilter {
+            namespace_filter: Some(namespace.to_string()),
+            ..Default::default()
+        });
+        repository.search(&tenant, collection, query)
+    };
+
+    let results = search("docs").await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .all(|r| r.payload.as_ref().unwrap()["namespace"] == "docs"));
+
+    assert!(search("other").await.unwrap().is_empty());
+
+    repository
+        .delete_collection(&tenant, collection)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,81 @@
+//! Snapshot round trip against a running Qdrant
+//!
+//! Point `QDRANT_URL` (and `QDRANT_REST_URL` when not using the default ports,
+//! `QDRANT_SNAPSHOTS_PATH` when not using the official image) at a Qdrant
+//! instance and run with `cargo test -p domain_vector -- --ignored`.
+
+use domain_vector::{
+    CreateCollection, QdrantConfig, QdrantRepository, TenantContext, Vector, VectorConfig,
+    VectorRepository,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+#[ignore] // Requires a running Qdrant
+async fn test_snapshot_create_list_delete() {
+    let repository = QdrantRepository::new(QdrantConfig::from_env().unwrap())
+        .await
+        .unwrap();
+    let tenant = TenantContext::new(Uuid::new_v4());
+    let collection = "snapshots";
+
+    repository
+        .create_collection(
+            &tenant,
+            CreateCollection {
+                name: collection.to_string(),
+                config: VectorConfig::new(4),
+            },
+        )
+        .await
+        .unwrap();
+    repository
+        .upsert(
+            &tenant,
+            collection,
+            Vector::new(Uuid::new_v4(), vec![0.1, 0.2, 0.3, 0.4]),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = repository
+        .create_snapshot(&tenant, collection)
+        .await
+        .unwrap();
+    assert_eq!(snapshot.collection_name, tenant.collection_name(collection));
+    assert!(snapshot.size_bytes > 0);
+
+    let snapshots = repository
+        .list_snapshots(&tenant, collection)
+        .await
+        .unwrap();
+    assert!(snapshots.iter().any(|s| s.name == snapshot.name));
+
+    // Snapshots are scoped to the tenant's collection
+    let other_tenant = TenantContext::new(Uuid::new_v4());
+    assert!(repository
+        .list_snapshots(&other_tenant, collection)
+        .await
+        .is_err());
+
+    repository
+        .restore_snapshot(&tenant, collection, &snapshot.name)
+        .await
+        .unwrap();
+
+    repository
+        .delete_snapshot(&tenant, collection, &snapshot.name)
+        .await
+        .unwrap();
+    let snapshots = repository
+        .list_snapshots(&tenant, collection)
+        .await
+        .unwrap();
+    assert!(snapshots.iter().all(|s| s.name != snapshot.name));
+
+    repository
+        .delete_collection(&tenant, collection)
+        .await
+        .unwrap();
+}
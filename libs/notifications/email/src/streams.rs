@@ -24,8 +24,9 @@ impl NatsStreamConfig for EmailNatsStream {
     /// Subject pattern for email jobs
     const SUBJECT: &'static str = "emails.>";
 
-    /// Max delivery attempts before DLQ
-    const MAX_DELIVER: i64 = 5;
+    /// Max delivery attempts; one more than the 5 rate-limited retries so
+    /// the last attempt can still move the job to the DLQ
+    const MAX_DELIVER: i64 = 6;
 
     /// Ack wait timeout (30 seconds)
     const ACK_WAIT_SECS: u64 = 30;
@@ -338,7 +338,7 @@ mod stream_config_tests {
         assert_eq!(EmailNatsStream::CONSUMER_NAME, "email-worker");
         assert_eq!(EmailNatsStream::DLQ_STREAM, "EMAILS_DLQ");
         assert_eq!(EmailNatsStream::SUBJECT, "emails.>");
-        assert_eq!(EmailNatsStream::MAX_DELIVER, 5);
+        assert_eq!(EmailNatsStream::MAX_DELIVER, 6);
         assert_eq!(EmailNatsStream::ACK_WAIT_SECS, 30);
     }
 }
@@ -224,6 +224,31 @@ pub mod tasks_service_client {
                 .insert(GrpcMethod::new("tasks.TasksService", "ListStream"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// All-or-nothing: one invalid item rejects the whole batch
+        pub async fn batch_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchCreateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks.TasksService/BatchCreate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks.TasksService", "BatchCreate"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -275,6 +300,14 @@ pub mod tasks_service_server {
             &self,
             request: tonic::Request<super::ListStreamRequest>,
         ) -> std::result::Result<tonic::Response<Self::ListStreamStream>, tonic::Status>;
+        /// All-or-nothing: one invalid item rejects the whole batch
+        async fn batch_create(
+            &self,
+            request: tonic::Request<super::BatchCreateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchCreateResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct TasksServiceServer<T> {
@@ -621,6 +654,51 @@ pub mod tasks_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/tasks.TasksService/BatchCreate" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchCreateSvc<T: TasksService>(pub Arc<T>);
+                    impl<
+                        T: TasksService,
+                    > tonic::server::UnaryService<super::BatchCreateRequest>
+                    for BatchCreateSvc<T> {
+                        type Response = super::BatchCreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchCreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TasksService>::batch_create(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BatchCreateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -181,6 +181,17 @@ pub struct ListStreamResponse {
     #[prost(int64, tag="10")]
     pub updated_at: i64,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateRequest {
+    #[prost(message, repeated, tag="1")]
+    pub tasks: ::prost::alloc::vec::Vec<CreateRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateResponse {
+    /// Same order as the request
+    #[prost(message, repeated, tag="1")]
+    pub data: ::prost::alloc::vec::Vec<CreateResponse>,
+}
 /// Enums use 1 byte instead of 4-6 bytes for strings
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -0,0 +1,4 @@
+-- GIN index for tag filtering on projects
+-- Supports containment queries like tags @> '[{"key": "env", "value": "prod"}]'
+
+CREATE INDEX idx_projects_tags ON projects USING GIN (tags jsonb_path_ops);
@@ -0,0 +1,9 @@
+-- Drift findings for cloud resources compared against their desired (IaC) state
+-- Holds the latest finding per resource; rows are removed once a resource is back in sync
+
+CREATE TABLE cloud_resource_drifts (
+  resource_id UUID PRIMARY KEY,
+  changes JSONB NOT NULL DEFAULT '[]',
+  detected_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  CONSTRAINT fk_cloud_resource_drifts_resource FOREIGN KEY (resource_id) REFERENCES cloud_resources(id) ON DELETE CASCADE
+);
@@ -0,0 +1,14 @@
+-- Transactional outbox for jobs written alongside domain changes
+-- Rows are published by the messaging outbox relay and kept with published_at set
+
+CREATE TABLE outbox (
+  id UUID PRIMARY KEY DEFAULT uuidv7(),
+  destination VARCHAR(255) NOT NULL,
+  payload JSONB NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  last_error TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  published_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_outbox_pending ON outbox(created_at) WHERE published_at IS NULL;
@@ -0,0 +1,5 @@
+-- TOTP two-factor auth for users
+-- totp_secret is written on enrollment; totp_enabled flips once the first code is verified
+
+ALTER TABLE users ADD COLUMN totp_secret TEXT;
+ALTER TABLE users ADD COLUMN totp_enabled BOOLEAN NOT NULL DEFAULT false;
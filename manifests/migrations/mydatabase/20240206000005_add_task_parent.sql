@@ -0,0 +1,12 @@
+-- Subtasks: a task may point at a parent task
+-- The service decides whether deleting a parent is blocked or cascades;
+-- ON DELETE CASCADE keeps the table consistent either way
+
+ALTER TABLE tasks ADD COLUMN parent_id UUID;
+
+ALTER TABLE tasks ADD CONSTRAINT fk_tasks_parent
+  FOREIGN KEY (parent_id) REFERENCES tasks(id) ON DELETE CASCADE;
+ALTER TABLE tasks ADD CONSTRAINT chk_tasks_not_own_parent
+  CHECK (parent_id IS NULL OR parent_id <> id);
+
+CREATE INDEX idx_tasks_parent_id ON tasks(parent_id) WHERE parent_id IS NOT NULL;
@@ -0,0 +1,6 @@
+-- Full-text search over task title and description
+-- The expression must match SEARCH_DOCUMENT in domain_tasks::postgres for the index to be used
+
+CREATE INDEX idx_tasks_search ON tasks USING GIN (
+  (setweight(to_tsvector('english', title), 'A') || setweight(to_tsvector('english', description), 'B'))
+);
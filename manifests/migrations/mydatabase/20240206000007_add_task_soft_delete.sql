@@ -0,0 +1,7 @@
+-- Soft delete for tasks: deleting sets deleted_at, restoring clears it
+-- Live-task queries filter on deleted_at IS NULL; the partial index serves
+-- the "recently deleted" listing
+
+ALTER TABLE tasks ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX idx_tasks_deleted_at ON tasks(deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,4 @@
+-- Recurring tasks: a JSONB RecurrenceRule, NULL for one-off tasks
+-- The tasks worker creates the next occurrence when a recurring task is completed
+
+ALTER TABLE tasks ADD COLUMN recurrence JSONB;
@@ -0,0 +1,11 @@
+-- Outbox relay leases and dead rows
+-- locked_until holds a row while a relay publishes it, and holds it back
+-- after a failed publish until its next retry; failed_at marks rows that
+-- used up their attempts and are no longer relayed
+
+ALTER TABLE outbox ADD COLUMN locked_until TIMESTAMPTZ;
+ALTER TABLE outbox ADD COLUMN failed_at TIMESTAMPTZ;
+
+DROP INDEX idx_outbox_pending;
+CREATE INDEX idx_outbox_pending ON outbox(created_at) WHERE published_at IS NULL AND failed_at IS NULL;
+CREATE INDEX idx_outbox_pending_destination ON outbox(destination, created_at) WHERE published_at IS NULL AND failed_at IS NULL;
@@ -0,0 +1,5 @@
+-- Last accepted TOTP time step per user
+-- A code is only accepted for a step later than this, so an intercepted code
+-- can't be replayed within the clock-skew window
+
+ALTER TABLE users ADD COLUMN totp_last_step BIGINT;
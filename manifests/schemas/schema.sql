@@ -57,6 +57,9 @@ CREATE TABLE users (
   last_login_at TIMESTAMPTZ,
   google_id VARCHAR(255),
   github_id VARCHAR(255),
+  totp_secret TEXT,
+  totp_enabled BOOLEAN NOT NULL DEFAULT false,
+  totp_last_step BIGINT,
   created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
   updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
 );
@@ -109,6 +112,7 @@ CREATE TABLE projects (
 );
 
 CREATE INDEX idx_projects_user_id ON projects(user_id);
+CREATE INDEX idx_projects_tags ON projects USING GIN (tags jsonb_path_ops);
 
 -- Tasks table
 CREATE TABLE tasks (
@@ -117,15 +121,25 @@ CREATE TABLE tasks (
   description TEXT NOT NULL DEFAULT '',
   completed BOOLEAN NOT NULL DEFAULT false,
   project_id UUID,
+  parent_id UUID,
   priority task_priority NOT NULL DEFAULT 'medium',
   status task_status NOT NULL DEFAULT 'todo',
   due_date TIMESTAMPTZ,
+  recurrence JSONB,
   created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
   updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
-  CONSTRAINT fk_tasks_project FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE SET NULL
+  deleted_at TIMESTAMPTZ,
+  CONSTRAINT fk_tasks_project FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE SET NULL,
+  CONSTRAINT fk_tasks_parent FOREIGN KEY (parent_id) REFERENCES tasks(id) ON DELETE CASCADE,
+  CONSTRAINT chk_tasks_not_own_parent CHECK (parent_id IS NULL OR parent_id <> id)
 );
 
 CREATE INDEX idx_tasks_project_id ON tasks(project_id);
+CREATE INDEX idx_tasks_parent_id ON tasks(parent_id) WHERE parent_id IS NOT NULL;
+CREATE INDEX idx_tasks_deleted_at ON tasks(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_tasks_search ON tasks USING GIN (
+  (setweight(to_tsvector('english', title), 'A') || setweight(to_tsvector('english', description), 'B'))
+);
 
 -- Cloud resources table
 CREATE TABLE cloud_resources (
@@ -150,6 +164,30 @@ CREATE TABLE cloud_resources (
 CREATE INDEX idx_cloud_resources_project_id ON cloud_resources(project_id);
 CREATE UNIQUE INDEX unique_resource_name_per_project ON cloud_resources(project_id, name);
 
+-- Drift findings for cloud resources
+CREATE TABLE cloud_resource_drifts (
+  resource_id UUID PRIMARY KEY,
+  changes JSONB NOT NULL DEFAULT '[]',
+  detected_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  CONSTRAINT fk_cloud_resource_drifts_resource FOREIGN KEY (resource_id) REFERENCES cloud_resources(id) ON DELETE CASCADE
+);
+
+-- Transactional outbox (see messaging::outbox)
+CREATE TABLE outbox (
+  id UUID PRIMARY KEY DEFAULT uuidv7(),
+  destination VARCHAR(255) NOT NULL,
+  payload JSONB NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  last_error TEXT,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+  published_at TIMESTAMPTZ,
+  locked_until TIMESTAMPTZ,
+  failed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_outbox_pending ON outbox(created_at) WHERE published_at IS NULL AND failed_at IS NULL;
+CREATE INDEX idx_outbox_pending_destination ON outbox(destination, created_at) WHERE published_at IS NULL AND failed_at IS NULL;
+
 -- =============================================================================
 -- Triggers
 -- =============================================================================